@@ -20,3 +20,4 @@ $rt test-with-features mop rayon
 $rt test-with-features mop serde
 $rt test-with-features mop std
 $rt test-with-features mop wasm-bindgen
+$rt test-with-features mop derive,rand
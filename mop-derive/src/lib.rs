@@ -0,0 +1,253 @@
+//! Derive macros of MOP
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Expr, Field, Member, Type};
+
+/// Implements `mop::Solution` for a struct whose fields share the same numeric type, which
+/// becomes the variable type. Variables are indexed in declaration order.
+///
+/// If every field has a `#[mop(range = START..=END)]` attribute, a `<Name>Domain` unit struct
+/// that implements `mop::Domain<Name>` is also generated.
+///
+/// Fields of different types, e.g., `f64` and `u32`, are rejected because `Solution::Var` is
+/// unique. Such problems can store integers as `f64` or use `mop::MixedVar` variables.
+#[proc_macro_derive(Solution, attributes(mop))]
+pub fn solution(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+  let fields = match &input.data {
+    Data::Struct(data) if !data.fields.is_empty() => &data.fields,
+    _ => {
+      return Err(syn::Error::new_spanned(
+        input,
+        "`Solution` can only be derived for structs with fields",
+      ))
+    }
+  };
+  let var = fields.iter().next().map(|field| &field.ty);
+  for field in fields {
+    if var.map(ty_string) != Some(ty_string(&field.ty)) {
+      return Err(syn::Error::new_spanned(
+        &field.ty,
+        "All fields must have the same type, which becomes `Solution::Var`",
+      ));
+    }
+  }
+  let members: Vec<_> = fields.members().collect();
+  let ranges = fields.iter().map(range).collect::<syn::Result<Vec<_>>>()?;
+  let mut tokens = expand_solution(input, &members, var);
+  if ranges.iter().any(Option::is_some) {
+    let ranges = ranges
+      .into_iter()
+      .zip(fields)
+      .map(|(range, field)| {
+        range.ok_or_else(|| syn::Error::new_spanned(field, "Missing `#[mop(range = ..)]`"))
+      })
+      .collect::<syn::Result<Vec<_>>>()?;
+    if !input.generics.params.is_empty() {
+      return Err(syn::Error::new_spanned(
+        &input.generics,
+        "Domains can not be generated for generic structs",
+      ));
+    }
+    tokens.extend(expand_domain(input, &members, &ranges));
+  }
+  Ok(tokens)
+}
+
+fn expand_domain(input: &DeriveInput, members: &[Member], ranges: &[Expr]) -> TokenStream2 {
+  let domain = format_ident!("{}Domain", input.ident);
+  let doc = format!("Domain of [`{}`] generated from the ranges of its fields", input.ident);
+  let ident = &input.ident;
+  let len = members.len();
+  let vis = &input.vis;
+  let idxs: Vec<_> = (0..len).collect();
+  let rand_methods = if cfg!(feature = "rand") {
+    quote! {
+      #[inline]
+      fn new_random_solution<R>(&self, rng: &mut R) -> ::core::result::Result<#ident, Self::Error>
+      where
+        R: ::mop::__private::rand::Rng,
+      {
+        ::core::result::Result::Ok(#ident { #(#members: ::mop::__private::rand::Rng::gen_range(rng, #ranges),)* })
+      }
+
+      #[inline]
+      fn set_rnd_domain<R>(&self, s: &mut #ident, idx: usize, rng: &mut R)
+      where
+        R: ::mop::__private::rand::Rng,
+      {
+        match idx {
+          #(#idxs => s.#members = ::mop::__private::rand::Rng::gen_range(rng, #ranges),)*
+          _ => {}
+        }
+      }
+    }
+  } else {
+    quote!()
+  };
+  quote! {
+    #[doc = #doc]
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+    #vis struct #domain;
+
+    impl ::mop::Domain<#ident> for #domain {
+      type Error = ::core::convert::Infallible;
+
+      #[inline]
+      fn clamp(&self, s: &mut #ident) -> ::core::option::Option<()> {
+        #(
+          if let ::core::option::Option::Some(var) = ::mop::NumVarDomain::clamp(&(#ranges), s.#members) {
+            s.#members = var;
+          }
+        )*
+        ::core::option::Option::Some(())
+      }
+
+      #[inline]
+      fn invalid_var(&self) -> ::core::option::Option<usize> {
+        [#((#ranges).is_empty()),*].iter().position(|is_empty| *is_empty)
+      }
+
+      #[inline]
+      fn len(&self) -> usize {
+        #len
+      }
+
+      #[inline]
+      fn out_of_bounds_vars(&self, s: &#ident) -> ::core::option::Option<::mop::__private::Vec<usize>> {
+        let mut rslt = ::mop::__private::Vec::new();
+        #(
+          if !::mop::NumVarDomain::is_within_bounds(&(#ranges), s.#members) {
+            rslt.push(#idxs);
+          }
+        )*
+        ::core::option::Option::Some(rslt)
+      }
+
+      #[inline]
+      fn reflect(&self, s: &mut #ident) -> ::core::option::Option<()> {
+        #(
+          if let ::core::option::Option::Some(var) = ::mop::NumVarDomain::reflect(&(#ranges), s.#members) {
+            s.#members = var;
+          }
+        )*
+        ::core::option::Option::Some(())
+      }
+
+      #rand_methods
+    }
+  }
+}
+
+fn expand_solution(input: &DeriveInput, members: &[Member], var: Option<&Type>) -> TokenStream2 {
+  let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+  let ident = &input.ident;
+  let len = members.len();
+  let idxs = 0..len;
+  let mut pairs = Vec::new();
+  for (a, first) in members.iter().enumerate() {
+    for (b, second) in members.iter().enumerate().skip(a + 1) {
+      pairs.push(quote! {
+        (#a, #b) | (#b, #a) => ::core::mem::swap(&mut self.#first, &mut self.#second),
+      });
+    }
+  }
+  quote! {
+    impl #impl_generics ::mop::Solution for #ident #ty_generics #where_clause {
+      const MAX_LEN: usize = #len;
+
+      type Var = #var;
+
+      #[inline]
+      fn has_var(&self, idx: usize) -> bool {
+        idx < #len
+      }
+
+      #[inline]
+      fn inter_swap(&mut self, other: &mut Self, idx: usize) {
+        match idx {
+          #(#idxs => ::core::mem::swap(&mut self.#members, &mut other.#members),)*
+          _ => panic!("Index {idx} is out of bounds"),
+        }
+      }
+
+      #[inline]
+      fn intra_swap(&mut self, a: usize, b: usize) {
+        match (a, b) {
+          #(#pairs)*
+          (a, b) if a == b && a < #len => {}
+          _ => panic!("Indices {a} and {b} must be lower than {}", #len),
+        }
+      }
+
+      #[inline]
+      fn len(&self) -> usize {
+        #len
+      }
+    }
+  }
+}
+
+fn range(field: &Field) -> syn::Result<Option<Expr>> {
+  let mut range = None;
+  for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("mop")) {
+    attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("range") {
+        range = Some(meta.value()?.parse()?);
+        Ok(())
+      } else {
+        Err(meta.error("Unsupported attribute, expected `range`"))
+      }
+    })?;
+  }
+  Ok(range)
+}
+
+fn ty_string(ty: &Type) -> String {
+  quote!(#ty).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::expand;
+  use syn::parse_quote;
+
+  #[test]
+  fn expand_domain() {
+    let tokens = expand(&parse_quote! {
+      struct Params {
+        #[mop(range = 0.0..=1.0)]
+        momentum: f64,
+        #[mop(range = 2.0..=3.0)]
+        width: f64,
+      }
+    })
+    .unwrap()
+    .to_string();
+    assert!(tokens.contains("struct ParamsDomain ;"));
+    assert!(tokens.contains("impl :: mop :: Domain < Params > for ParamsDomain"));
+    assert!(tokens.contains("fn len (& self) -> usize { 2usize }"));
+  }
+
+  #[test]
+  fn expand_solution() {
+    let tokens = expand(&parse_quote! {
+      struct Params<T> {
+        momentum: T,
+        width: T,
+      }
+    })
+    .unwrap()
+    .to_string();
+    assert!(tokens.contains("impl < T > :: mop :: Solution for Params < T >"));
+    assert!(tokens.contains("type Var = T ;"));
+    assert!(tokens.contains("(0usize , 1usize) | (1usize , 0usize) =>"));
+    assert!(!tokens.contains("ParamsDomain"));
+  }
+}
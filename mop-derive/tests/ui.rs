@@ -0,0 +1,6 @@
+//! Compilation errors of the derive macros
+
+#[test]
+fn ui() {
+  trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,6 @@
+#[derive(mop::Solution)]
+enum Params {
+  Rate(f64),
+}
+
+fn main() {}
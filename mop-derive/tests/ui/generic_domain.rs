@@ -0,0 +1,7 @@
+#[derive(mop::Solution)]
+struct Params<T> {
+  #[mop(range = 0.0..=1.0)]
+  rate: T,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(mop::Solution)]
+struct Params {
+  #[mop(range = 0.0..=1.0)]
+  momentum: f64,
+  rate: f64,
+}
+
+fn main() {}
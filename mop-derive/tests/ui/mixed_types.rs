@@ -0,0 +1,7 @@
+#[derive(mop::Solution)]
+struct Params {
+  rate: f64,
+  width: u32,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(mop::Solution)]
+struct Params {
+  #[mop(step = 0.1)]
+  rate: f64,
+}
+
+fn main() {}
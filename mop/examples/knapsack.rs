@@ -0,0 +1,153 @@
+//! E. Zitzler and L. Thiele; Multiobjective evolutionary algorithms: a comparative case study and
+//! the strength Pareto approach (0/1 multi-objective knapsack problem with one knapsack)
+
+#![allow(
+  // Problems are only used when their respective features are enabled
+  dead_code
+)]
+
+mod common;
+
+#[cfg(all(feature = "solvers", feature = "std"))]
+mod module {
+  use crate::common::Problem;
+  use core::ops::Range;
+  use mop::{
+    gp::{mp_defs_from_gp_defs, GpOperations, MpVec, MphDefinitionsBuilder, MphMpMph, MphVec},
+    objs::MinCstrsRslts,
+    opt::OptFacade,
+    quality_comparator::ObjsAvg,
+    solvers::genetic_algorithm::{
+      operators::{crossover::BitUniform, mating_selection::Tournament, mutation::BitFlip},
+      GeneticAlgorithmParamsBuilder, Spea2,
+    },
+    BitString, BitStringDomain, Either, ObjDirection, Pct,
+  };
+
+  const CAPACITY: usize = 90;
+  // (Profit of the first objective, profit of the second objective, weight)
+  const ITEMS: [(usize, usize, usize); 20] = [
+    (12, 4, 9),
+    (3, 15, 6),
+    (9, 9, 12),
+    (17, 2, 11),
+    (4, 13, 4),
+    (8, 8, 7),
+    (2, 19, 13),
+    (14, 6, 8),
+    (6, 11, 5),
+    (19, 3, 14),
+    (7, 7, 3),
+    (1, 16, 9),
+    (15, 5, 10),
+    (5, 12, 6),
+    (11, 10, 11),
+    (13, 1, 7),
+    (3, 14, 8),
+    (10, 9, 5),
+    (16, 4, 12),
+    (2, 18, 10),
+  ];
+  const RSLTS_NUM: usize = 50;
+
+  type Domain = BitStringDomain;
+  type Solution = BitString;
+
+  // Overweight selections are worthless, which keeps the population near the feasible region
+  fn profit(s: &Solution, f: fn(&(usize, usize, usize)) -> usize) -> f64 {
+    if g1(s) > 0 {
+      return 0.0;
+    }
+    s.iter_ones().map(|idx| f(&ITEMS[idx])).sum::<usize>() as f64
+  }
+
+  fn f1(s: &Solution) -> f64 {
+    profit(s, |item| item.0)
+  }
+
+  fn f2(s: &Solution) -> f64 {
+    profit(s, |item| item.1)
+  }
+
+  fn g1(s: &Solution) -> usize {
+    let weight: usize = s.iter_ones().map(|idx| ITEMS[idx].2).sum();
+    weight.saturating_sub(CAPACITY)
+  }
+
+  #[derive(Debug)]
+  struct Knapsack;
+
+  impl Problem<Domain, Solution, 1, 2> for Knapsack {
+    const GRAPH_RANGES: [Range<f64>; 2] = [0.0..200.0, 0.0..200.0];
+    const NAME: &'static str = "Knapsack";
+
+    type Hcs = fn(&Solution) -> usize;
+    type Objs = (ObjDirection, fn(&Solution) -> f64);
+
+    #[inline]
+    fn domain() -> Domain {
+      BitStringDomain::new(ITEMS.len())
+    }
+
+    #[inline]
+    fn hcs() -> [Self::Hcs; 1] {
+      [g1]
+    }
+
+    #[inline]
+    fn objs() -> [Self::Objs; 2] {
+      [(ObjDirection::Max, f1), (ObjDirection::Max, f2)]
+    }
+  }
+
+  pub(crate) fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mph = MphVec::with_capacity(
+      MphDefinitionsBuilder::default()
+        .domain(Knapsack::domain())
+        .hard_cstrs(Knapsack::hcs().to_vec())
+        .name(Knapsack::NAME)
+        .objs(Knapsack::objs().to_vec())
+        .build()?,
+      RSLTS_NUM,
+    );
+    let (mph_defs, mph_rslts) = mph.parts_mut();
+
+    let mp_defs_ref = mp_defs_from_gp_defs(mph_defs)?
+      .push_obj(Either::Right(MinCstrsRslts::from_gp_hcs(mph_defs)))?
+      .build()?;
+    let mut mp_ref = MpVec::with_random_solutions(mp_defs_ref, 100)?;
+
+    let spea2 = Spea2::new(
+      Pct::from_percent(50),
+      GeneticAlgorithmParamsBuilder::default()
+        .crossover(BitUniform::new(Pct::from_percent(70)))
+        .mating_selection(Tournament::new(5, ObjsAvg))
+        .mutation(BitFlip::new(Pct::from_percent(5), Pct::from_percent(30)))
+        .build()?,
+      &mp_ref,
+      RSLTS_NUM,
+    )?;
+    let _facade = OptFacade::new(200)
+      .set_quality_comparator(ObjsAvg)
+      .set_opt_hooks(())
+      .solve_problem_with(&mut mp_ref, spea2)?;
+
+    MphMpMph::transfer(mph_defs, mph_rslts, &mp_ref)?;
+
+    for rslt in mph_rslts.iter().filter(|rslt| rslt.hard_cstr_rslts().iter().all(|v| *v == 0)) {
+      println!("{:?}: {:?}", rslt.solution().iter_ones().collect::<Vec<_>>(), rslt.obj_rslts());
+    }
+    Ok(())
+  }
+}
+
+#[cfg(not(all(feature = "solvers", feature = "std")))]
+mod module {
+  pub(crate) fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+  }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+  module::main()
+}
@@ -0,0 +1,280 @@
+use crate::Solution;
+use alloc::vec::Vec;
+use core::ops::Range;
+#[cfg(feature = "rand")]
+use {crate::Domain, rand::Rng};
+
+const WORD_BITS: usize = 64;
+
+/// Fixed-length sequence of bits packed into 64-bit words. Each bit is a variable of a solution,
+/// e.g., if an item of a knapsack is selected or if a feature is used.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BitString {
+  len: usize,
+  // Bits past `len` are always zero
+  words: Vec<u64>,
+}
+
+impl BitString {
+  /// All bits are unset
+  #[inline]
+  pub fn new(len: usize) -> Self {
+    Self { len, words: alloc::vec![0; len.div_ceil(WORD_BITS)] }
+  }
+
+  #[inline]
+  pub fn count_ones(&self) -> usize {
+    self.words.iter().map(|word| word.count_ones() as usize).sum()
+  }
+
+  /// Inverts the bit of index `idx`.
+  #[inline]
+  pub fn flip(&mut self, idx: usize) {
+    assert!(idx < self.len);
+    self.words[idx / WORD_BITS] ^= 1 << (idx % WORD_BITS);
+  }
+
+  #[inline]
+  pub fn get(&self, idx: usize) -> Option<bool> {
+    if idx >= self.len {
+      return None;
+    }
+    Some(self.words[idx / WORD_BITS] & (1 << (idx % WORD_BITS)) != 0)
+  }
+
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  #[inline]
+  pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+    (0..self.len).map(|idx| self.words[idx / WORD_BITS] & (1 << (idx % WORD_BITS)) != 0)
+  }
+
+  /// Indices of the set bits in ascending order
+  #[inline]
+  pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+    self.words.iter().enumerate().flat_map(|(word_idx, word)| {
+      let mut remaining = *word;
+      core::iter::from_fn(move || {
+        if remaining == 0 {
+          return None;
+        }
+        let bit = remaining.trailing_zeros() as usize;
+        remaining &= remaining - 1;
+        Some(word_idx * WORD_BITS + bit)
+      })
+    })
+  }
+
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  #[inline]
+  pub fn set(&mut self, idx: usize, value: bool) {
+    assert!(idx < self.len);
+    let mask = 1 << (idx % WORD_BITS);
+    let word = &mut self.words[idx / WORD_BITS];
+    if value {
+      *word |= mask;
+    } else {
+      *word &= !mask;
+    }
+  }
+
+  /// Packed bits. The bit of index `idx` is the bit `idx % 64` of the word `idx / 64`.
+  #[inline]
+  pub fn words(&self) -> &[u64] {
+    &self.words
+  }
+
+  /// Swaps, between `self` and `other`, the bits of `range` that are also set in the
+  /// masks returned by `mask`. `mask` is called once per word. Bits past the length of
+  /// any of the strings are never swapped.
+  pub(crate) fn swap_masked<F>(&mut self, other: &mut Self, range: Range<usize>, mut mask: F)
+  where
+    F: FnMut() -> u64,
+  {
+    let end = range.end.min(self.len).min(other.len);
+    if range.start >= end {
+      return;
+    }
+    let last_word = (end - 1) / WORD_BITS;
+    for word_idx in range.start / WORD_BITS..=last_word {
+      let word_start = word_idx * WORD_BITS;
+      let lower = range.start.saturating_sub(word_start);
+      let upper = (end - word_start).min(WORD_BITS);
+      let range_mask = (u64::MAX << lower) & (u64::MAX >> (WORD_BITS - upper));
+      let diff = (self.words[word_idx] ^ other.words[word_idx]) & range_mask & mask();
+      self.words[word_idx] ^= diff;
+      other.words[word_idx] ^= diff;
+    }
+  }
+
+  /// Flips the bits set in `mask` of the word of index `word_idx`. Bits past the length
+  /// are ignored.
+  #[cfg(feature = "rand")]
+  pub(crate) fn xor_word(&mut self, word_idx: usize, mask: u64) {
+    let word_start = word_idx * WORD_BITS;
+    let upper = self.len.saturating_sub(word_start).min(WORD_BITS);
+    if upper == 0 {
+      return;
+    }
+    self.words[word_idx] ^= mask & (u64::MAX >> (WORD_BITS - upper));
+  }
+}
+
+impl FromIterator<bool> for BitString {
+  #[inline]
+  fn from_iter<I>(iter: I) -> Self
+  where
+    I: IntoIterator<Item = bool>,
+  {
+    let mut this = Self::default();
+    for (idx, value) in iter.into_iter().enumerate() {
+      if idx.is_multiple_of(WORD_BITS) {
+        this.words.push(0);
+      }
+      this.len = idx + 1;
+      this.set(idx, value);
+    }
+    this
+  }
+}
+
+impl Solution for BitString {
+  const MAX_LEN: usize = usize::MAX;
+
+  type Var = bool;
+
+  #[inline]
+  fn has_var(&self, idx: usize) -> bool {
+    idx < self.len
+  }
+
+  #[inline]
+  fn insert_var(&mut self, idx: usize, var: Self::Var) -> crate::Result<()> {
+    if idx > self.len {
+      return Err(crate::Error::EmptyElement);
+    }
+    if self.len.is_multiple_of(WORD_BITS) {
+      self.words.push(0);
+    }
+    self.len = self.len.wrapping_add(1);
+    for curr in (idx + 1..self.len).rev() {
+      let prev = self.get(curr - 1).unwrap_or_default();
+      self.set(curr, prev);
+    }
+    self.set(idx, var);
+    Ok(())
+  }
+
+  #[inline]
+  fn inter_swap(&mut self, other: &mut Self, idx: usize) {
+    assert!(idx < self.len && idx < other.len);
+    self.swap_masked(other, idx..idx + 1, || u64::MAX);
+  }
+
+  #[inline]
+  fn intra_swap(&mut self, a: usize, b: usize) {
+    if let (Some(first), Some(second)) = (self.get(a), self.get(b)) {
+      self.set(a, second);
+      self.set(b, first);
+    }
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.len
+  }
+
+  #[inline]
+  fn remove_var(&mut self, idx: usize) -> crate::Result<Self::Var> {
+    let var = crate::Error::opt_rslt(self.get(idx))?;
+    for curr in idx..self.len - 1 {
+      let next = self.get(curr + 1).unwrap_or_default();
+      self.set(curr, next);
+    }
+    self.set(self.len - 1, false);
+    self.len -= 1;
+    self.words.truncate(self.len.div_ceil(WORD_BITS));
+    Ok(var)
+  }
+}
+
+/// Domain of [`BitString`]s of a fixed length, where every bit can be freely set or unset.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BitStringDomain {
+  len: usize,
+}
+
+impl BitStringDomain {
+  #[inline]
+  pub fn new(len: usize) -> Self {
+    Self { len }
+  }
+}
+
+#[cfg(feature = "rand")]
+impl Domain<BitString> for BitStringDomain {
+  type Error = core::convert::Infallible;
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.len
+  }
+
+  #[inline]
+  fn new_random_solution<R>(&self, rng: &mut R) -> Result<BitString, Self::Error>
+  where
+    R: Rng,
+  {
+    let mut s = BitString::new(self.len);
+    for word_idx in 0..s.words.len() {
+      s.xor_word(word_idx, rng.gen());
+    }
+    Ok(s)
+  }
+
+  #[inline]
+  fn set_rnd_domain<R>(&self, s: &mut BitString, idx: usize, rng: &mut R)
+  where
+    R: Rng,
+  {
+    s.set(idx, rng.gen());
+  }
+}
+
+#[cfg(all(feature = "rand", test))]
+mod tests {
+  use crate::{BitString, Solution};
+  use alloc::vec::Vec;
+
+  #[test]
+  fn bit_string() {
+    let mut a: BitString = (0..70).map(|idx| idx % 3 == 0).collect();
+    let mut b = BitString::new(70);
+    assert_eq!(a.count_ones(), 24);
+    assert_eq!(a.iter_ones().take(3).collect::<Vec<_>>(), [0, 3, 6]);
+    a.swap_masked(&mut b, 60..100, || u64::MAX);
+    assert_eq!(a.iter_ones().last(), Some(57));
+    assert_eq!(b.iter_ones().collect::<Vec<_>>(), [60, 63, 66, 69]);
+    b.intra_swap(60, 61);
+    b.flip(69);
+    assert_eq!(
+      (b.get(60), b.get(61), b.get(69), b.get(70)),
+      (Some(false), Some(true), Some(false), None)
+    );
+    b.xor_word(1, u64::MAX);
+    assert_eq!(b.words()[1], 0b11_1011);
+    b.insert_var(0, true).unwrap();
+    assert_eq!((b.len(), b.get(62), b.words()[1]), (71, Some(true), 0b111_0111));
+    assert_eq!((b.remove_var(0), b.remove_var(70)), (Ok(true), Err(crate::Error::EmptyElement)));
+    assert_eq!(b.words()[1], 0b11_1011);
+  }
+}
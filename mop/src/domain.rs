@@ -1,8 +1,15 @@
+mod scaled_range;
+mod variable_length_domain;
+
+use alloc::{boxed::Box, vec::Vec};
 #[cfg(all(feature = "ndstruct", feature = "rand"))]
 use cl_aux::{Push, SingleTypeStorage};
+use core::ops::RangeInclusive;
+pub use scaled_range::{NumVarDomain, NumVarDomains, Scale, ScaledRange};
+pub use variable_length_domain::VariableLengthDomain;
 #[cfg(feature = "rand")]
 use {
-  core::ops::RangeInclusive,
+  num_traits::NumCast,
   rand::{
     distributions::{uniform::SampleUniform, Distribution, Uniform},
     Rng,
@@ -12,96 +19,177 @@ use {
 pub trait Domain<S> {
   type Error;
 
+  /// Moves every out-of-bounds variable of `s` to the nearest value of its domain. `None` if the
+  /// domain doesn't support clamping, which is the default.
+  #[inline]
+  fn clamp(&self, _s: &mut S) -> Option<()> {
+    None
+  }
+
+  /// Index of the first variable whose domain is invalid, e.g., a range whose start is greater
+  /// than its end. Verified by [`crate::gp::GpDefinitionsBuilder::build`]. Defaults to none.
+  #[inline]
+  fn invalid_var(&self) -> Option<usize> {
+    None
+  }
+
   #[inline]
   fn is_empty(&self) -> bool {
     self.len() == 0
   }
 
+  /// If the variable of index `idx` of `s` is used by `s`. Variables of conditional domains are
+  /// only active when their conditions are met. Defaults to `true`.
+  #[inline]
+  fn is_active(&self, _s: &S, _idx: usize) -> bool {
+    true
+  }
+
   fn len(&self) -> usize;
 
+  /// Maximum number of variables of a solution. Defaults to `len`.
+  #[inline]
+  fn max_len(&self) -> usize {
+    self.len()
+  }
+
+  /// Minimum number of variables of a solution. Defaults to `len`.
+  #[inline]
+  fn min_len(&self) -> usize {
+    self.len()
+  }
+
   #[cfg(feature = "rand")]
   fn new_random_solution<R>(&self, rng: &mut R) -> Result<S, Self::Error>
   where
     R: Rng;
 
+  /// Indices of the variables of `s` that are outside of their domains. `None` if the domain
+  /// doesn't support bounds verification, which is the default.
+  #[inline]
+  fn out_of_bounds_vars(&self, _s: &S) -> Option<Vec<usize>> {
+    None
+  }
+
+  /// Mirrors every out-of-bounds variable of `s` back into its domain. `None` if the domain
+  /// doesn't support reflection, which is the default.
+  #[inline]
+  fn reflect(&self, _s: &mut S) -> Option<()> {
+    None
+  }
+
   #[cfg(feature = "rand")]
   fn set_rnd_domain<R>(&self, s: &mut S, idx: usize, rng: &mut R)
   where
     R: Rng;
 }
 
+/// Implements [`Domain`] for collections of [`NumVarDomain`]s, where `$new_var` creates a random
+/// variable of `$domain`. Arrays are filled by index while other collections are collected.
 #[cfg(feature = "rand")]
-impl<T, const N: usize> Domain<[T; N]> for [RangeInclusive<T>; N]
-where
-  T: Copy + SampleUniform,
-{
-  type Error = core::convert::Infallible;
+macro_rules! impl_num_var_domain {
+  (@new array, $domains:ident, |$domain:ident| $new_var:expr) => {
+    core::array::from_fn(|idx| {
+      let $domain = &$domains[idx];
+      $new_var
+    })
+  };
+  (@new collect, $domains:ident, |$domain:ident| $new_var:expr) => {
+    $domains.iter().map(|$domain| $new_var).collect()
+  };
+  (
+    impl[$($generic:tt)*] Domain<$s:ty> for $ty:ty as $kind:ident
+    where [$($bound:tt)*],
+    |$domain:ident, $rng:ident| $new_var:expr
+  ) => {
+    impl<$($generic)*> Domain<$s> for $ty
+    where
+      $($bound)*
+    {
+      type Error = core::convert::Infallible;
 
-  #[inline]
-  fn len(&self) -> usize {
-    N
-  }
+      #[inline]
+      fn clamp(&self, s: &mut $s) -> Option<()> {
+        $crate::domain::update_vars(self.iter(), &mut s[..], NumVarDomain::clamp);
+        Some(())
+      }
 
-  #[inline]
-  fn new_random_solution<R>(&self, rng: &mut R) -> Result<[T; N], Self::Error>
-  where
-    R: Rng,
-  {
-    Ok(core::array::from_fn(|idx| Uniform::from(*self[idx].start()..=*self[idx].end()).sample(rng)))
-  }
+      #[inline]
+      fn invalid_var(&self) -> Option<usize> {
+        self.iter().position(|domain| !domain.is_valid())
+      }
 
-  #[inline]
-  fn set_rnd_domain<R>(&self, s: &mut [T; N], idx: usize, rng: &mut R)
-  where
-    R: Rng,
-  {
-    let domain = &self[idx];
-    let domain_value = Uniform::from(*domain.start()..=*domain.end()).sample(rng);
-    s[idx] = domain_value;
-  }
-}
+      #[inline]
+      fn len(&self) -> usize {
+        self.iter().len()
+      }
 
-#[cfg(feature = "rand")]
-impl<T, const N: usize> Domain<arrayvec::ArrayVec<T, N>>
-  for arrayvec::ArrayVec<RangeInclusive<T>, N>
-where
-  T: Copy + SampleUniform,
-{
-  type Error = core::convert::Infallible;
+      #[inline]
+      fn new_random_solution<R>(&self, $rng: &mut R) -> Result<$s, Self::Error>
+      where
+        R: Rng,
+      {
+        let domains = self;
+        Ok(impl_num_var_domain!(@new $kind, domains, |$domain| $new_var))
+      }
 
-  #[inline]
-  fn len(&self) -> usize {
-    self.len()
-  }
+      #[inline]
+      fn out_of_bounds_vars(&self, s: &$s) -> Option<Vec<usize>> {
+        Some($crate::domain::out_of_bounds_vars(self.iter(), &s[..]))
+      }
 
-  #[inline]
-  fn new_random_solution<R>(&self, rng: &mut R) -> Result<arrayvec::ArrayVec<T, N>, Self::Error>
-  where
-    R: Rng,
-  {
-    let mut s = arrayvec::ArrayVec::new();
-    for domain in self {
-      s.push(Uniform::from(*domain.start()..=*domain.end()).sample(rng));
-    }
-    Ok(s)
-  }
+      #[inline]
+      fn reflect(&self, s: &mut $s) -> Option<()> {
+        $crate::domain::update_vars(self.iter(), &mut s[..], NumVarDomain::reflect);
+        Some(())
+      }
 
-  #[inline]
-  fn set_rnd_domain<R>(&self, s: &mut arrayvec::ArrayVec<T, N>, idx: usize, rng: &mut R)
-  where
-    R: Rng,
-  {
-    let domain = &self[idx];
-    let domain_value = Uniform::from(*domain.start()..=*domain.end()).sample(rng);
-    s[idx] = domain_value;
-  }
+      #[inline]
+      fn set_rnd_domain<R>(&self, s: &mut $s, idx: usize, $rng: &mut R)
+      where
+        R: Rng,
+      {
+        let $domain = &self[idx];
+        s[idx] = $new_var;
+      }
+    }
+  };
 }
+#[cfg(feature = "rand")]
+pub(crate) use impl_num_var_domain;
+
+#[cfg(feature = "rand")]
+impl_num_var_domain!(
+  impl[T, const N: usize] Domain<[T; N]> for [RangeInclusive<T>; N] as array
+  where [T: Copy + NumCast + SampleUniform, RangeInclusive<T>: NumVarDomain<T>],
+  |domain, rng| Uniform::from(*domain.start()..=*domain.end()).sample(rng)
+);
+#[cfg(feature = "rand")]
+impl_num_var_domain!(
+  impl[T, const N: usize] Domain<arrayvec::ArrayVec<T, N>>
+    for arrayvec::ArrayVec<RangeInclusive<T>, N> as collect
+  where [T: Copy + NumCast + SampleUniform, RangeInclusive<T>: NumVarDomain<T>],
+  |domain, rng| Uniform::from(*domain.start()..=*domain.end()).sample(rng)
+);
+#[cfg(feature = "rand")]
+impl_num_var_domain!(
+  impl[T] Domain<Box<[T]>> for Box<[RangeInclusive<T>]> as collect
+  where [T: Copy + NumCast + SampleUniform, RangeInclusive<T>: NumVarDomain<T>],
+  |domain, rng| Uniform::from(*domain.start()..=*domain.end()).sample(rng)
+);
+#[cfg(feature = "rand")]
+impl_num_var_domain!(
+  impl[T] Domain<Vec<T>> for Vec<RangeInclusive<T>> as collect
+  where [T: Copy + NumCast + SampleUniform, RangeInclusive<T>: NumVarDomain<T>],
+  |domain, rng| Uniform::from(*domain.start()..=*domain.end()).sample(rng)
+);
 
 #[cfg(all(feature = "ndstruct", feature = "rand"))]
 impl<DATA, DS, IS, OS, const D: usize, const N: usize> Domain<ndstruct::csl::Csl<DS, IS, OS, D>>
   for [RangeInclusive<DATA>; N]
 where
-  DATA: Copy + SampleUniform,
+  DATA: Copy + NumCast + SampleUniform,
+  RangeInclusive<DATA>: NumVarDomain<DATA>,
   DS: AsMut<[DATA]> + AsRef<[DATA]> + Default + Push<DATA> + SingleTypeStorage<Item = DATA>,
   IS: AsMut<[usize]> + AsRef<[usize]> + Default + Push<usize>,
   OS: AsMut<[usize]> + AsRef<[usize]> + Default + Push<usize>,
@@ -109,6 +197,18 @@ where
 {
   type Error = crate::Error;
 
+  /// Only the stored values are verified, their positions are left untouched
+  #[inline]
+  fn clamp(&self, s: &mut ndstruct::csl::Csl<DS, IS, OS, D>) -> Option<()> {
+    update_vars(self.iter(), s.data_mut(), NumVarDomain::clamp);
+    Some(())
+  }
+
+  #[inline]
+  fn invalid_var(&self) -> Option<usize> {
+    self.iter().position(|domain| !domain.is_valid())
+  }
+
   #[inline]
   fn len(&self) -> usize {
     self.as_ref().len()
@@ -135,6 +235,17 @@ where
       .map_err(crate::Error::NdsparseError)
   }
 
+  #[inline]
+  fn out_of_bounds_vars(&self, s: &ndstruct::csl::Csl<DS, IS, OS, D>) -> Option<Vec<usize>> {
+    Some(out_of_bounds_vars(self.iter(), s.data()))
+  }
+
+  #[inline]
+  fn reflect(&self, s: &mut ndstruct::csl::Csl<DS, IS, OS, D>) -> Option<()> {
+    update_vars(self.iter(), s.data_mut(), NumVarDomain::reflect);
+    Some(())
+  }
+
   #[inline]
   fn set_rnd_domain<R>(&self, s: &mut ndstruct::csl::Csl<DS, IS, OS, D>, idx: usize, rng: &mut R)
   where
@@ -145,3 +256,96 @@ where
     s.data_mut()[idx] = domain_value;
   }
 }
+
+/// Indices of the variables of `vars` that are outside of their respective domains
+#[cfg(feature = "rand")]
+fn out_of_bounds_vars<'any, I, T, V>(domains: I, vars: &[T]) -> Vec<usize>
+where
+  I: IntoIterator<Item = &'any V>,
+  T: Copy,
+  V: NumVarDomain<T> + 'any,
+{
+  domains
+    .into_iter()
+    .zip(vars)
+    .enumerate()
+    .filter_map(|(idx, (domain, var))| (!domain.is_within_bounds(*var)).then_some(idx))
+    .collect()
+}
+
+/// Replaces every variable of `vars` with the result of `f`, e.g., [`NumVarDomain::clamp`]
+#[cfg(feature = "rand")]
+fn update_vars<'any, I, T, V>(domains: I, vars: &mut [T], f: fn(&V, T) -> Option<T>)
+where
+  I: IntoIterator<Item = &'any V>,
+  T: Copy,
+  V: NumVarDomain<T> + 'any,
+{
+  for (domain, var) in domains.into_iter().zip(vars) {
+    if let Some(elem) = f(domain, *var) {
+      *var = elem;
+    }
+  }
+}
+
+#[cfg(all(feature = "rand", test))]
+mod tests {
+  use crate::{Domain, NumVarDomain, VariableLengthDomain};
+  use alloc::vec;
+
+  #[test]
+  fn domain_bounds() {
+    let domain = [0.0..=10.0, 0.0..=10.0, 0.0..=10.0];
+    let mut s = [-2.0, 5.0, 13.0];
+    assert_eq!(domain.out_of_bounds_vars(&s), Some(vec![0, 2]));
+    let mut reflected = s;
+    assert_eq!(domain.reflect(&mut reflected), Some(()));
+    assert_eq!(reflected, [2.0, 5.0, 7.0]);
+    assert_eq!(domain.clamp(&mut s), Some(()));
+    assert_eq!(s, [0.0, 5.0, 10.0]);
+    assert_eq!(domain.out_of_bounds_vars(&s), Some(vec![]));
+    assert_eq!(Domain::<[f64; 2]>::invalid_var(&[0.0..=1.0, 1.0..=0.0]), Some(1));
+    assert_eq!(Domain::<[f64; 2]>::invalid_var(&[0.0..=1.0, 0.0..=f64::INFINITY]), Some(1));
+    let vld = VariableLengthDomain::new(2..=3, 0..=9).unwrap();
+    let mut short = vec![12];
+    assert_eq!(vld.out_of_bounds_vars(&short), Some(vec![0, 1]));
+    assert_eq!(vld.clamp(&mut short), Some(()));
+    assert_eq!(short, [9, 0]);
+    let mut long = vec![1, 2, 3, 4];
+    assert_eq!(vld.out_of_bounds_vars(&long), Some(vec![3]));
+    assert_eq!(vld.reflect(&mut long), Some(()));
+    assert_eq!(long, [1, 2, 3]);
+  }
+
+  #[test]
+  fn integer_domain_bounds() {
+    let start = 1_i64 << 60;
+    let domain = [start..=start + 10, 0..=i64::MAX];
+    let mut s = [start + 13, -3];
+    let mut reflected = s;
+    assert_eq!(domain.reflect(&mut reflected), Some(()));
+    assert_eq!(reflected, [start + 7, 3]);
+    assert_eq!(domain.clamp(&mut s), Some(()));
+    assert_eq!(s, [start + 10, 0]);
+    assert_eq!(domain[1].decode(f64::MAX), Some(i64::MAX));
+    assert_eq!(domain[1].reflect(i64::MIN), Some(i64::MAX));
+    assert_eq!((0..=u8::MAX).reflect(0), Some(0));
+  }
+
+  #[cfg(feature = "ndstruct")]
+  #[test]
+  fn csl_domain_bounds() {
+    let domain = [0.0..=1.0, 0.0..=1.0];
+    let mut csl = ndstruct::csl::Csl::<
+      alloc::vec::Vec<f64>,
+      alloc::vec::Vec<usize>,
+      alloc::vec::Vec<usize>,
+      2,
+    >::new([2, 2], vec![2.0, 0.5], vec![0, 1], vec![0, 1, 2])
+    .unwrap();
+    assert_eq!(domain.out_of_bounds_vars(&csl), Some(vec![0]));
+    assert_eq!(domain.clamp(&mut csl), Some(()));
+    assert_eq!(csl.data(), [1.0, 0.5]);
+    assert_eq!(csl.indcs(), [0, 1]);
+  }
+}
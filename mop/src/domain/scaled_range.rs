@@ -0,0 +1,392 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::ops::RangeInclusive;
+use num_traits::{Float, NumCast, ToPrimitive};
+#[cfg(feature = "rand")]
+use {super::impl_num_var_domain, crate::Domain, rand::Rng};
+
+/// Domain of a single numeric variable. Numeric operators, e.g., mutations that add
+/// perturbations or crossovers that blend parents, work in the encoded space of each
+/// variable and decode their results back into the domain.
+pub trait NumVarDomain<T> {
+  /// Moves `var` to the nearest value of the domain.
+  #[inline]
+  fn clamp(&self, var: T) -> Option<T> {
+    self.decode(self.encode(var)?)
+  }
+
+  /// Converts an encoded value into a variable of the domain. Values outside the encoded bounds
+  /// are clamped.
+  fn decode(&self, value: f64) -> Option<T>;
+
+  /// Converts a variable into the encoded space.
+  fn encode(&self, var: T) -> Option<f64>;
+
+  /// Inclusive lower and upper bounds of the encoded space
+  fn encoded_bounds(&self) -> Option<[f64; 2]>;
+
+  /// If the domain can produce variables. Defaults to verifying that the encoded bounds are
+  /// ordered.
+  #[inline]
+  fn is_valid(&self) -> bool {
+    matches!(self.encoded_bounds(), Some([start, end]) if start <= end)
+  }
+
+  /// If `var` is within the encoded bounds.
+  #[inline]
+  fn is_within_bounds(&self, var: T) -> bool {
+    match (self.encode(var), self.encoded_bounds()) {
+      (Some(value), Some([start, end])) => start <= value && value <= end,
+      _ => false,
+    }
+  }
+
+  /// Mirrors `var` across the violated bound, which preserves the distance to the bound instead of
+  /// piling values on it. Values that are still out of bounds are clamped.
+  #[inline]
+  fn reflect(&self, var: T) -> Option<T> {
+    let [start, end] = self.encoded_bounds()?;
+    let value = self.encode(var)?;
+    let reflected = if value < start {
+      start + (start - value)
+    } else if value > end {
+      end - (value - end)
+    } else {
+      value
+    };
+    self.decode(reflected)
+  }
+}
+
+macro_rules! impl_float_range {
+  ($($ty:ty),*) => {
+    $(
+      /// Linear scale. Bounds must be finite.
+      impl NumVarDomain<$ty> for RangeInclusive<$ty> {
+        #[inline]
+        fn decode(&self, value: f64) -> Option<$ty> {
+          let [start, end] = self.encoded_bounds()?;
+          NumCast::from(value.max(start).min(end))
+        }
+
+        #[inline]
+        fn encode(&self, var: $ty) -> Option<f64> {
+          Some(var.into())
+        }
+
+        #[inline]
+        fn encoded_bounds(&self) -> Option<[f64; 2]> {
+          Some([(*self.start()).into(), (*self.end()).into()])
+        }
+
+        #[inline]
+        fn is_valid(&self) -> bool {
+          let (start, end) = (*self.start(), *self.end());
+          start.is_finite() && end.is_finite() && start <= end
+        }
+      }
+    )*
+  };
+}
+
+impl_float_range!(f32, f64);
+
+macro_rules! impl_integer_range {
+  ($($ty:ty),*) => {
+    $(
+      /// Linear scale. Variables are clamped and reflected without leaving the integer type, the
+      /// encoded space is only used by numeric operators.
+      impl NumVarDomain<$ty> for RangeInclusive<$ty> {
+        #[inline]
+        fn clamp(&self, var: $ty) -> Option<$ty> {
+          let (start, end) = (*self.start(), *self.end());
+          (start <= end).then(|| var.clamp(start, end))
+        }
+
+        #[inline]
+        fn decode(&self, value: f64) -> Option<$ty> {
+          let var = <$ty as NumCast>::from(value).or_else(|| {
+            if value < 0.0 {
+              Some(<$ty>::MIN)
+            } else if value > 0.0 {
+              Some(<$ty>::MAX)
+            } else {
+              None
+            }
+          })?;
+          self.clamp(var)
+        }
+
+        #[inline]
+        fn encode(&self, var: $ty) -> Option<f64> {
+          var.to_f64()
+        }
+
+        #[inline]
+        fn encoded_bounds(&self) -> Option<[f64; 2]> {
+          Some([self.start().to_f64()?, self.end().to_f64()?])
+        }
+
+        #[inline]
+        fn is_valid(&self) -> bool {
+          self.start() <= self.end()
+        }
+
+        #[inline]
+        fn is_within_bounds(&self, var: $ty) -> bool {
+          self.contains(&var)
+        }
+
+        /// Reflections that overflow the type are beyond the opposite bound and therefore
+        /// clamped.
+        #[inline]
+        fn reflect(&self, var: $ty) -> Option<$ty> {
+          let (start, end) = (*self.start(), *self.end());
+          let reflected = if var < start {
+            start.checked_sub(var).and_then(|diff| start.checked_add(diff)).unwrap_or(end)
+          } else if var > end {
+            var.checked_sub(end).and_then(|diff| end.checked_sub(diff)).unwrap_or(start)
+          } else {
+            var
+          };
+          self.clamp(reflected)
+        }
+      }
+    )*
+  };
+}
+
+impl_integer_range!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Sequence of [`NumVarDomain`]s indexed like the variables of a solution. `()` has no domains.
+pub trait NumVarDomains<T> {
+  type VarDomain: NumVarDomain<T>;
+
+  /// Domain of the variable of index `idx`
+  fn var_domain(&self, idx: usize) -> Option<&Self::VarDomain>;
+
+  /// Number of domains
+  fn var_domains_num(&self) -> usize;
+}
+
+impl<T> NumVarDomains<T> for ()
+where
+  RangeInclusive<T>: NumVarDomain<T>,
+{
+  type VarDomain = RangeInclusive<T>;
+
+  #[inline]
+  fn var_domain(&self, _: usize) -> Option<&Self::VarDomain> {
+    None
+  }
+
+  #[inline]
+  fn var_domains_num(&self) -> usize {
+    0
+  }
+}
+
+macro_rules! impl_num_var_domains {
+  ($ty:ty $(, const $n:ident)?) => {
+    impl<T, V $(, const $n: usize)?> NumVarDomains<T> for $ty
+    where
+      V: NumVarDomain<T>,
+    {
+      type VarDomain = V;
+
+      #[inline]
+      fn var_domain(&self, idx: usize) -> Option<&Self::VarDomain> {
+        self.get(idx)
+      }
+
+      #[inline]
+      fn var_domains_num(&self) -> usize {
+        self.len()
+      }
+    }
+  };
+}
+
+impl_num_var_domains!([V; N], const N);
+impl_num_var_domains!(arrayvec::ArrayVec<V, N>, const N);
+impl_num_var_domains!(Box<[V]>);
+impl_num_var_domains!(Vec<V>);
+
+/// How values are distributed within a [`ScaledRange`]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Scale {
+  Linear,
+  /// Orders of magnitude are equally likely, e.g., learning rates or tolerances. Bounds must be
+  /// positive.
+  Log,
+  /// Only multiples of the step, counted from the start of the range, are valid
+  Quantized(f64),
+}
+
+/// Continuous range of `f64` values with a [`Scale`].
+///
+/// `[ScaledRange; N]`, `ArrayVec<ScaledRange, N>` and `Vec<ScaledRange>` are domains of
+/// `[f64; N]`, `ArrayVec<f64, N>` and `Vec<f64>` respectively.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScaledRange {
+  range: RangeInclusive<f64>,
+  scale: Scale,
+}
+
+impl ScaledRange {
+  /// Bounds must be finite and ordered.
+  #[inline]
+  pub fn new(range: RangeInclusive<f64>, scale: Scale) -> crate::Result<Self> {
+    let this = Self { range, scale };
+    if !NumVarDomain::is_valid(&this) {
+      return Err(crate::Error::Other("Invalid scaled range"));
+    }
+    Ok(this)
+  }
+
+  #[inline]
+  pub fn linear(range: RangeInclusive<f64>) -> crate::Result<Self> {
+    Self::new(range, Scale::Linear)
+  }
+
+  #[inline]
+  pub fn log(range: RangeInclusive<f64>) -> crate::Result<Self> {
+    Self::new(range, Scale::Log)
+  }
+
+  #[inline]
+  pub fn quantized(range: RangeInclusive<f64>, step: f64) -> crate::Result<Self> {
+    Self::new(range, Scale::Quantized(step))
+  }
+
+  /// If `var` is within the range and, for quantized ranges, is a multiple of the step.
+  #[inline]
+  pub fn contains(&self, var: f64) -> bool {
+    let is_on_scale = !matches!(self.scale, Scale::Quantized(_)) || self.decode(var) == Some(var);
+    self.range.contains(&var) && is_on_scale
+  }
+
+  #[inline]
+  pub fn range(&self) -> &RangeInclusive<f64> {
+    &self.range
+  }
+
+  #[inline]
+  pub fn scale(&self) -> Scale {
+    self.scale
+  }
+
+  /// Random variable distributed according to the scale
+  #[cfg(feature = "rand")]
+  #[inline]
+  pub fn new_random_var<R>(&self, rng: &mut R) -> f64
+  where
+    R: Rng,
+  {
+    let (start, end) = (*self.range.start(), *self.range.end());
+    match self.scale {
+      Scale::Linear => rng.gen_range(self.range.clone()),
+      Scale::Log => Float::exp(rng.gen_range(Float::ln(start)..=Float::ln(end))).clamp(start, end),
+      Scale::Quantized(step) => {
+        let steps =
+          crate::Error::cast_rslt::<_, u64>(Float::floor((end - start) / step)).unwrap_or(u64::MAX);
+        let nth = crate::Error::cast_rslt::<_, f64>(rng.gen_range(0..=steps)).unwrap_or(0.0);
+        start + nth * step
+      }
+    }
+  }
+}
+
+impl NumVarDomain<f64> for ScaledRange {
+  #[inline]
+  fn decode(&self, value: f64) -> Option<f64> {
+    if value.is_nan() {
+      return None;
+    }
+    let (start, end) = (*self.range.start(), *self.range.end());
+    Some(match self.scale {
+      Scale::Linear => value.clamp(start, end),
+      Scale::Log => Float::exp(value).clamp(start, end),
+      Scale::Quantized(step) => {
+        let max_steps = Float::floor((end - start) / step);
+        let steps = Float::round((value - start) / step).clamp(0.0, max_steps);
+        start + steps * step
+      }
+    })
+  }
+
+  #[inline]
+  fn encode(&self, var: f64) -> Option<f64> {
+    Some(if let Scale::Log = self.scale { Float::ln(var) } else { var })
+  }
+
+  #[inline]
+  fn encoded_bounds(&self) -> Option<[f64; 2]> {
+    let (start, end) = (*self.range.start(), *self.range.end());
+    Some(if let Scale::Log = self.scale {
+      [Float::ln(start), Float::ln(end)]
+    } else {
+      [start, end]
+    })
+  }
+
+  /// Deserialized ranges bypass the validation of [`ScaledRange::new`]
+  #[inline]
+  fn is_valid(&self) -> bool {
+    let (start, end) = (*self.range.start(), *self.range.end());
+    start.is_finite()
+      && end.is_finite()
+      && start <= end
+      && match self.scale {
+        Scale::Linear => true,
+        Scale::Log => start > 0.0,
+        Scale::Quantized(step) => step.is_finite() && step > 0.0,
+      }
+  }
+}
+
+#[cfg(feature = "rand")]
+impl_num_var_domain!(
+  impl[const N: usize] Domain<[f64; N]> for [ScaledRange; N] as array
+  where [],
+  |domain, rng| domain.new_random_var(rng)
+);
+#[cfg(feature = "rand")]
+impl_num_var_domain!(
+  impl[const N: usize] Domain<arrayvec::ArrayVec<f64, N>>
+    for arrayvec::ArrayVec<ScaledRange, N> as collect
+  where [],
+  |domain, rng| domain.new_random_var(rng)
+);
+#[cfg(feature = "rand")]
+impl_num_var_domain!(
+  impl[] Domain<Vec<f64>> for Vec<ScaledRange> as collect
+  where [],
+  |domain, rng| domain.new_random_var(rng)
+);
+
+#[cfg(all(feature = "rand", test))]
+mod tests {
+  use crate::{Domain, NumVarDomain, ScaledRange};
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn scaled_range() {
+    let domain =
+      [ScaledRange::log(1e-6..=1.0).unwrap(), ScaledRange::quantized(0.0..=1.0, 0.25).unwrap()];
+    let mut small = 0;
+    for _ in 0..100 {
+      let [log, quantized] = domain.new_random_solution(&mut OsRng).unwrap();
+      assert!(domain[0].contains(log) && domain[1].contains(quantized));
+      if log < 1e-3 {
+        small += 1;
+      }
+    }
+    assert!(small > 10);
+    assert_eq!(domain[1].decode(0.6), Some(0.5));
+    assert_eq!(domain[1].decode(7.0), Some(1.0));
+    assert_eq!(domain[0].encoded_bounds().map(|[_, end]| end), Some(0.0));
+    assert!(ScaledRange::log(0.0..=1.0).is_err());
+  }
+}
@@ -0,0 +1,125 @@
+use core::ops::RangeInclusive;
+#[cfg(feature = "rand")]
+use {
+  super::{out_of_bounds_vars, update_vars},
+  crate::{Domain, NumVarDomain},
+  alloc::vec::Vec,
+  core::iter::{repeat, repeat_n},
+  num_traits::NumCast,
+  rand::{
+    distributions::{uniform::SampleUniform, Distribution, Uniform},
+    Rng,
+  },
+};
+
+/// Domain of `Vec` solutions whose number of variables is within `len` and whose variables are
+/// all within `var`, e.g., routes of variable sizes over the same set of nodes.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariableLengthDomain<T> {
+  len: RangeInclusive<usize>,
+  var: RangeInclusive<T>,
+}
+
+impl<T> VariableLengthDomain<T> {
+  #[inline]
+  pub fn new(len: RangeInclusive<usize>, var: RangeInclusive<T>) -> crate::Result<Self> {
+    if len.is_empty() {
+      return Err(crate::Error::EmptyElement);
+    }
+    Ok(Self { len, var })
+  }
+
+  #[inline]
+  pub fn var(&self) -> &RangeInclusive<T> {
+    &self.var
+  }
+
+  #[cfg(feature = "rand")]
+  fn clamp_len(&self, s: &mut Vec<T>)
+  where
+    T: Copy,
+  {
+    s.truncate(*self.len.end());
+    if s.len() < *self.len.start() {
+      s.resize(*self.len.start(), *self.var.start());
+    }
+  }
+}
+
+#[cfg(feature = "rand")]
+impl<T> Domain<Vec<T>> for VariableLengthDomain<T>
+where
+  T: Copy + NumCast + SampleUniform,
+  RangeInclusive<T>: NumVarDomain<T>,
+{
+  type Error = core::convert::Infallible;
+
+  /// Also truncates solutions that are longer than the maximum length and pads solutions that
+  /// are shorter than the minimum length with the start of the variable range.
+  #[inline]
+  fn clamp(&self, s: &mut Vec<T>) -> Option<()> {
+    self.clamp_len(s);
+    update_vars(repeat(&self.var), s, NumVarDomain::clamp);
+    Some(())
+  }
+
+  /// Deserialized domains bypass the validation of [`VariableLengthDomain::new`]
+  #[inline]
+  fn invalid_var(&self) -> Option<usize> {
+    (self.len.is_empty() || !self.var.is_valid()).then_some(0)
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    *self.len.end()
+  }
+
+  #[inline]
+  fn max_len(&self) -> usize {
+    *self.len.end()
+  }
+
+  #[inline]
+  fn min_len(&self) -> usize {
+    *self.len.start()
+  }
+
+  #[inline]
+  fn new_random_solution<R>(&self, rng: &mut R) -> Result<Vec<T>, Self::Error>
+  where
+    R: Rng,
+  {
+    let len = rng.gen_range(self.len.clone());
+    let uniform = Uniform::from(*self.var.start()..=*self.var.end());
+    Ok((0..len).map(|_| uniform.sample(rng)).collect())
+  }
+
+  /// Also includes the indices of the variables that exceed the maximum length or, for
+  /// solutions that are shorter than the minimum length, of the missing variables.
+  #[inline]
+  fn out_of_bounds_vars(&self, s: &Vec<T>) -> Option<Vec<usize>> {
+    let mut rslt = out_of_bounds_vars(repeat_n(&self.var, *self.len.end()), s);
+    rslt.extend(*self.len.end()..s.len());
+    rslt.extend(s.len()..*self.len.start());
+    Some(rslt)
+  }
+
+  /// Lengths are adjusted like in [`Domain::clamp`].
+  #[inline]
+  fn reflect(&self, s: &mut Vec<T>) -> Option<()> {
+    self.clamp_len(s);
+    update_vars(repeat(&self.var), s, NumVarDomain::reflect);
+    Some(())
+  }
+
+  #[inline]
+  fn set_rnd_domain<R>(&self, s: &mut Vec<T>, idx: usize, rng: &mut R)
+  where
+    R: Rng,
+  {
+    if let Some(var) = s.get_mut(idx) {
+      *var = Uniform::from(*self.var.start()..=*self.var.end()).sample(rng);
+    }
+  }
+}
@@ -11,6 +11,8 @@ pub enum Error {
   DrMatrixError(DrMatrixError),
   /// It wasn't possible to extract optional element
   EmptyElement,
+  /// Variables can't be inserted into or removed from a fixed-length solution
+  FixedLengthSolution,
   /// Insufficient capacity
   InsufficientCapacity,
   /// GpDefinitionsBuilder error
@@ -20,6 +22,8 @@ pub enum Error {
   NdsparseError(ndstruct::Error),
   /// An unspecified error occurred
   Other(&'static str),
+  /// Checkpoint was created with an incompatible format version
+  UnsupportedCheckpointVersion(u32),
   /// Unsupported conversion
   UnsupportedConversion,
 }
@@ -88,11 +92,13 @@ impl fmt::Display for Error {
       Self::BadCast => write!(f, "BadCast"),
       Self::DrMatrixError(x) => write!(f, "DrMatrixError({x})"),
       Self::EmptyElement => write!(f, "EmptyElement"),
+      Self::FixedLengthSolution => write!(f, "Fixed-length solution"),
       Self::InsufficientCapacity => write!(f, "Insufficient capacity"),
       Self::Gdbe(x) => write!(f, "GDBE({x})"),
       #[cfg(feature = "ndstruct")]
       Self::NdsparseError(x) => write!(f, "NdsparseError({x})"),
       Self::Other(x) => write!(f, "Other({x})"),
+      Self::UnsupportedCheckpointVersion(x) => write!(f, "Unsupported checkpoint version ({x})"),
       Self::UnsupportedConversion => write!(f, "Unsupported conversion"),
     }
   }
@@ -2,6 +2,7 @@
 
 mod gp_definitions;
 mod gp_definitions_builder;
+mod gp_evaluations;
 mod gp_operations;
 mod gp_or;
 mod gp_ors;
@@ -14,6 +15,7 @@ use arrayvec::ArrayVec;
 use cl_aux::{Push, SingleTypeStorage, WithCapacity};
 pub use gp_definitions::*;
 pub use gp_definitions_builder::*;
+pub use gp_evaluations::*;
 pub use gp_operations::{mph_mp_mph::*, mphs_mp_mphs::*, *};
 pub use gp_or::*;
 pub use gp_ors::*;
@@ -113,7 +115,7 @@ where
   #[inline]
   pub fn with_capacity(defs: GpDefinitions<D, HCS, OS, SCS>, rslts_num: usize) -> Self {
     let ors = GpOrs::with_capacity(&defs, rslts_num);
-    Self { defs, ors }
+    Self::new(defs, ors)
   }
 
   #[cfg(feature = "rand")]
@@ -132,7 +134,33 @@ where
     let mut ors = GpOrs::with_capacity(&defs, rslts_num);
     let fun = (0..rslts_num).map(|_| defs.domain().new_random_solution(&mut rng));
     let _ = ors.constructor().ors_s_iter(fun);
-    Ok(Self { defs, ors })
+    Ok(Self::new(defs, ors))
+  }
+
+  /// Initial population generated at once by `sampler`, e.g.,
+  /// [`LatinHypercube`](crate::sampler::LatinHypercube).
+  #[cfg(feature = "rand")]
+  #[inline]
+  pub fn with_sampled_solutions<R, SA>(
+    defs: GpDefinitions<D, HCS, OS, SCS>,
+    rslts_num: usize,
+    sampler: &SA,
+    rng: &mut R,
+  ) -> crate::Result<Self>
+  where
+    HCR: Clone + Default,
+    OR: Clone + Default,
+    R: rand::Rng,
+    SA: crate::sampler::Sampler<D, S>,
+    SA::Error: Into<crate::Error>,
+    SCR: Clone + Default,
+  {
+    let solutions = sampler.sample(defs.domain(), rslts_num, rng).map_err(Into::into)?;
+    let mut ors = GpOrs::with_capacity(&defs, rslts_num);
+    if ors.constructor().ors_s_iter(solutions.into_iter().map(Ok::<_, ()>)).is_none() {
+      return Err(crate::Error::InsufficientCapacity);
+    }
+    Ok(Self::new(defs, ors))
   }
 
   #[inline]
@@ -149,6 +177,13 @@ where
   {
     let mut ors = GpOrs::with_capacity(&defs, rslts_num);
     let _ = ors.constructor().ors_s_iter((0..rslts_num).map(|idx| Ok::<_, ()>(f(idx))));
+    Self::new(defs, ors)
+  }
+
+  fn new(mut defs: GpDefinitions<D, HCS, OS, SCS>, ors: GpOrs<HCRS, ORS, SCRS, SS>) -> Self {
+    let [hard_cstrs, objs, soft_cstrs] =
+      [defs.hard_cstrs().len(), defs.objs().len(), defs.soft_cstrs().len()];
+    defs.evaluations.resize(hard_cstrs, objs, soft_cstrs);
     Self { defs, ors }
   }
 }
@@ -1,4 +1,4 @@
-use crate::gp::{GpDefinitionsBuilder, NoCstr, OneObj};
+use crate::gp::{GpDefinitionsBuilder, GpEvaluationsCounter, NoCstr, OneObj};
 use alloc::vec::Vec;
 use cl_aux::SingleTypeStorage;
 
@@ -27,6 +27,7 @@ pub type SpDefinitionsVec<D, O> = SpDefinitions<D, OneObj<O>>;
 #[derive(Clone, Debug)]
 pub struct GpDefinitions<D, HCS, OS, SCS> {
   pub(crate) domain: D,
+  pub(crate) evaluations: GpEvaluationsCounter,
   pub(crate) hard_cstrs: HCS,
   pub(crate) name: &'static str,
   pub(crate) objs: OS,
@@ -50,6 +51,12 @@ impl<D, HCS, OS, SCS> GpDefinitions<D, HCS, OS, SCS> {
     &self.domain
   }
 
+  /// Evaluations performed through `GpOrsEvaluators`
+  #[inline]
+  pub fn evaluations(&self) -> &GpEvaluationsCounter {
+    &self.evaluations
+  }
+
   #[inline]
   pub fn name(&self) -> &str {
     self.name
@@ -108,6 +115,7 @@ where
   fn default() -> Self {
     Self {
       domain: D::default(),
+      evaluations: <_>::default(),
       hard_cstrs: <_>::default(),
       name: <_>::default(),
       objs: OS::default(),
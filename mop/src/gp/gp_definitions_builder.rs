@@ -1,4 +1,7 @@
-use crate::gp::{GpDefinitions, NoCstr, OneObj};
+use crate::{
+  gp::{GpDefinitions, NoCstr, OneObj},
+  Domain,
+};
 use alloc::vec::Vec;
 use cl_aux::{Push, SingleTypeStorage};
 use core::fmt;
@@ -36,20 +39,29 @@ pub struct GpDefinitionsBuilder<D, HCS, OS, SCS> {
 }
 
 impl<D, HCS, OS, SCS> GpDefinitionsBuilder<D, HCS, OS, SCS> {
+  /// The domain is validated through [`Domain::invalid_var`], so invalid domains don't panic in
+  /// the middle of an optimization. `S` is the solution type and only needs to be annotated when
+  /// `D` is the domain of several solution types, e.g., arrays of ranges are also domains of
+  /// `Csl` solutions.
   #[inline]
-  pub fn build(self) -> crate::Result<GpDefinitions<D, HCS, OS, SCS>>
+  pub fn build<S>(self) -> crate::Result<GpDefinitions<D, HCS, OS, SCS>>
   where
+    D: Domain<S>,
     HCS: Default,
     SCS: Default,
   {
     let Some(domain) = self.domain else {
       return Err(GpDefinitionsBuilderError::NoDomainForDefinitionsBuilder.into());
     };
+    if let Some(idx) = domain.invalid_var() {
+      return Err(GpDefinitionsBuilderError::InvalidDomain(idx).into());
+    }
     let Some(objs) = self.objs else {
       return Err(GpDefinitionsBuilderError::NoObjForDefinitionsBuilder.into());
     };
     Ok(GpDefinitions {
       domain,
+      evaluations: <_>::default(),
       hard_cstrs: self.hard_cstrs.unwrap_or_default(),
       name: self.name,
       soft_cstrs: self.soft_cstrs.unwrap_or_default(),
@@ -213,6 +225,9 @@ impl<D, HCS, OS, SCS> Default for GpDefinitionsBuilder<D, HCS, OS, SCS> {
 #[derive(Debug, PartialEq)]
 #[non_exhaustive]
 pub enum GpDefinitionsBuilderError {
+  /// The domain of the variable of the given index can't produce variables, e.g., an inverted
+  /// range
+  InvalidDomain(usize),
   /// A domain must be included
   NoDomainForDefinitionsBuilder,
   /// Must have at least one objective
@@ -226,9 +241,29 @@ impl fmt::Display for GpDefinitionsBuilderError {
   #[inline]
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     let s = match *self {
+      Self::InvalidDomain(idx) => return write!(f, "InvalidDomain({idx})"),
       Self::NoDomainForDefinitionsBuilder => "NoDomainForDefinitionsBuilder",
       Self::NoObjForDefinitionsBuilder => "NoObjForDefinitionsBuilder",
     };
     write!(f, "{s}")
   }
 }
+
+#[cfg(all(feature = "rand", test))]
+mod tests {
+  use crate::{
+    gp::{GpDefinitionsBuilderError, MpDefinitionsBuilder},
+    ObjDirection,
+  };
+  use alloc::vec;
+
+  #[test]
+  fn build_with_invalid_domain() {
+    let obj: fn(&[f64; 2]) -> f64 = |_| 0.0;
+    let rslt = MpDefinitionsBuilder::default()
+      .domain([0.0..=1.0, 1.0..=0.0])
+      .objs(vec![(ObjDirection::Min, obj)])
+      .build::<[f64; 2]>();
+    assert_eq!(rslt.err(), Some(GpDefinitionsBuilderError::InvalidDomain(1).into()));
+  }
+}
@@ -0,0 +1,153 @@
+use alloc::vec::Vec;
+use core::{
+  ops::Add,
+  sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Number of times each objective and each constraint of a problem was evaluated, indexed by
+/// the position of the function in its definitions storage.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GpEvaluations {
+  hard_cstrs: Vec<usize>,
+  objs: Vec<usize>,
+  soft_cstrs: Vec<usize>,
+}
+
+impl GpEvaluations {
+  #[inline]
+  pub fn new(hard_cstrs: Vec<usize>, objs: Vec<usize>, soft_cstrs: Vec<usize>) -> Self {
+    Self { hard_cstrs, objs, soft_cstrs }
+  }
+
+  /// Evaluations of each hard constraint
+  #[inline]
+  pub fn hard_cstrs(&self) -> &[usize] {
+    &self.hard_cstrs
+  }
+
+  /// Greatest number of evaluations among all objectives
+  #[inline]
+  pub fn max_objs(&self) -> usize {
+    self.objs.iter().copied().max().unwrap_or_default()
+  }
+
+  /// Evaluations of each objective
+  #[inline]
+  pub fn objs(&self) -> &[usize] {
+    &self.objs
+  }
+
+  /// Evaluations of each soft constraint
+  #[inline]
+  pub fn soft_cstrs(&self) -> &[usize] {
+    &self.soft_cstrs
+  }
+
+  /// Evaluations performed since `earlier`
+  #[inline]
+  #[must_use]
+  pub fn since(&self, earlier: &Self) -> Self {
+    let since = |this: &[usize], earlier: &[usize]| {
+      this
+        .iter()
+        .enumerate()
+        .map(|(idx, elem)| elem.saturating_sub(earlier.get(idx).copied().unwrap_or_default()))
+        .collect()
+    };
+    Self {
+      hard_cstrs: since(&self.hard_cstrs, &earlier.hard_cstrs),
+      objs: since(&self.objs, &earlier.objs),
+      soft_cstrs: since(&self.soft_cstrs, &earlier.soft_cstrs),
+    }
+  }
+}
+
+impl Add for GpEvaluations {
+  type Output = Self;
+
+  #[inline]
+  fn add(self, rhs: Self) -> Self::Output {
+    let add = |mut this: Vec<usize>, rhs: Vec<usize>| {
+      if this.len() < rhs.len() {
+        this.resize(rhs.len(), 0);
+      }
+      for (elem, rhs_elem) in this.iter_mut().zip(rhs) {
+        *elem = elem.saturating_add(rhs_elem);
+      }
+      this
+    };
+    Self {
+      hard_cstrs: add(self.hard_cstrs, rhs.hard_cstrs),
+      objs: add(self.objs, rhs.objs),
+      soft_cstrs: add(self.soft_cstrs, rhs.soft_cstrs),
+    }
+  }
+}
+
+/// Thread-safe counters of [`GpEvaluations`] stored in the problem definitions.
+///
+/// Counters are sized according to the number of definitions when a [`Gp`](crate::gp::Gp) is
+/// created.
+#[derive(Debug, Default)]
+pub struct GpEvaluationsCounter {
+  hard_cstrs: Vec<AtomicUsize>,
+  objs: Vec<AtomicUsize>,
+  soft_cstrs: Vec<AtomicUsize>,
+}
+
+impl GpEvaluationsCounter {
+  /// Sets all counters to zero
+  #[inline]
+  pub fn reset(&self) {
+    for counter in self.hard_cstrs.iter().chain(&self.objs).chain(&self.soft_cstrs) {
+      counter.store(0, Ordering::Relaxed);
+    }
+  }
+
+  /// Current values
+  #[inline]
+  pub fn snapshot(&self) -> GpEvaluations {
+    let load = |counters: &[AtomicUsize]| {
+      counters.iter().map(|counter| counter.load(Ordering::Relaxed)).collect()
+    };
+    GpEvaluations {
+      hard_cstrs: load(&self.hard_cstrs),
+      objs: load(&self.objs),
+      soft_cstrs: load(&self.soft_cstrs),
+    }
+  }
+
+  pub(crate) fn add_hard_cstr(&self, idx: usize, evaluations: usize) {
+    Self::add(&self.hard_cstrs, idx, evaluations);
+  }
+
+  pub(crate) fn add_obj(&self, idx: usize, evaluations: usize) {
+    Self::add(&self.objs, idx, evaluations);
+  }
+
+  pub(crate) fn add_soft_cstr(&self, idx: usize, evaluations: usize) {
+    Self::add(&self.soft_cstrs, idx, evaluations);
+  }
+
+  pub(crate) fn resize(&mut self, hard_cstrs: usize, objs: usize, soft_cstrs: usize) {
+    self.hard_cstrs.resize_with(hard_cstrs, AtomicUsize::default);
+    self.objs.resize_with(objs, AtomicUsize::default);
+    self.soft_cstrs.resize_with(soft_cstrs, AtomicUsize::default);
+  }
+
+  fn add(counters: &[AtomicUsize], idx: usize, evaluations: usize) {
+    if let Some(counter) = counters.get(idx) {
+      let _ = counter.fetch_add(evaluations, Ordering::Relaxed);
+    }
+  }
+}
+
+impl Clone for GpEvaluationsCounter {
+  #[inline]
+  fn clone(&self) -> Self {
+    let GpEvaluations { hard_cstrs, objs, soft_cstrs } = self.snapshot();
+    let new = |values: Vec<usize>| values.into_iter().map(AtomicUsize::new).collect();
+    Self { hard_cstrs: new(hard_cstrs), objs: new(objs), soft_cstrs: new(soft_cstrs) }
+  }
+}
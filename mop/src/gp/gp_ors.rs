@@ -43,6 +43,7 @@ pub type SpOrsVec<OR, S> = SpOrs<Vec<OR>, Vec<S>>;
 /// * `ORS`: Objectives Results Storage
 /// * `ORAS`: Objective Results Average Storage
 /// * `SS`: Solutions Storage
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct GpOrs<HCRS, ORS, SCRS, SS> {
   pub(crate) hard_cstr_rslts: DrMatrix<HCRS>,
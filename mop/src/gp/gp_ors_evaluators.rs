@@ -40,6 +40,9 @@ where
     SCRS: AsMut<[SCR]> + SingleTypeStorage<Item = SCR>,
   {
     let hard_cstrs = defs.hard_cstrs();
+    for idx in 0..hard_cstrs.len() {
+      defs.evaluations.add_hard_cstr(idx, rslts.rslts_num());
+    }
     Self::eval_cstrs_rslts(hard_cstrs, rslts, |rslt| (rslt.hard_cstr_rslts, rslt.solution));
   }
 
@@ -56,6 +59,9 @@ where
     SCS: AsRef<[SC]> + SingleTypeStorage<Item = SC>,
   {
     let soft_cstrs = defs.soft_cstrs();
+    for idx in 0..soft_cstrs.len() {
+      defs.evaluations.add_soft_cstr(idx, rslts.rslts_num());
+    }
     Self::eval_cstrs_rslts(soft_cstrs, rslts, |rslt| (rslt.soft_cstr_rslts, rslt.solution));
   }
 
@@ -109,7 +115,11 @@ where
   {
     let cols = rslts.hard_cstr_rslts.cols();
     let rows = rslts.hard_cstr_rslts.rows();
-    Self::eval_cstrs_reasons([rows, cols], defs.hard_cstrs(), rslts)
+    let hard_cstrs = defs.hard_cstrs();
+    for idx in 0..hard_cstrs.len() {
+      defs.evaluations.add_hard_cstr(idx, rows);
+    }
+    Self::eval_cstrs_reasons([rows, cols], hard_cstrs, rslts)
   }
 
   #[inline]
@@ -123,7 +133,11 @@ where
   {
     let cols = rslts.soft_cstr_rslts.cols();
     let rows = rslts.soft_cstr_rslts.rows();
-    Self::eval_cstrs_reasons([rows, cols], defs.soft_cstrs(), rslts)
+    let soft_cstrs = defs.soft_cstrs();
+    for idx in 0..soft_cstrs.len() {
+      defs.evaluations.add_soft_cstr(idx, rows);
+    }
+    Self::eval_cstrs_reasons([rows, cols], soft_cstrs, rslts)
   }
 
   #[inline]
@@ -169,6 +183,9 @@ where
 {
   #[inline]
   pub fn eval_objs(defs: &GpDefinitions<D, HCS, OS, SCS>, rslts: &mut GpOrs<HCRS, ORS, SCRS, SS>) {
+    for idx in 0..defs.objs().len() {
+      defs.evaluations.add_obj(idx, rslts.rslts_num());
+    }
     let func = |mut r| Self::eval_objs_for_solution(defs.objs(), &mut r);
     #[cfg(feature = "rayon")]
     rslts.par_iter_mut().for_each(func);
@@ -3,7 +3,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
+#[cfg(all(feature = "derive", test))]
+extern crate self as mop;
 
+mod bit_string;
 mod criteria;
 pub mod doc_tests;
 mod domain;
@@ -14,27 +17,43 @@ mod error;
 pub mod wasm_bindgen;
 
 pub mod gp;
+mod mixed_var;
 mod obj_direction;
 pub mod objs;
 pub mod opt;
 mod par_bounds;
 mod pct;
 pub mod quality_comparator;
+#[cfg(feature = "rand")]
+pub mod sampler;
 mod solution;
 mod solver;
 #[cfg(feature = "solvers")]
 pub mod solvers;
 pub mod utils;
 
+pub use bit_string::*;
 pub use criteria::{cstr::*, obj::*};
 pub use domain::*;
 pub use either::Either;
 pub use error::*;
+pub use mixed_var::*;
+#[cfg(feature = "derive")]
+pub use mop_derive::Solution;
 pub use obj_direction::*;
 pub use par_bounds::ParBounds;
 pub use pct::*;
 pub use solution::*;
-pub use solver::Solver;
+pub use solver::{Progress, Solver, SolverCheckpoint};
+
+/// Items used by the code generated by the `derive` feature
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+  pub use alloc::vec::Vec;
+  #[cfg(feature = "rand")]
+  pub use rand;
+}
 
 pub type Result<T> = core::result::Result<T, Error>;
 
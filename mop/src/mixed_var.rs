@@ -0,0 +1,350 @@
+mod conditional_domain;
+
+use crate::NumVarDomain;
+use alloc::{string::String, vec::Vec};
+pub use conditional_domain::{Condition, ConditionalDomain};
+use core::ops::RangeInclusive;
+#[cfg(feature = "rand")]
+use {crate::Domain, rand::Rng};
+
+/// Variable of a solution that mixes different kinds of variables. Must match the
+/// [`MixedVarDomain`] of the same index.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MixedVar {
+  Bool(bool),
+  /// Index of a label
+  Categorical(usize),
+  Continuous(f64),
+  /// Variable of a [`ConditionalDomain`] whose condition isn't met
+  Inactive,
+  Integer(i64),
+}
+
+impl MixedVar {
+  #[inline]
+  pub fn as_bool(&self) -> Option<bool> {
+    if let Self::Bool(elem) = *self {
+      Some(elem)
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  pub fn as_categorical(&self) -> Option<usize> {
+    if let Self::Categorical(elem) = *self {
+      Some(elem)
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  pub fn as_continuous(&self) -> Option<f64> {
+    if let Self::Continuous(elem) = *self {
+      Some(elem)
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  pub fn as_integer(&self) -> Option<i64> {
+    if let Self::Integer(elem) = *self {
+      Some(elem)
+    } else {
+      None
+    }
+  }
+
+  /// If this variable isn't [`MixedVar::Inactive`]
+  #[inline]
+  pub fn is_active(&self) -> bool {
+    !matches!(self, Self::Inactive)
+  }
+}
+
+/// Domain of one variable of a mixed solution.
+///
+/// `[MixedVarDomain; N]` and `ArrayVec<MixedVarDomain, N>` are domains of `[MixedVar; N]` and
+/// `ArrayVec<MixedVar, N>` respectively.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MixedVarDomain {
+  Bool,
+  /// Set of labels referenced by their indices
+  Categorical(Vec<String>),
+  Continuous(RangeInclusive<f64>),
+  Integer(RangeInclusive<i64>),
+}
+
+impl MixedVarDomain {
+  /// Moves `var` to the nearest value of this domain. Variables of other kinds are returned as is.
+  #[inline]
+  pub fn clamp(&self, var: MixedVar) -> MixedVar {
+    match (self, var) {
+      (Self::Continuous(range), MixedVar::Continuous(value)) => {
+        range.clamp(value).map_or(var, MixedVar::Continuous)
+      }
+      (Self::Integer(range), MixedVar::Integer(value)) => {
+        range.clamp(value).map_or(var, MixedVar::Integer)
+      }
+      _ => self.clamp_categorical(var),
+    }
+  }
+
+  /// If `var` has the kind of this domain and is within its bounds.
+  #[inline]
+  pub fn contains(&self, var: &MixedVar) -> bool {
+    match (self, var) {
+      (Self::Bool, MixedVar::Bool(_)) => true,
+      (Self::Categorical(labels), MixedVar::Categorical(idx)) => *idx < labels.len(),
+      (Self::Continuous(range), MixedVar::Continuous(value)) => range.contains(value),
+      (Self::Integer(range), MixedVar::Integer(value)) => range.contains(value),
+      _ => false,
+    }
+  }
+
+  /// If this domain can produce variables, e.g., categorical domains need at least one label.
+  #[inline]
+  pub fn is_valid(&self) -> bool {
+    match self {
+      Self::Bool => true,
+      Self::Categorical(labels) => !labels.is_empty(),
+      Self::Continuous(range) => range.is_valid(),
+      Self::Integer(range) => range.is_valid(),
+    }
+  }
+
+  /// Label of a categorical variable
+  #[inline]
+  pub fn label(&self, var: &MixedVar) -> Option<&str> {
+    if let (Self::Categorical(labels), MixedVar::Categorical(idx)) = (self, var) {
+      labels.get(*idx).map(String::as_str)
+    } else {
+      None
+    }
+  }
+
+  /// Mirrors `var` back into this domain. Categorical variables are clamped and variables of
+  /// other kinds are returned as is.
+  #[inline]
+  pub fn reflect(&self, var: MixedVar) -> MixedVar {
+    match (self, var) {
+      (Self::Continuous(range), MixedVar::Continuous(value)) => {
+        range.reflect(value).map_or(var, MixedVar::Continuous)
+      }
+      (Self::Integer(range), MixedVar::Integer(value)) => {
+        range.reflect(value).map_or(var, MixedVar::Integer)
+      }
+      _ => self.clamp_categorical(var),
+    }
+  }
+
+  /// Random variable of this domain. Categorical domains without labels and empty ranges are
+  /// invalid.
+  #[cfg(feature = "rand")]
+  #[inline]
+  pub fn new_random_var<R>(&self, rng: &mut R) -> crate::Result<MixedVar>
+  where
+    R: Rng,
+  {
+    Ok(match self {
+      Self::Bool => MixedVar::Bool(rng.gen()),
+      Self::Categorical(labels) => {
+        if labels.is_empty() {
+          return Err(crate::Error::EmptyElement);
+        }
+        MixedVar::Categorical(rng.gen_range(0..labels.len()))
+      }
+      Self::Continuous(range) => {
+        if range.is_empty() {
+          return Err(crate::Error::EmptyElement);
+        }
+        MixedVar::Continuous(rng.gen_range(range.clone()))
+      }
+      Self::Integer(range) => {
+        if range.is_empty() {
+          return Err(crate::Error::EmptyElement);
+        }
+        MixedVar::Integer(rng.gen_range(range.clone()))
+      }
+    })
+  }
+
+  fn clamp_categorical(&self, var: MixedVar) -> MixedVar {
+    match (self, var) {
+      (Self::Categorical(labels), MixedVar::Categorical(idx)) if !labels.is_empty() => {
+        MixedVar::Categorical(idx.min(labels.len() - 1))
+      }
+      _ => var,
+    }
+  }
+}
+
+#[cfg(feature = "rand")]
+impl<const N: usize> Domain<[MixedVar; N]> for [MixedVarDomain; N] {
+  type Error = crate::Error;
+
+  #[inline]
+  fn clamp(&self, s: &mut [MixedVar; N]) -> Option<()> {
+    update_vars(self, s, MixedVarDomain::clamp);
+    Some(())
+  }
+
+  #[inline]
+  fn invalid_var(&self) -> Option<usize> {
+    self.iter().position(|domain| !domain.is_valid())
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    N
+  }
+
+  #[inline]
+  fn new_random_solution<R>(&self, rng: &mut R) -> Result<[MixedVar; N], Self::Error>
+  where
+    R: Rng,
+  {
+    let mut s = [MixedVar::Bool(false); N];
+    for (var, domain) in s.iter_mut().zip(self) {
+      *var = domain.new_random_var(rng)?;
+    }
+    Ok(s)
+  }
+
+  #[inline]
+  fn out_of_bounds_vars(&self, s: &[MixedVar; N]) -> Option<Vec<usize>> {
+    Some(out_of_bounds_vars(self, s))
+  }
+
+  #[inline]
+  fn reflect(&self, s: &mut [MixedVar; N]) -> Option<()> {
+    update_vars(self, s, MixedVarDomain::reflect);
+    Some(())
+  }
+
+  #[inline]
+  fn set_rnd_domain<R>(&self, s: &mut [MixedVar; N], idx: usize, rng: &mut R)
+  where
+    R: Rng,
+  {
+    if let Ok(var) = self[idx].new_random_var(rng) {
+      s[idx] = var;
+    }
+  }
+}
+
+#[cfg(feature = "rand")]
+impl<const N: usize> Domain<arrayvec::ArrayVec<MixedVar, N>>
+  for arrayvec::ArrayVec<MixedVarDomain, N>
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn clamp(&self, s: &mut arrayvec::ArrayVec<MixedVar, N>) -> Option<()> {
+    update_vars(self, s, MixedVarDomain::clamp);
+    Some(())
+  }
+
+  #[inline]
+  fn invalid_var(&self) -> Option<usize> {
+    self.iter().position(|domain| !domain.is_valid())
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.len()
+  }
+
+  #[inline]
+  fn new_random_solution<R>(
+    &self,
+    rng: &mut R,
+  ) -> Result<arrayvec::ArrayVec<MixedVar, N>, Self::Error>
+  where
+    R: Rng,
+  {
+    let mut s = arrayvec::ArrayVec::new();
+    for domain in self {
+      s.push(domain.new_random_var(rng)?);
+    }
+    Ok(s)
+  }
+
+  #[inline]
+  fn out_of_bounds_vars(&self, s: &arrayvec::ArrayVec<MixedVar, N>) -> Option<Vec<usize>> {
+    Some(out_of_bounds_vars(self, s))
+  }
+
+  #[inline]
+  fn reflect(&self, s: &mut arrayvec::ArrayVec<MixedVar, N>) -> Option<()> {
+    update_vars(self, s, MixedVarDomain::reflect);
+    Some(())
+  }
+
+  #[inline]
+  fn set_rnd_domain<R>(&self, s: &mut arrayvec::ArrayVec<MixedVar, N>, idx: usize, rng: &mut R)
+  where
+    R: Rng,
+  {
+    if let Ok(var) = self[idx].new_random_var(rng) {
+      s[idx] = var;
+    }
+  }
+}
+
+/// Indices of the variables of `vars` that don't belong to their respective domains
+#[cfg(feature = "rand")]
+fn out_of_bounds_vars<'any, DI, VI>(domains: DI, vars: VI) -> Vec<usize>
+where
+  DI: IntoIterator<Item = &'any MixedVarDomain>,
+  VI: IntoIterator<Item = &'any MixedVar>,
+{
+  domains
+    .into_iter()
+    .zip(vars)
+    .enumerate()
+    .filter_map(|(idx, (domain, var))| (!domain.contains(var)).then_some(idx))
+    .collect()
+}
+
+/// Replaces every variable of `vars` with the result of `f`, e.g., [`MixedVarDomain::clamp`]
+#[cfg(feature = "rand")]
+fn update_vars<'any, DI, VI>(domains: DI, vars: VI, f: fn(&MixedVarDomain, MixedVar) -> MixedVar)
+where
+  DI: IntoIterator<Item = &'any MixedVarDomain>,
+  VI: IntoIterator<Item = &'any mut MixedVar>,
+{
+  for (domain, var) in domains.into_iter().zip(vars) {
+    *var = f(domain, *var);
+  }
+}
+
+#[cfg(all(feature = "rand", test))]
+mod tests {
+  use crate::{Domain, MixedVar, MixedVarDomain};
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn mixed_var_domain() {
+    let domain = [
+      MixedVarDomain::Continuous(-1.0..=1.0),
+      MixedVarDomain::Integer(1..=5),
+      MixedVarDomain::Categorical(["a".into(), "b".into()].into()),
+      MixedVarDomain::Bool,
+    ];
+    let solution = domain.new_random_solution(&mut OsRng).unwrap();
+    assert!(domain.iter().zip(&solution).all(|(d, v)| d.contains(v)));
+    assert!(domain[2].label(&solution[2]).is_some());
+    assert!(!domain[0].contains(&MixedVar::Integer(0)));
+    assert!(MixedVarDomain::Categorical([].into()).new_random_var(&mut OsRng).is_err());
+    assert!(!MixedVarDomain::Continuous(f64::NEG_INFINITY..=f64::INFINITY).is_valid());
+    assert_eq!(
+      MixedVarDomain::Integer(0..=i64::MAX).clamp(MixedVar::Integer(-1)),
+      MixedVar::Integer(0)
+    );
+  }
+}
@@ -0,0 +1,236 @@
+use crate::{Domain, MixedVar, MixedVarDomain};
+use alloc::vec::Vec;
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+/// Activates a variable only when the variable of index `parent` has one of `values`.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Condition {
+  parent: usize,
+  values: Vec<MixedVar>,
+}
+
+impl Condition {
+  #[inline]
+  pub fn new(parent: usize, values: Vec<MixedVar>) -> Self {
+    Self { parent, values }
+  }
+
+  #[inline]
+  pub fn parent(&self) -> usize {
+    self.parent
+  }
+
+  #[inline]
+  pub fn values(&self) -> &[MixedVar] {
+    &self.values
+  }
+}
+
+/// Domain of `Vec<MixedVar>` solutions where some variables only exist when other variables have
+/// particular values, e.g., a kernel degree that only applies to a polynomial kernel.
+///
+/// Parents always precede their children, so activations are resolved in a single pass.
+/// Variables whose conditions aren't met, directly or through an inactive parent, are
+/// [`MixedVar::Inactive`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConditionalDomain {
+  vars: Vec<(MixedVarDomain, Option<Condition>)>,
+}
+
+impl ConditionalDomain {
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Domain and condition of each variable
+  #[inline]
+  pub fn vars(&self) -> &[(MixedVarDomain, Option<Condition>)] {
+    &self.vars
+  }
+
+  /// If the variable of index `idx` is active according to the variables of `s`.
+  #[inline]
+  pub fn is_var_active(&self, s: &[MixedVar], idx: usize) -> bool {
+    match self.vars.get(idx) {
+      Some((_, None)) => true,
+      Some((_, Some(condition))) => {
+        s.get(condition.parent).is_some_and(|parent| condition.values.contains(parent))
+      }
+      None => false,
+    }
+  }
+
+  /// Adds a variable that is always active.
+  #[inline]
+  #[must_use]
+  pub fn push(mut self, domain: MixedVarDomain) -> Self {
+    self.vars.push((domain, None));
+    self
+  }
+
+  /// Adds a variable that is only active when `condition` is met. The parent must be already
+  /// added and `values` must belong to its domain.
+  #[inline]
+  pub fn push_conditional(
+    mut self,
+    domain: MixedVarDomain,
+    condition: Condition,
+  ) -> crate::Result<Self> {
+    let (parent_domain, _) = crate::Error::opt_rslt(self.vars.get(condition.parent))?;
+    if !condition.values.iter().all(|value| parent_domain.contains(value)) {
+      return Err(crate::Error::Other("Condition values must belong to the parent domain"));
+    }
+    self.vars.push((domain, Some(condition)));
+    Ok(self)
+  }
+
+  /// Marks variables whose conditions aren't met as inactive and gives random values to active
+  /// variables that are inactive or outside their domains.
+  #[cfg(feature = "rand")]
+  #[inline]
+  pub fn update_activations<R>(&self, s: &mut [MixedVar], rng: &mut R) -> crate::Result<()>
+  where
+    R: Rng,
+  {
+    for (idx, (domain, _)) in self.vars.iter().enumerate() {
+      let is_active = self.is_var_active(s, idx);
+      let Some(var) = s.get_mut(idx) else {
+        break;
+      };
+      if !is_active {
+        *var = MixedVar::Inactive;
+      } else if !domain.contains(var) {
+        *var = domain.new_random_var(rng)?;
+      }
+    }
+    Ok(())
+  }
+
+  fn update_active_vars(&self, s: &mut [MixedVar], f: fn(&MixedVarDomain, MixedVar) -> MixedVar) {
+    for (idx, (domain, _)) in self.vars.iter().enumerate() {
+      let is_active = self.is_var_active(s, idx);
+      let Some(var) = s.get_mut(idx) else {
+        break;
+      };
+      if is_active {
+        *var = f(domain, *var);
+      }
+    }
+  }
+}
+
+impl Domain<Vec<MixedVar>> for ConditionalDomain {
+  type Error = crate::Error;
+
+  /// Inactive variables are left untouched
+  #[inline]
+  fn clamp(&self, s: &mut Vec<MixedVar>) -> Option<()> {
+    self.update_active_vars(s, MixedVarDomain::clamp);
+    Some(())
+  }
+
+  #[inline]
+  fn invalid_var(&self) -> Option<usize> {
+    self.vars.iter().position(|(domain, _)| !domain.is_valid())
+  }
+
+  #[inline]
+  fn is_active(&self, s: &Vec<MixedVar>, idx: usize) -> bool {
+    self.is_var_active(s, idx)
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.vars.len()
+  }
+
+  #[cfg(feature = "rand")]
+  #[inline]
+  fn new_random_solution<R>(&self, rng: &mut R) -> Result<Vec<MixedVar>, Self::Error>
+  where
+    R: Rng,
+  {
+    let mut s = alloc::vec![MixedVar::Inactive; self.vars.len()];
+    self.update_activations(&mut s, rng)?;
+    Ok(s)
+  }
+
+  /// Inactive variables are out of bounds if they aren't [`MixedVar::Inactive`]
+  #[inline]
+  fn out_of_bounds_vars(&self, s: &Vec<MixedVar>) -> Option<Vec<usize>> {
+    let rslt = self
+      .vars
+      .iter()
+      .zip(s)
+      .enumerate()
+      .filter_map(|(idx, ((domain, _), var))| {
+        let is_valid =
+          if self.is_var_active(s, idx) { domain.contains(var) } else { !var.is_active() };
+        (!is_valid).then_some(idx)
+      })
+      .collect();
+    Some(rslt)
+  }
+
+  /// Inactive variables are left untouched
+  #[inline]
+  fn reflect(&self, s: &mut Vec<MixedVar>) -> Option<()> {
+    self.update_active_vars(s, MixedVarDomain::reflect);
+    Some(())
+  }
+
+  #[cfg(feature = "rand")]
+  #[inline]
+  fn set_rnd_domain<R>(&self, s: &mut Vec<MixedVar>, idx: usize, rng: &mut R)
+  where
+    R: Rng,
+  {
+    if !self.is_var_active(s, idx) {
+      return;
+    }
+    if let (Some((domain, _)), Some(var)) = (self.vars.get(idx), s.get_mut(idx)) {
+      if let Ok(elem) = domain.new_random_var(rng) {
+        *var = elem;
+      }
+    }
+    let _rslt = self.update_activations(s, rng);
+  }
+}
+
+#[cfg(all(feature = "rand", test))]
+mod tests {
+  use crate::{Condition, ConditionalDomain, Domain, MixedVar, MixedVarDomain};
+  use alloc::vec;
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn conditional_domain() {
+    let domain = ConditionalDomain::new()
+      .push(MixedVarDomain::Categorical(vec!["linear".into(), "poly".into()]))
+      .push_conditional(
+        MixedVarDomain::Integer(2..=5),
+        Condition::new(0, vec![MixedVar::Categorical(1)]),
+      )
+      .unwrap();
+    for _ in 0..20 {
+      let s = domain.new_random_solution(&mut OsRng).unwrap();
+      assert_eq!(s[1].is_active(), s[0] == MixedVar::Categorical(1));
+      assert_eq!(domain.is_active(&s, 1), s[1].is_active());
+    }
+    let mut s = vec![MixedVar::Categorical(0), MixedVar::Integer(3)];
+    domain.update_activations(&mut s, &mut OsRng).unwrap();
+    assert_eq!(s[1], MixedVar::Inactive);
+    s[0] = MixedVar::Categorical(1);
+    domain.update_activations(&mut s, &mut OsRng).unwrap();
+    assert!(MixedVarDomain::Integer(2..=5).contains(&s[1]));
+    let invalid = Condition::new(0, vec![MixedVar::Categorical(2)]);
+    assert!(ConditionalDomain::new()
+      .push(MixedVarDomain::Bool)
+      .push_conditional(MixedVarDomain::Bool, invalid)
+      .is_err());
+  }
+}
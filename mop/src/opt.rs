@@ -1,7 +1,21 @@
+mod cancellation;
+mod clock;
+mod convergence_trace;
+mod experiment;
 mod opt_facade;
+mod opt_facade_checkpoint;
 mod opt_hooks;
 mod opt_hooks_fn_builder;
+mod restart;
+mod termination;
 
+pub use cancellation::*;
+pub use clock::*;
+pub use convergence_trace::*;
+pub use experiment::*;
 pub use opt_facade::*;
+pub use opt_facade_checkpoint::*;
 pub use opt_hooks::*;
 pub use opt_hooks_fn_builder::*;
+pub use restart::*;
+pub use termination::*;
@@ -0,0 +1,78 @@
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Polled by `OptFacade` at each iteration to know if the optimization should be aborted.
+///
+/// Useful when the optimization runs in a different thread or task than the one that decides
+/// to abort it, e.g., a server request or a GUI.
+pub trait Cancellation {
+  /// Was the optimization cancelled?
+  fn is_cancelled(&self) -> bool;
+}
+
+/// Never cancels
+impl Cancellation for () {
+  #[inline]
+  fn is_cancelled(&self) -> bool {
+    false
+  }
+}
+
+impl Cancellation for fn() -> bool {
+  #[inline]
+  fn is_cancelled(&self) -> bool {
+    self()
+  }
+}
+
+impl<T> Cancellation for &T
+where
+  T: Cancellation + ?Sized,
+{
+  #[inline]
+  fn is_cancelled(&self) -> bool {
+    (**self).is_cancelled()
+  }
+}
+
+/// Shared atomic flag that can be cancelled from any clone, including clones sent to other
+/// threads.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+  flag: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Requests the cancellation of every optimization that uses this token or one of its clones.
+  #[inline]
+  pub fn cancel(&self) {
+    self.flag.store(true, Ordering::Relaxed);
+  }
+}
+
+impl Cancellation for CancellationToken {
+  #[inline]
+  fn is_cancelled(&self) -> bool {
+    self.flag.load(Ordering::Relaxed)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::opt::{Cancellation, CancellationToken};
+
+  #[test]
+  fn cancellation_token() {
+    let token = CancellationToken::new();
+    let clone = token.clone();
+    assert!(!token.is_cancelled());
+    clone.cancel();
+    assert!(token.is_cancelled());
+    assert!(!().is_cancelled());
+  }
+}
@@ -0,0 +1,110 @@
+use alloc::sync::Arc;
+use core::{
+  sync::atomic::{AtomicU64, Ordering},
+  time::Duration,
+};
+
+/// Clock used by `OptFacade` to enforce the maximum duration of an optimization.
+///
+/// [`DefaultClock`] is [`JsClock`] with the `wasm-bindgen` feature, [`StdClock`] with the `std`
+/// feature and `()` otherwise.
+pub trait Clock {
+  /// Monotonic time elapsed since an arbitrary but fixed instant.
+  fn now(&self) -> Duration;
+}
+
+/// Time never advances, thus maximum durations never expire.
+impl Clock for () {
+  #[inline]
+  fn now(&self) -> Duration {
+    Duration::ZERO
+  }
+}
+
+#[cfg(feature = "wasm-bindgen")]
+pub type DefaultClock = JsClock;
+#[cfg(all(feature = "std", not(feature = "wasm-bindgen")))]
+pub type DefaultClock = StdClock;
+#[cfg(not(any(feature = "std", feature = "wasm-bindgen")))]
+pub type DefaultClock = ();
+
+/// Milliseconds of `Date.now()`
+#[cfg(feature = "wasm-bindgen")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsClock;
+
+#[cfg(feature = "wasm-bindgen")]
+impl Clock for JsClock {
+  #[inline]
+  fn now(&self) -> Duration {
+    Duration::from_secs_f64(js_sys::Date::now().max(0.0) / 1000.0)
+  }
+}
+
+/// Clock that is only advanced by hand, through any of its clones. Useful to test time limits
+/// deterministically.
+#[derive(Clone, Debug, Default)]
+pub struct ManualClock {
+  nanos: Arc<AtomicU64>,
+}
+
+impl ManualClock {
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Moves the time forward by `duration`
+  #[inline]
+  pub fn advance(&self, duration: Duration) {
+    let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+    let _ = self
+      .nanos
+      .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |curr| Some(curr.saturating_add(nanos)));
+  }
+}
+
+impl Clock for ManualClock {
+  #[inline]
+  fn now(&self) -> Duration {
+    Duration::from_nanos(self.nanos.load(Ordering::Relaxed))
+  }
+}
+
+/// Backed by `std::time::Instant`
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+pub struct StdClock {
+  origin: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl Clock for StdClock {
+  #[inline]
+  fn now(&self) -> Duration {
+    self.origin.elapsed()
+  }
+}
+
+#[cfg(feature = "std")]
+impl Default for StdClock {
+  #[inline]
+  fn default() -> Self {
+    Self { origin: std::time::Instant::now() }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::opt::{Clock, ManualClock};
+  use core::time::Duration;
+
+  #[test]
+  fn manual_clock() {
+    let clock = ManualClock::new();
+    let clone = clock.clone();
+    clone.advance(Duration::from_millis(1500));
+    clone.advance(Duration::from_millis(500));
+    assert_eq!(clock.now(), Duration::from_secs(2));
+  }
+}
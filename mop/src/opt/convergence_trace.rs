@@ -0,0 +1,300 @@
+use crate::{
+  gp::Gp,
+  opt::OptHooks,
+  utils::{hypervolume, verify_pareto_dominance},
+  Obj, ObjDirection, Progress,
+};
+use alloc::vec::Vec;
+use cl_aux::SingleTypeStorage;
+use core::{
+  cmp::Ordering,
+  fmt::{self, Write},
+  ops::ControlFlow,
+};
+use num_traits::NumCast;
+
+/// [`OptHooks`] that records statistics of the population of each iteration, e.g., to chart the
+/// convergence of an optimization.
+///
+/// Statistics are taken in `after_iter`, i.e., after the evaluation and the stopping
+/// verification, so the iteration that stops the optimization isn't recorded.
+#[derive(Clone, Debug, Default)]
+pub struct ConvergenceTrace {
+  buffer: Vec<f64>,
+  reference_opt: Option<Vec<f64>>,
+  rows: Vec<ConvergenceTraceRow>,
+}
+
+impl ConvergenceTrace {
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Recorded iterations
+  #[inline]
+  pub fn rows(&self) -> &[ConvergenceTraceRow] {
+    &self.rows
+  }
+
+  /// Also records the hypervolume of each population. `reference` is a point, in the objective
+  /// space, that is worse than any relevant result.
+  #[inline]
+  #[must_use]
+  pub fn set_hypervolume_reference(mut self, reference: Vec<f64>) -> Self {
+    self.reference_opt = Some(reference);
+    self
+  }
+
+  /// Writes one line per iteration preceded by a header. Objectives are suffixed by their
+  /// index, e.g., `best_0`.
+  #[inline]
+  pub fn write_csv<W>(&self, w: &mut W) -> fmt::Result
+  where
+    W: Write,
+  {
+    let objs_num = self.rows.first().map_or(0, |row| row.best.len());
+    w.write_str("iteration")?;
+    for name in ["best", "mean", "worst"] {
+      for idx in 0..objs_num {
+        write!(w, ",{name}_{idx}")?;
+      }
+    }
+    w.write_str(",feasible_fraction,non_dominated,hypervolume\n")?;
+    for row in &self.rows {
+      write!(w, "{}", row.iteration)?;
+      for value in row.best.iter().chain(&row.mean).chain(&row.worst) {
+        write!(w, ",{value}")?;
+      }
+      write!(w, ",{},{},", row.feasible_fraction, row.non_dominated)?;
+      if let Some(elem) = row.hypervolume {
+        write!(w, "{elem}")?;
+      }
+      w.write_char('\n')?;
+    }
+    Ok(())
+  }
+
+  /// Writes an array with one object per iteration. Non-finite numbers are written as `null`.
+  #[inline]
+  pub fn write_json<W>(&self, w: &mut W) -> fmt::Result
+  where
+    W: Write,
+  {
+    fn number<W>(w: &mut W, value: f64) -> fmt::Result
+    where
+      W: Write,
+    {
+      if value.is_finite() {
+        write!(w, "{value}")
+      } else {
+        w.write_str("null")
+      }
+    }
+
+    fn numbers<W>(w: &mut W, name: &str, values: &[f64]) -> fmt::Result
+    where
+      W: Write,
+    {
+      write!(w, "\"{name}\":[")?;
+      for (idx, value) in values.iter().enumerate() {
+        if idx > 0 {
+          w.write_char(',')?;
+        }
+        number(w, *value)?;
+      }
+      w.write_str("],")
+    }
+
+    w.write_char('[')?;
+    for (idx, row) in self.rows.iter().enumerate() {
+      if idx > 0 {
+        w.write_char(',')?;
+      }
+      write!(w, "{{\"iteration\":{},", row.iteration)?;
+      numbers(w, "best", &row.best)?;
+      numbers(w, "mean", &row.mean)?;
+      numbers(w, "worst", &row.worst)?;
+      w.write_str("\"feasible_fraction\":")?;
+      number(w, row.feasible_fraction)?;
+      write!(w, ",\"non_dominated\":{},\"hypervolume\":", row.non_dominated)?;
+      number(w, row.hypervolume.unwrap_or(f64::NAN))?;
+      w.write_char('}')?;
+    }
+    w.write_char(']')
+  }
+
+  fn record<D, HCR, HCRS, HCS, O, OR, ORS, OS, S, SCR, SCRS, SCS, SS>(
+    &mut self,
+    p: &Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>,
+    progress: &Progress,
+  ) where
+    HCR: Default + PartialEq,
+    HCRS: AsRef<[HCR]> + SingleTypeStorage<Item = HCR>,
+    O: Obj<OR, S>,
+    OR: Copy + NumCast + PartialOrd,
+    ORS: AsRef<[OR]> + SingleTypeStorage<Item = OR>,
+    OS: AsRef<[O]> + SingleTypeStorage<Item = O>,
+    SCRS: AsRef<[SCR]> + SingleTypeStorage<Item = SCR>,
+    SS: AsRef<[S]> + SingleTypeStorage<Item = S>,
+  {
+    let (defs, rslts) = p.parts();
+    let objs = defs.objs();
+    let sign = |obj: &O| if obj.obj_direction() == ObjDirection::Max { -1.0 } else { 1.0 };
+    let mut row = ConvergenceTraceRow {
+      best: objs.iter().map(|obj| sign(obj) * f64::INFINITY).collect(),
+      feasible_fraction: 0.0,
+      hypervolume: None,
+      iteration: progress.current_iteration(),
+      mean: objs.iter().map(|_| 0.0).collect(),
+      non_dominated: 0,
+      worst: objs.iter().map(|obj| sign(obj) * f64::NEG_INFINITY).collect(),
+    };
+    let mut feasible: usize = 0;
+    self.buffer.clear();
+    for rslt in rslts.obj_rslts.row_iter() {
+      let stats = row.best.iter_mut().zip(&mut row.mean).zip(&mut row.worst);
+      for ((obj, obj_rslt), ((best, mean), worst)) in objs.iter().zip(rslt).zip(stats) {
+        let value = obj_rslt.to_f64().unwrap_or(f64::NAN);
+        let signed = sign(obj) * value;
+        if signed < sign(obj) * *best {
+          *best = value;
+        }
+        if signed > sign(obj) * *worst {
+          *worst = value;
+        }
+        *mean += value;
+        self.buffer.push(signed);
+      }
+    }
+    for rslt in rslts.iter() {
+      if rslt.hard_cstr_rslts.iter().all(|elem| *elem == HCR::default()) {
+        feasible = feasible.saturating_add(1);
+      }
+    }
+    for rslt in rslts.obj_rslts.row_iter() {
+      let is_dominated = rslts
+        .obj_rslts
+        .row_iter()
+        .any(|other| verify_pareto_dominance(objs, other, rslt) == Ordering::Greater);
+      if !is_dominated {
+        row.non_dominated = row.non_dominated.saturating_add(1);
+      }
+    }
+    let len = rslts.rslts_num();
+    if let (true, Ok(feasible_f64), Ok(len_f64)) =
+      (len > 0, crate::Error::cast_rslt::<_, f64>(feasible), crate::Error::cast_rslt::<_, f64>(len))
+    {
+      row.feasible_fraction = feasible_f64 / len_f64;
+      for elem in &mut row.mean {
+        *elem /= len_f64;
+      }
+    }
+    if let Some(reference) = self.reference_opt.as_ref() {
+      if reference.len() == objs.len() && !objs.is_empty() {
+        let signed_reference: Vec<f64> =
+          objs.iter().zip(reference).map(|(obj, r)| sign(obj) * r).collect();
+        row.hypervolume =
+          Some(hypervolume(self.buffer.chunks_exact(objs.len()), &signed_reference));
+      }
+    }
+    self.rows.push(row);
+  }
+}
+
+impl<B, D, HCR, HCRS, HCS, O, OR, ORS, OS, S, SCR, SCRS, SCS, SS>
+  OptHooks<B, Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>> for ConvergenceTrace
+where
+  HCR: Default + PartialEq,
+  HCRS: AsRef<[HCR]> + SingleTypeStorage<Item = HCR>,
+  O: Obj<OR, S>,
+  OR: Copy + NumCast + PartialOrd,
+  ORS: AsRef<[OR]> + SingleTypeStorage<Item = OR>,
+  OS: AsRef<[O]> + SingleTypeStorage<Item = O>,
+  SCRS: AsRef<[SCR]> + SingleTypeStorage<Item = SCR>,
+  SS: AsRef<[S]> + SingleTypeStorage<Item = S>,
+{
+  #[inline]
+  fn before_iter(
+    &mut self,
+    _: &mut Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>,
+    _: &Progress,
+    _: Option<&B>,
+  ) -> ControlFlow<()> {
+    ControlFlow::Continue(())
+  }
+
+  #[inline]
+  fn after_iter(
+    &mut self,
+    p: &mut Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>,
+    progress: &Progress,
+    _: Option<&B>,
+  ) -> ControlFlow<()> {
+    self.record(p, progress);
+    ControlFlow::Continue(())
+  }
+
+  #[inline]
+  fn finished(&mut self) {}
+
+  #[inline]
+  fn init(&mut self) {
+    self.rows.clear();
+  }
+}
+
+/// Statistics of the population of an iteration. Objective values are in the same order of the
+/// objective definitions.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvergenceTraceRow {
+  /// Best value of each objective
+  pub best: Vec<f64>,
+  /// Fraction of results that don't violate hard constraints, from 0 to 1
+  pub feasible_fraction: f64,
+  /// Hypervolume of the population, if a reference was provided
+  pub hypervolume: Option<f64>,
+  /// Number of processed iterations
+  pub iteration: usize,
+  /// Mean value of each objective
+  pub mean: Vec<f64>,
+  /// Number of results that aren't dominated by any other result
+  pub non_dominated: usize,
+  /// Worst value of each objective
+  pub worst: Vec<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{opt::ConvergenceTrace, utils::dummy_mp_with_solutions, Progress};
+  use alloc::string::String;
+
+  #[test]
+  fn convergence_trace() {
+    let problem = dummy_mp_with_solutions();
+    let mut trace = ConvergenceTrace::new().set_hypervolume_reference([20.0, 20.0].into());
+    trace.record(&problem, &Progress::new(1, 10));
+    let row = &trace.rows()[0];
+    assert_eq!(row.best, [4.0, 8.0]);
+    assert_eq!(row.mean, [8.0, 10.0]);
+    assert_eq!(row.worst, [12.0, 12.0]);
+    assert_eq!(row.feasible_fraction, 1.0);
+    assert_eq!(row.non_dominated, 1);
+    assert_eq!(row.hypervolume, Some(192.0));
+    let mut csv = String::new();
+    trace.write_csv(&mut csv).unwrap();
+    assert_eq!(
+      csv,
+      "iteration,best_0,best_1,mean_0,mean_1,worst_0,worst_1,feasible_fraction,non_dominated,\
+       hypervolume\n1,4,8,8,10,12,12,1,1,192\n"
+    );
+    let mut json = String::new();
+    trace.write_json(&mut json).unwrap();
+    assert_eq!(
+      json,
+      "[{\"iteration\":1,\"best\":[4,8],\"mean\":[8,10],\"worst\":[12,12],\
+       \"feasible_fraction\":1,\"non_dominated\":1,\"hypervolume\":192}]"
+    );
+  }
+}
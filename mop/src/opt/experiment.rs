@@ -0,0 +1,218 @@
+mod statistics;
+
+use crate::ParBounds;
+use alloc::vec::Vec;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Runs several solver configurations many times, each run with its own seed and problem, and
+/// collects quality indicators of the final problems. Runs are executed in parallel with the
+/// `rayon` feature.
+///
+/// # Types
+///
+/// * `F`: Problem factory
+#[derive(Debug)]
+pub struct Experiment<F> {
+  problem_factory: F,
+  runs: usize,
+  seed: u64,
+}
+
+impl<F> Experiment<F> {
+  /// * `problem_factory`: Creates a new problem for each run
+  /// * `runs`: Number of independent runs of each configuration
+  #[inline]
+  pub fn new(problem_factory: F, runs: usize) -> Self {
+    Self { problem_factory, runs, seed: 0 }
+  }
+
+  #[inline]
+  pub fn runs(&self) -> usize {
+    self.runs
+  }
+
+  #[inline]
+  pub fn seed(&self) -> u64 {
+    self.seed
+  }
+
+  /// Seed of the first run. The run `n` of every configuration receives `seed + n`.
+  #[inline]
+  #[must_use]
+  pub fn set_seed(mut self, seed: u64) -> Self {
+    self.seed = seed;
+    self
+  }
+
+  /// Solves a new problem with each configuration `runs` times and evaluates every indicator
+  /// on the solved problems.
+  ///
+  /// * `configurations`: Solve the given problem using the given seed, e.g., through
+  ///   `OptFacade` and a seeded solver.
+  /// * `indicators`: Quality indicators of a solved problem, e.g., the hypervolume.
+  #[inline]
+  pub fn run<C, I, P>(
+    &self,
+    configurations: &[C],
+    indicators: &[I],
+  ) -> crate::Result<ExperimentReport>
+  where
+    C: Fn(&mut P, u64) -> crate::Result<()> + ParBounds,
+    F: Fn() -> crate::Result<P> + ParBounds,
+    I: Fn(&P) -> f64 + ParBounds,
+  {
+    let jobs = configurations.len().saturating_mul(self.runs);
+    let job = |job: usize| {
+      let (configuration, run) = (job / self.runs, job % self.runs);
+      let mut problem = (self.problem_factory)()?;
+      let seed = self.seed.wrapping_add(crate::Error::cast_rslt(run)?);
+      crate::Error::opt_rslt(configurations.get(configuration))?(&mut problem, seed)?;
+      Ok(indicators.iter().map(|indicator| indicator(&problem)).collect::<Vec<_>>())
+    };
+    #[cfg(feature = "rayon")]
+    let rslts: Vec<crate::Result<Vec<f64>>> = (0..jobs).into_par_iter().map(job).collect();
+    #[cfg(not(feature = "rayon"))]
+    let rslts: Vec<crate::Result<Vec<f64>>> = (0..jobs).map(job).collect();
+    let mut samples = alloc::vec![0.0; jobs.saturating_mul(indicators.len())];
+    for (job, rslt) in rslts.into_iter().enumerate() {
+      let (configuration, run) = (job / self.runs, job % self.runs);
+      for (indicator, value) in rslt?.into_iter().enumerate() {
+        let idx = configuration
+          .saturating_mul(indicators.len())
+          .saturating_add(indicator)
+          .saturating_mul(self.runs)
+          .saturating_add(run);
+        *crate::Error::opt_rslt(samples.get_mut(idx))? = value;
+      }
+    }
+    Ok(ExperimentReport {
+      configurations: configurations.len(),
+      indicators: indicators.len(),
+      runs: self.runs,
+      samples,
+    })
+  }
+}
+
+/// Indicators collected by [`Experiment::run`]. Configurations and indicators are referenced by
+/// their indices in the slices given to `run`.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExperimentReport {
+  configurations: usize,
+  indicators: usize,
+  runs: usize,
+  samples: Vec<f64>,
+}
+
+impl ExperimentReport {
+  #[inline]
+  pub fn configurations(&self) -> usize {
+    self.configurations
+  }
+
+  #[inline]
+  pub fn indicators(&self) -> usize {
+    self.indicators
+  }
+
+  /// Kruskal–Wallis test of an indicator between all configurations
+  #[inline]
+  pub fn kruskal_wallis(&self, indicator: usize) -> Option<HypothesisTest> {
+    let groups = (0..self.configurations)
+      .map(|configuration| self.samples(configuration, indicator))
+      .collect::<Option<Vec<_>>>()?;
+    let (statistic, p_value) = statistics::kruskal_wallis(&groups)?;
+    Some(HypothesisTest { p_value, statistic })
+  }
+
+  /// Wilcoxon rank-sum test of an indicator between two configurations. The statistic is
+  /// negative when `a` tends to have lower values than `b`.
+  #[inline]
+  pub fn rank_sum(&self, a: usize, b: usize, indicator: usize) -> Option<HypothesisTest> {
+    let (statistic, p_value) =
+      statistics::wilcoxon_rank_sum(self.samples(a, indicator)?, self.samples(b, indicator)?)?;
+    Some(HypothesisTest { p_value, statistic })
+  }
+
+  #[inline]
+  pub fn runs(&self) -> usize {
+    self.runs
+  }
+
+  /// Values of an indicator for all runs of a configuration
+  #[inline]
+  pub fn samples(&self, configuration: usize, indicator: usize) -> Option<&[f64]> {
+    if configuration >= self.configurations || indicator >= self.indicators {
+      return None;
+    }
+    let begin = configuration
+      .saturating_mul(self.indicators)
+      .saturating_add(indicator)
+      .saturating_mul(self.runs);
+    self.samples.get(begin..begin.saturating_add(self.runs))
+  }
+
+  /// Median and interquartile range of an indicator for all runs of a configuration
+  #[inline]
+  pub fn summary(&self, configuration: usize, indicator: usize) -> Option<Summary> {
+    let mut sorted = Vec::from(self.samples(configuration, indicator)?);
+    sorted.sort_unstable_by(|a, b| a.total_cmp(b));
+    let iqr = statistics::quantile(&sorted, 0.75)? - statistics::quantile(&sorted, 0.25)?;
+    Some(Summary { iqr, median: statistics::quantile(&sorted, 0.5)? })
+  }
+}
+
+/// Statistic and two-sided p-value of a hypothesis test. Small p-values indicate that the
+/// compared samples come from different distributions.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HypothesisTest {
+  pub p_value: f64,
+  pub statistic: f64,
+}
+
+/// Central tendency and dispersion of the samples of an indicator
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Summary {
+  /// Interquartile range
+  pub iqr: f64,
+  pub median: f64,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{gp::MpVec, opt::Experiment, utils::dummy_mp_with_solutions, ObjDirection};
+  use core::ops::RangeInclusive;
+
+  type Problem =
+    MpVec<[RangeInclusive<f64>; 2], (ObjDirection, fn(&[f64; 2]) -> f64), f64, [f64; 2]>;
+
+  #[test]
+  fn experiment() {
+    let configurations: [fn(&mut Problem, u64) -> crate::Result<()>; 2] = [
+      |p, seed| {
+        let _ = p.rslts_mut().constructor().or_os_iter([seed as f64, 0.0].into_iter(), [0.0; 2]);
+        Ok(())
+      },
+      |p, seed| {
+        let _ =
+          p.rslts_mut().constructor().or_os_iter([seed as f64 + 100.0, 0.0].into_iter(), [0.0; 2]);
+        Ok(())
+      },
+    ];
+    let indicators: [fn(&Problem) -> f64; 1] =
+      [|p| p.rslts().get(p.rslts().rslts_num() - 1).unwrap().obj_rslts()[0]];
+    let report = Experiment::new(|| Ok(dummy_mp_with_solutions()), 10)
+      .set_seed(1)
+      .run(&configurations, &indicators)
+      .unwrap();
+    assert_eq!(report.samples(0, 0).unwrap()[..3], [1.0, 2.0, 3.0]);
+    let summary = report.summary(1, 0).unwrap();
+    assert_eq!((summary.median, summary.iqr), (105.5, 4.5));
+    assert!(report.rank_sum(0, 1, 0).unwrap().p_value < 0.001);
+    assert!(report.kruskal_wallis(0).unwrap().p_value < 0.001);
+  }
+}
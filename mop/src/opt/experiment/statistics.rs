@@ -0,0 +1,177 @@
+//! Non-parametric statistics used to compare the runs of an experiment.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use num_traits::Float;
+
+/// Kruskal–Wallis H statistic and its p-value, approximated by a chi-squared distribution with
+/// `groups.len() - 1` degrees of freedom.
+pub(crate) fn kruskal_wallis(groups: &[&[f64]]) -> Option<(f64, f64)> {
+  if groups.len() < 2 || groups.iter().any(|group| group.is_empty()) {
+    return None;
+  }
+  let len = groups.iter().fold(0usize, |acc, group| acc.saturating_add(group.len()));
+  let n: f64 = crate::Error::cast_rslt(len).ok()?;
+  let (ranks, ties) = ranks(groups)?;
+  let mut sum = 0.0;
+  let mut ranks_iter = ranks.iter();
+  for group in groups {
+    let group_ranks: f64 = ranks_iter.by_ref().take(group.len()).sum();
+    let group_len: f64 = crate::Error::cast_rslt(group.len()).ok()?;
+    sum += group_ranks * group_ranks / group_len;
+  }
+  let correction = 1.0 - ties / (n * n * n - n);
+  if correction <= 0.0 {
+    return Some((0.0, 1.0));
+  }
+  let h = (12.0 / (n * (n + 1.0)) * sum - 3.0 * (n + 1.0)) / correction;
+  let df: f64 = crate::Error::cast_rslt(groups.len().saturating_sub(1)).ok()?;
+  Some((h, upper_regularized_gamma(df / 2.0, h.max(0.0) / 2.0)))
+}
+
+/// Quantile with linear interpolation between the closest ranks. `sorted` must be sorted.
+pub(crate) fn quantile(sorted: &[f64], q: f64) -> Option<f64> {
+  let last = sorted.len().checked_sub(1)?;
+  let pos = q.clamp(0.0, 1.0) * crate::Error::cast_rslt::<_, f64>(last).ok()?;
+  let lower = Float::floor(pos);
+  let idx: usize = crate::Error::cast_rslt(lower).ok()?;
+  let fst = *sorted.get(idx)?;
+  let sec = sorted.get(idx.saturating_add(1)).copied().unwrap_or(fst);
+  Some(fst + (sec - fst) * (pos - lower))
+}
+
+/// Wilcoxon rank-sum (Mann–Whitney U) z statistic of `a` against `b` and its two-sided
+/// p-value, approximated by a normal distribution with tie correction.
+pub(crate) fn wilcoxon_rank_sum(a: &[f64], b: &[f64]) -> Option<(f64, f64)> {
+  if a.is_empty() || b.is_empty() {
+    return None;
+  }
+  let (ranks, ties) = ranks(&[a, b])?;
+  let n1: f64 = crate::Error::cast_rslt(a.len()).ok()?;
+  let n2: f64 = crate::Error::cast_rslt(b.len()).ok()?;
+  let n = n1 + n2;
+  let u = ranks.iter().take(a.len()).sum::<f64>() - n1 * (n1 + 1.0) / 2.0;
+  let variance = n1 * n2 / 12.0 * ((n + 1.0) - ties / (n * (n - 1.0)));
+  if variance <= 0.0 {
+    return Some((0.0, 1.0));
+  }
+  let z = (u - n1 * n2 / 2.0) / Float::sqrt(variance);
+  Some((z, upper_regularized_gamma(0.5, z * z / 2.0)))
+}
+
+// Lanczos approximation
+fn ln_gamma(x: f64) -> f64 {
+  const COEFFICIENTS: [f64; 6] = [
+    76.180_091_729_471_46,
+    -86.505_320_329_416_77,
+    24.014_098_240_830_91,
+    -1.231_739_572_450_155,
+    0.001_208_650_973_866_179,
+    -0.000_005_395_239_384_953,
+  ];
+  let tmp = x + 5.5;
+  let mut series = 1.000_000_000_190_015;
+  let mut denominator = x;
+  for coefficient in COEFFICIENTS {
+    denominator += 1.0;
+    series += coefficient / denominator;
+  }
+  (x + 0.5) * Float::ln(tmp) - tmp + Float::ln(2.506_628_274_631_000_5 * series / x)
+}
+
+// Ranks, starting from 1, of the concatenation of `groups` and the tie correction term
+// `sum(t^3 - t)`. Equal values receive the average of their ranks.
+fn ranks(groups: &[&[f64]]) -> Option<(Vec<f64>, f64)> {
+  let mut indexed: Vec<(usize, f64)> =
+    groups.iter().flat_map(|group| group.iter().copied()).enumerate().collect();
+  indexed.sort_unstable_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+  let mut ranks = alloc::vec![0.0; indexed.len()];
+  let mut ties = 0.0;
+  let mut begin = 0;
+  while begin < indexed.len() {
+    let tied = indexed.get(begin..)?;
+    let value = tied.first()?.1;
+    let tied_len = tied.iter().position(|elem| elem.1 != value).unwrap_or(tied.len());
+    let end = begin.saturating_add(tied_len);
+    let average =
+      crate::Error::cast_rslt::<_, f64>(begin.saturating_add(end).saturating_add(1)).ok()? / 2.0;
+    for (idx, _) in tied.get(..tied_len)? {
+      *ranks.get_mut(*idx)? = average;
+    }
+    let t: f64 = crate::Error::cast_rslt(tied_len).ok()?;
+    ties += t * t * t - t;
+    begin = end;
+  }
+  Some((ranks, ties))
+}
+
+// Q(a, x) through a series when `x < a + 1` and a continued fraction otherwise.
+fn upper_regularized_gamma(a: f64, x: f64) -> f64 {
+  const EPS: f64 = 1e-14;
+  const ITERATIONS: usize = 500;
+  if x <= 0.0 {
+    return 1.0;
+  }
+  let ln_prefix = a * Float::ln(x) - x - ln_gamma(a);
+  if x < a + 1.0 {
+    let mut term = 1.0 / a;
+    let mut sum = term;
+    let mut denominator = a;
+    for _ in 0..ITERATIONS {
+      denominator += 1.0;
+      term *= x / denominator;
+      sum += term;
+      if Float::abs(term) < Float::abs(sum) * EPS {
+        break;
+      }
+    }
+    (1.0 - sum * Float::exp(ln_prefix)).clamp(0.0, 1.0)
+  } else {
+    let tiny = f64::MIN_POSITIVE / EPS;
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / tiny;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    let mut idx = 0.0;
+    for _ in 1..ITERATIONS {
+      idx += 1.0;
+      let an = -idx * (idx - a);
+      b += 2.0;
+      d = an * d + b;
+      if Float::abs(d) < tiny {
+        d = tiny;
+      }
+      c = b + an / c;
+      if Float::abs(c) < tiny {
+        c = tiny;
+      }
+      d = 1.0 / d;
+      let delta = d * c;
+      h *= delta;
+      if Float::abs(delta - 1.0) < EPS {
+        break;
+      }
+    }
+    (Float::exp(ln_prefix) * h).clamp(0.0, 1.0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::opt::experiment::statistics::{kruskal_wallis, quantile, wilcoxon_rank_sum};
+
+  #[test]
+  fn statistics() {
+    let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let b = [6.0, 7.0, 8.0, 9.0, 10.0];
+    let (z, p) = wilcoxon_rank_sum(&a, &b).unwrap();
+    assert!((z + 2.611_164).abs() < 1e-6);
+    assert!((p - 0.009_023).abs() < 1e-6);
+    let (h, p) = kruskal_wallis(&[&a, &b]).unwrap();
+    assert!((h - 6.818_182).abs() < 1e-6);
+    assert!((p - 0.009_023).abs() < 1e-6);
+    assert_eq!(quantile(&a, 0.25), Some(2.0));
+    assert_eq!(quantile(&[1.0, 2.0], 0.5), Some(1.5));
+    assert_eq!(wilcoxon_rank_sum(&[1.0, 1.0], &[1.0, 1.0]), Some((0.0, 1.0)));
+  }
+}
@@ -1,42 +1,106 @@
 use crate::{
-  gp::{Gp, GpOrRef},
-  opt::OptHooks,
+  dr_matrix::{DrMatrix, DrMatrixVec},
+  gp::{Gp, GpEvaluations, GpOrRef, GpOrs},
+  opt::{
+    opt_facade_checkpoint::OPT_FACADE_CHECKPOINT_VERSION, Cancellation, Clock, DefaultClock,
+    OptFacadeCheckpoint, OptHooks, Restart, RestartCheckpoint, Termination, TerminationCheckpoint,
+  },
   quality_comparator::QualityComparator,
-  Domain, Obj, ObjDirection, Pct, Solution, Solver,
+  Domain, Obj, ObjDirection, Pct, Progress, Solution, Solver,
 };
 use alloc::vec::Vec;
-use cl_aux::{Length, SingleTypeStorage};
+use cl_aux::{Clear, Length, Push, SingleTypeStorage, WithCapacity};
 use core::{
   fmt::Debug,
   iter::Sum,
   marker::PhantomData,
   ops::{Div, Sub},
+  time::Duration,
 };
 use num_traits::{NumCast, One, Zero};
-#[cfg(feature = "std")]
-use {core::time::Duration, std::time::Instant};
 
 #[derive(Debug)]
-pub struct OptFacade<D, HCRS, HCS, OH, OR, ORS, OS, QC, SCRS, SCS, SS> {
+pub struct OptFacade<
+  D,
+  HCRS,
+  HCS,
+  OH,
+  OR,
+  ORS,
+  OS,
+  QC,
+  SCRS,
+  SCS,
+  SS,
+  TE = (),
+  CA = (),
+  CL = DefaultClock,
+  RE = (),
+> {
   best_idx_opt: Option<usize>,
+  best_objs_history: DrMatrixVec<OR>,
+  best_opt: Option<GpOrs<HCRS, ORS, SCRS, SS>>,
+  cancellation: CA,
+  clock: CL,
   current_iteration: usize,
+  evaluations: GpEvaluations,
+  max_evaluations_opt: Option<usize>,
   max_iterations: usize,
   objs_goals: Vec<OR>,
   opt_hooks_opt: Option<OH>,
   phantom: PhantomData<(D, HCRS, HCS, ORS, OS, SCRS, SCS, SS)>,
   quality_comparator_opt: Option<QC>,
+  restart: RE,
   stagnation_opt: Option<Stagnation<OR>>,
-  #[cfg(feature = "std")]
+  termination: TE,
   time_opt: Option<Time>,
+  was_cancelled: bool,
 }
 
-impl<D, HCR, HCRS, HCS, O, OH, OR, ORS, OS, QC, S, SCR, SCRS, SCS, SS>
+impl<D, HCRS, HCS, OH, OR, ORS, OS, QC, SCRS, SCS, SS>
   OptFacade<D, HCRS, HCS, OH, OR, ORS, OS, QC, SCRS, SCS, SS>
+{
+  #[inline]
+  pub fn new(max_iterations: usize) -> Self {
+    OptFacade {
+      best_idx_opt: None,
+      best_objs_history: DrMatrix::with_capacity(0, 0),
+      best_opt: None,
+      cancellation: (),
+      clock: DefaultClock::default(),
+      current_iteration: 0,
+      evaluations: GpEvaluations::default(),
+      max_evaluations_opt: None,
+      max_iterations,
+      objs_goals: Vec::new(),
+      opt_hooks_opt: None,
+      phantom: PhantomData,
+      quality_comparator_opt: None,
+      restart: (),
+      stagnation_opt: None,
+      termination: (),
+      time_opt: None,
+      was_cancelled: false,
+    }
+  }
+}
+
+impl<CA, CL, D, HC, HCR, HCRS, HCS, O, OH, OR, ORS, OS, QC, RE, S, SC, SCR, SCRS, SCS, SS, TE>
+  OptFacade<D, HCRS, HCS, OH, OR, ORS, OS, QC, SCRS, SCS, SS, TE, CA, CL, RE>
 where
-  HCRS: AsMut<[HCR]> + AsRef<[HCR]> + SingleTypeStorage<Item = HCR>,
+  CA: Cancellation,
+  CL: Clock,
+  HCR: Clone,
+  HCRS: AsMut<[HCR]>
+    + AsRef<[HCR]>
+    + Clear
+    + Extend<HCR>
+    + SingleTypeStorage<Item = HCR>
+    + WithCapacity<Input = usize>,
+  HCS: AsRef<[HC]> + SingleTypeStorage<Item = HC>,
   D: Domain<S>,
   O: Obj<OR, S>,
-  OH: OptHooks<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
+  OH: OptHooks<GpOrs<HCRS, ORS, SCRS, SS>, Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
   OR: Copy
     + Div<OR, Output = OR>
     + NumCast
@@ -45,40 +109,107 @@ where
     + Sub<OR, Output = OR>
     + Zero
     + Sum<OR>,
-  SCRS: AsMut<[SCR]> + AsRef<[SCR]> + SingleTypeStorage<Item = SCR>,
-  ORS: AsMut<[OR]> + AsRef<[OR]> + SingleTypeStorage<Item = OR>,
+  SCR: Clone,
+  SCRS: AsMut<[SCR]>
+    + AsRef<[SCR]>
+    + Clear
+    + Extend<SCR>
+    + SingleTypeStorage<Item = SCR>
+    + WithCapacity<Input = usize>,
+  SCS: AsRef<[SC]> + SingleTypeStorage<Item = SC>,
+  ORS: AsMut<[OR]>
+    + AsRef<[OR]>
+    + Clear
+    + Extend<OR>
+    + SingleTypeStorage<Item = OR>
+    + WithCapacity<Input = usize>,
   OS: AsMut<[O]> + AsRef<[O]> + SingleTypeStorage<Item = O>,
   QC: for<'any> QualityComparator<[O], GpOrRef<'any, HCR, OR, S, SCR>>,
-  S: Solution,
-  SS: AsMut<[S]> + AsRef<[S]> + Length + SingleTypeStorage<Item = S>,
+  RE: Restart<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
+  S: Clone + Solution,
+  SS: AsMut<[S]>
+    + AsRef<[S]>
+    + Clear
+    + Length
+    + Push<S>
+    + SingleTypeStorage<Item = S>
+    + WithCapacity<Input = usize>,
+  TE: Termination<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
 {
+  /// Objective results of the best result of each iteration, one row per iteration.
   #[inline]
-  pub fn new(max_iterations: usize) -> Self {
-    OptFacade {
-      best_idx_opt: None,
-      current_iteration: 0,
-      max_iterations,
-      objs_goals: Vec::new(),
-      opt_hooks_opt: None,
-      phantom: PhantomData,
-      quality_comparator_opt: None,
-      stagnation_opt: None,
-      #[cfg(feature = "std")]
-      time_opt: None,
+  pub fn best_objs_history(&self) -> &DrMatrixVec<OR> {
+    &self.best_objs_history
+  }
+
+  /// Copy of the best result found so far, according to the quality comparator. Unlike
+  /// [`Self::curr_best_idx`], it isn't affected by subsequent generations.
+  #[inline]
+  pub fn best_rslt(&self) -> Option<GpOrRef<'_, HCR, OR, S, SCR>> {
+    self.best_opt.as_ref()?.get(0)
+  }
+
+  #[inline]
+  pub fn cancellation(&self) -> &CA {
+    &self.cancellation
+  }
+
+  /// Counters and stopping state after the last iteration. Together with the
+  /// [`SolverCheckpoint`](crate::SolverCheckpoint) of the solver, allows the continuation of the
+  /// optimization through [`Self::resume_problem_with`].
+  #[inline]
+  pub fn checkpoint(
+    &self,
+  ) -> OptFacadeCheckpoint<HCRS, OR, ORS, SCRS, SS, RE::Checkpoint, TE::Checkpoint>
+  where
+    HCRS: Clone,
+    ORS: Clone,
+    RE: RestartCheckpoint<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
+    SCRS: Clone,
+    SS: Clone,
+    TE: TerminationCheckpoint<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
+  {
+    OptFacadeCheckpoint {
+      best_idx_opt: self.best_idx_opt,
+      best_objs_history: self.best_objs_history.clone(),
+      best_opt: self.best_opt.clone(),
+      current_iteration: self.current_iteration,
+      evaluations: self.evaluations.clone(),
+      restart: self.restart.checkpoint(),
+      stagnation_opt: self.stagnation_opt.as_ref().map(|s| (s.last_fitness, s.threshold_counter)),
+      termination: self.termination.checkpoint(),
+      version: OPT_FACADE_CHECKPOINT_VERSION,
     }
   }
 
+  /// Index of the best result of the current population, according to the quality comparator.
   #[inline]
   pub fn curr_best_idx(&self) -> Option<usize> {
     self.best_idx_opt
   }
 
-  #[cfg(feature = "std")]
+  /// Evaluations performed by the last call of [`Self::solve_problem_with`], including the
+  /// evaluations stored in the checkpoint when resuming.
+  #[inline]
+  pub fn evaluations(&self) -> &GpEvaluations {
+    &self.evaluations
+  }
+
+  #[inline]
+  pub fn clock(&self) -> &CL {
+    &self.clock
+  }
+
   #[inline]
   pub fn max_duration(&self) -> Option<Duration> {
     self.time_opt.as_ref().map(|t| t.max_duration)
   }
 
+  #[inline]
+  pub fn max_evaluations(&self) -> Option<usize> {
+    self.max_evaluations_opt
+  }
+
   #[inline]
   pub fn max_iterations(&self) -> usize {
     self.max_iterations
@@ -99,16 +230,98 @@ where
     &self.quality_comparator_opt
   }
 
+  #[inline]
+  pub fn restart(&self) -> &RE {
+    &self.restart
+  }
+
   #[inline]
   pub fn stagnation(&self) -> Option<(Pct, usize)> {
     self.stagnation_opt.as_ref().map(|s| (s.percentage, s.threshold))
   }
 
-  #[cfg(feature = "std")]
+  #[inline]
+  pub fn termination(&self) -> &TE {
+    &self.termination
+  }
+
+  /// If the last optimization was aborted through the cancellation handle. Results of the
+  /// problem and the best result are the ones of the last evaluated iteration.
+  #[inline]
+  pub fn was_cancelled(&self) -> bool {
+    self.was_cancelled
+  }
+
+  /// Handle polled at each iteration, after the evaluation of the results. See
+  /// [`Cancellation`].
+  #[inline]
+  pub fn set_cancellation<NCA>(
+    self,
+    cancellation: NCA,
+  ) -> OptFacade<D, HCRS, HCS, OH, OR, ORS, OS, QC, SCRS, SCS, SS, TE, NCA, CL, RE> {
+    OptFacade {
+      best_idx_opt: self.best_idx_opt,
+      best_objs_history: self.best_objs_history,
+      best_opt: self.best_opt,
+      cancellation,
+      clock: self.clock,
+      current_iteration: self.current_iteration,
+      evaluations: self.evaluations,
+      max_evaluations_opt: self.max_evaluations_opt,
+      max_iterations: self.max_iterations,
+      objs_goals: self.objs_goals,
+      opt_hooks_opt: self.opt_hooks_opt,
+      phantom: PhantomData,
+      quality_comparator_opt: self.quality_comparator_opt,
+      restart: self.restart,
+      stagnation_opt: self.stagnation_opt,
+      termination: self.termination,
+      time_opt: self.time_opt,
+      was_cancelled: self.was_cancelled,
+    }
+  }
+
+  /// Source of time used by [`Self::set_max_duration`]. See [`Clock`].
+  #[inline]
+  pub fn set_clock<NCL>(
+    self,
+    clock: NCL,
+  ) -> OptFacade<D, HCRS, HCS, OH, OR, ORS, OS, QC, SCRS, SCS, SS, TE, CA, NCL, RE> {
+    OptFacade {
+      best_idx_opt: self.best_idx_opt,
+      best_objs_history: self.best_objs_history,
+      best_opt: self.best_opt,
+      cancellation: self.cancellation,
+      clock,
+      current_iteration: self.current_iteration,
+      evaluations: self.evaluations,
+      max_evaluations_opt: self.max_evaluations_opt,
+      max_iterations: self.max_iterations,
+      objs_goals: self.objs_goals,
+      opt_hooks_opt: self.opt_hooks_opt,
+      phantom: PhantomData,
+      quality_comparator_opt: self.quality_comparator_opt,
+      restart: self.restart,
+      stagnation_opt: self.stagnation_opt,
+      termination: self.termination,
+      time_opt: self.time_opt,
+      was_cancelled: self.was_cancelled,
+    }
+  }
+
+  /// Maximum duration of an optimization, measured by the clock. See [`Self::set_clock`].
   #[inline]
   #[must_use]
   pub fn set_max_duration(mut self, max_duration: Duration) -> Self {
-    self.time_opt = Some(Time { current_duration: Instant::now(), max_duration });
+    self.time_opt = Some(Time { max_duration, start: self.clock.now() });
+    self
+  }
+
+  /// The maximum number of times that each objective will be evaluated
+  #[inline]
+  #[must_use]
+  pub fn set_max_evaluations(mut self, max_evaluations: usize) -> Self {
+    self.max_evaluations_opt = Some(max_evaluations);
     self
   }
 
@@ -142,7 +355,8 @@ where
     self
   }
 
-  /// Defines a stagnation stopping criteria.
+  /// Defines a stagnation stopping criteria. The optimization restarts instead of stopping if a
+  /// policy was set through [`Self::set_restart`].
   ///
   /// # Arguments
   ///
@@ -164,6 +378,102 @@ where
     Ok(self)
   }
 
+  /// Policy applied when the stagnation criterion is met instead of stopping. See [`Restart`].
+  #[inline]
+  pub fn set_restart<NRE>(
+    self,
+    restart: NRE,
+  ) -> OptFacade<D, HCRS, HCS, OH, OR, ORS, OS, QC, SCRS, SCS, SS, TE, CA, CL, NRE> {
+    OptFacade {
+      best_idx_opt: self.best_idx_opt,
+      best_objs_history: self.best_objs_history,
+      best_opt: self.best_opt,
+      cancellation: self.cancellation,
+      clock: self.clock,
+      current_iteration: self.current_iteration,
+      evaluations: self.evaluations,
+      max_evaluations_opt: self.max_evaluations_opt,
+      max_iterations: self.max_iterations,
+      objs_goals: self.objs_goals,
+      opt_hooks_opt: self.opt_hooks_opt,
+      phantom: PhantomData,
+      quality_comparator_opt: self.quality_comparator_opt,
+      restart,
+      stagnation_opt: self.stagnation_opt,
+      termination: self.termination,
+      time_opt: self.time_opt,
+      was_cancelled: self.was_cancelled,
+    }
+  }
+
+  /// Additional stopping criteria verified at each iteration. See [`Termination`].
+  #[inline]
+  pub fn set_termination<NTE>(
+    self,
+    termination: NTE,
+  ) -> OptFacade<D, HCRS, HCS, OH, OR, ORS, OS, QC, SCRS, SCS, SS, NTE, CA, CL, RE> {
+    OptFacade {
+      best_idx_opt: self.best_idx_opt,
+      best_objs_history: self.best_objs_history,
+      best_opt: self.best_opt,
+      cancellation: self.cancellation,
+      clock: self.clock,
+      current_iteration: self.current_iteration,
+      evaluations: self.evaluations,
+      max_evaluations_opt: self.max_evaluations_opt,
+      max_iterations: self.max_iterations,
+      objs_goals: self.objs_goals,
+      opt_hooks_opt: self.opt_hooks_opt,
+      phantom: PhantomData,
+      quality_comparator_opt: self.quality_comparator_opt,
+      restart: self.restart,
+      stagnation_opt: self.stagnation_opt,
+      termination,
+      time_opt: self.time_opt,
+      was_cancelled: self.was_cancelled,
+    }
+  }
+
+  /// Continues an optimization stopped after the iteration recorded in `checkpoint`.
+  ///
+  /// `solver` and `problem` must have been restored with
+  /// [`SolverCheckpoint::restore`](crate::SolverCheckpoint::restore) beforehand. The maximum
+  /// number of iterations, evaluations or duration can be increased to extend the optimization.
+  /// If the run is resumed with the same parameters, the trajectory is equal to the trajectory
+  /// of a run that wasn't interrupted.
+  #[inline]
+  pub fn resume_problem_with<SOLVER>(
+    mut self,
+    checkpoint: OptFacadeCheckpoint<HCRS, OR, ORS, SCRS, SS, RE::Checkpoint, TE::Checkpoint>,
+    problem: &mut Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>,
+    solver: SOLVER,
+  ) -> Result<Self, SOLVER::Error>
+  where
+    RE: RestartCheckpoint<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
+    SOLVER: Solver<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
+    SOLVER::Error: From<crate::Error>,
+    TE: TerminationCheckpoint<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
+  {
+    if checkpoint.version != OPT_FACADE_CHECKPOINT_VERSION {
+      return Err(crate::Error::UnsupportedCheckpointVersion(checkpoint.version).into());
+    }
+    self.reset_aux_params(problem);
+    self.best_idx_opt = checkpoint.best_idx_opt;
+    self.best_objs_history = checkpoint.best_objs_history;
+    self.best_opt = checkpoint.best_opt;
+    self.current_iteration = checkpoint.current_iteration;
+    self.evaluations = checkpoint.evaluations;
+    self.restart.restore(checkpoint.restart);
+    self.termination.restore(checkpoint.termination);
+    if let (Some(stagnation), Some((last_fitness, threshold_counter))) =
+      (self.stagnation_opt.as_mut(), checkpoint.stagnation_opt)
+    {
+      stagnation.last_fitness = last_fitness;
+      stagnation.threshold_counter = threshold_counter;
+    }
+    self.run(problem, solver, true)
+  }
+
   #[inline]
   pub fn solve_problem_with<SOLVER>(
     mut self,
@@ -174,66 +484,138 @@ where
     SOLVER: Solver<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
     SOLVER::Error: From<crate::Error>,
   {
-    self.reset_aux_params();
+    self.reset_aux_params(problem);
     solver.init(problem);
+    self.run(problem, solver, false)
+  }
+
+  fn evaluations_number_has_extrapolated(&self) -> bool {
+    self.max_evaluations_opt.is_some_and(|max| self.evaluations.max_objs() >= max)
+  }
+
+  fn iterations_number_has_extrapolated(&mut self) -> bool {
+    self.current_iteration += 1;
+    self.current_iteration >= self.max_iterations
+  }
+
+  // A resumed run starts right after the stopping verification of the checkpointed iteration.
+  fn run<SOLVER>(
+    mut self,
+    problem: &mut Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>,
+    mut solver: SOLVER,
+    is_resuming: bool,
+  ) -> Result<Self, SOLVER::Error>
+  where
+    SOLVER: Solver<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
+    SOLVER::Error: From<crate::Error>,
+  {
+    let initial_evaluations = problem.defs().evaluations().snapshot();
+    let previous_evaluations = self.evaluations.clone();
+    if !is_resuming {
+      self.restart.init(problem);
+      self.termination.init();
+    }
+    self.was_cancelled = false;
     if let Some(oh) = self.opt_hooks_opt.as_mut() {
       oh.init();
     }
+    let mut skip_verification = is_resuming;
     loop {
-      if let Some(oh) = self.opt_hooks_opt.as_mut() {
-        oh.before_iter(problem);
-      }
-      solver.before_iter(problem)?;
-      // Ignore if best result couldn't be found
-      let _ = self.manage_best_result(problem);
-      let should_stop_partial = self.iterations_number_has_extrapolated()
-        || crate::Error::opt_rslt(self.objs_are_not_converging(problem))?
-        || crate::Error::opt_rslt(self.were_all_specified_goals_achieved(problem))?;
-      #[cfg(feature = "std")]
-      let should_stop = self.time_has_expired() || should_stop_partial;
-      #[cfg(not(feature = "std"))]
-      let should_stop = should_stop_partial;
-      if should_stop {
-        break;
+      let mut was_restarted = false;
+      if skip_verification {
+        skip_verification = false;
+        if self.evaluations_number_has_extrapolated()
+          || self.current_iteration >= self.max_iterations
+        {
+          break;
+        }
+      } else {
+        let progress = Progress::new(self.current_iteration, self.max_iterations);
+        let hooks_stop = if let Some(oh) = self.opt_hooks_opt.as_mut() {
+          oh.before_iter(problem, &progress, self.best_opt.as_ref()).is_break()
+        } else {
+          false
+        };
+        solver.before_iter(problem)?;
+        // Ignore if best result couldn't be found
+        let _ = self.manage_best_result(problem);
+        self.evaluations = previous_evaluations.clone()
+          + problem.defs().evaluations().snapshot().since(&initial_evaluations);
+        if self.cancellation.is_cancelled() {
+          self.was_cancelled = true;
+          break;
+        }
+        let should_stop = self.termination.should_stop(problem)
+          || self.time_has_expired()
+          || self.evaluations_number_has_extrapolated()
+          || self.iterations_number_has_extrapolated()
+          || crate::Error::opt_rslt(self.were_all_specified_goals_achieved(problem))?;
+        if hooks_stop || should_stop {
+          break;
+        }
+        if crate::Error::opt_rslt(self.objs_are_not_converging(problem))? {
+          if !self.restart.restart(problem) {
+            break;
+          }
+          self.reset_stagnation();
+          solver.restarted(problem);
+          was_restarted = true;
+        }
       }
+      let progress = Progress::new(self.current_iteration, self.max_iterations);
       if let Some(oh) = self.opt_hooks_opt.as_mut() {
-        oh.after_iter(problem);
+        if oh.after_iter(problem, &progress, self.best_opt.as_ref()).is_break() {
+          break;
+        }
+      }
+      solver.update_progress(&progress);
+      if !was_restarted {
+        solver.after_iter(problem)?;
       }
-      solver.after_iter(problem)?;
     }
     solver.finished(problem);
+    self.restart.finished(problem);
+    self.evaluations =
+      previous_evaluations + problem.defs().evaluations().snapshot().since(&initial_evaluations);
     if let Some(oh) = self.opt_hooks_opt.as_mut() {
       oh.finished();
     }
     Ok(self)
   }
 
-  fn iterations_number_has_extrapolated(&mut self) -> bool {
-    self.current_iteration += 1;
-    self.current_iteration >= self.max_iterations
-  }
-
   fn manage_best_result(
     &mut self,
     problem: &Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>,
   ) -> Option<()> {
     let quality_comparator = self.quality_comparator_opt.as_ref()?;
-    let mut best_idx = 0;
     let (defs, rslts) = problem.parts();
+    let mut best_idx = 0;
+    let mut best_rslt = rslts.get(best_idx)?;
     for current_idx in 1..rslts.rslts_num() {
-      let current_solution = rslts.get(current_idx)?;
-      let best_solution = rslts.get(current_idx)?;
-      if quality_comparator.is_better(defs.objs(), &current_solution, &best_solution) {
+      let current_rslt = rslts.get(current_idx)?;
+      if quality_comparator.is_better(defs.objs(), &current_rslt, &best_rslt) {
         best_idx = current_idx;
+        best_rslt = current_rslt;
       }
     }
     self.best_idx_opt = Some(best_idx);
+    let _ = self.best_objs_history.constructor().row_slice(best_rslt.obj_rslts)?;
+    let best = self.best_opt.get_or_insert_with(|| GpOrs::with_capacity(defs, 1));
+    let is_best_ever = if let Some(best_ever_rslt) = best.get(0) {
+      quality_comparator.is_better(defs.objs(), &best_rslt, &best_ever_rslt)
+    } else {
+      true
+    };
+    if is_best_ever {
+      best.clear();
+      let _ = best.constructor().or_ref(&best_rslt)?;
+    }
     Some(())
   }
 
   fn objs_are_not_converging(
     &mut self,
-    problem: &mut Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>,
+    problem: &Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>,
   ) -> Option<bool> {
     let (best_idx_opt, stagnation_opt) = (self.best_idx_opt, self.stagnation_opt.as_mut());
     if let Some(stagnation) = stagnation_opt {
@@ -256,24 +638,28 @@ where
     }
   }
 
-  fn reset_aux_params(&mut self) {
+  fn reset_aux_params(&mut self, problem: &Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>) {
+    self.best_idx_opt = None;
+    self.best_objs_history = DrMatrix::with_capacity(0, problem.defs().objs().len());
+    self.best_opt = None;
     self.current_iteration = 0;
+    self.evaluations = GpEvaluations::default();
+    self.reset_stagnation();
+    if let Some(time) = self.time_opt.as_mut() {
+      time.start = self.clock.now();
+    }
+  }
+
+  fn reset_stagnation(&mut self) {
     if let Some(stagnation) = self.stagnation_opt.as_mut() {
       stagnation.last_fitness = OR::zero();
       stagnation.threshold_counter = 0;
     }
-    #[cfg(feature = "std")]
-    {
-      if let Some(time) = self.time_opt.as_mut() {
-        time.current_duration = Instant::now();
-      }
-    }
   }
 
-  #[cfg(feature = "std")]
   fn time_has_expired(&self) -> bool {
     if let Some(t) = self.time_opt.as_ref() {
-      t.current_duration.elapsed() > t.max_duration
+      self.clock.now().saturating_sub(t.start) > t.max_duration
     } else {
       false
     }
@@ -324,8 +710,223 @@ struct Stagnation<OR> {
 }
 
 #[derive(Debug)]
-#[cfg(feature = "std")]
 struct Time {
-  current_duration: Instant,
   max_duration: Duration,
+  start: Duration,
+}
+
+#[cfg(all(feature = "rand", test))]
+mod tests {
+  use crate::{
+    gp::{GpOrsEvaluators, MpOrsVec, MpVec},
+    opt::{
+      Any, CancellationToken, DiversityCollapse, EvaluationBudget, ManualClock, OptFacade,
+      OptHooks, OptHooksFnBuilder, PopulationRestart,
+    },
+    quality_comparator::ObjsAvg,
+    utils::dummy_mp_with_solutions,
+    ObjDirection, Pct, Progress, Solver,
+  };
+  use alloc::{boxed::Box, vec::Vec};
+  use core::{
+    ops::{ControlFlow, RangeInclusive},
+    time::Duration,
+  };
+  use rand::rngs::OsRng;
+
+  type Problem =
+    MpVec<[RangeInclusive<f64>; 2], (ObjDirection, fn(&[f64; 2]) -> f64), f64, [f64; 2]>;
+
+  // Evaluates the objectives of the population
+  struct Evaluate;
+
+  impl Solver<Problem> for Evaluate {
+    type Error = crate::Error;
+
+    fn after_iter(&mut self, _: &mut Problem) -> Result<(), Self::Error> {
+      Ok(())
+    }
+
+    fn before_iter(&mut self, p: &mut Problem) -> Result<(), Self::Error> {
+      let (defs, rslts) = p.parts_mut();
+      GpOrsEvaluators::eval_objs(defs, rslts);
+      Ok(())
+    }
+  }
+
+  // Replaces the population with a single worse result
+  struct Worsen;
+
+  impl Solver<Problem> for Worsen {
+    type Error = crate::Error;
+
+    fn after_iter(&mut self, p: &mut Problem) -> Result<(), Self::Error> {
+      p.rslts_mut().clear();
+      let _ = p.rslts_mut().constructor().or_os_iter([20.0, 20.0].into_iter(), [9.0, 9.0]);
+      Ok(())
+    }
+
+    fn before_iter(&mut self, _: &mut Problem) -> Result<(), Self::Error> {
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn opt_facade() {
+    let mut problem = dummy_mp_with_solutions();
+    let facade = OptFacade::new(2)
+      .set_opt_hooks(())
+      .set_quality_comparator(ObjsAvg)
+      .solve_problem_with(&mut problem, Worsen)
+      .unwrap();
+    assert_eq!(facade.curr_best_idx(), Some(0));
+    assert_eq!(facade.best_rslt().unwrap().obj_rslts(), &[4.0, 8.0]);
+    assert_eq!(facade.best_objs_history().data(), &[4.0, 8.0, 20.0, 20.0]);
+  }
+
+  #[test]
+  fn opt_facade_with_termination() {
+    let mut problem = dummy_mp_with_solutions();
+    let facade = OptFacade::new(10)
+      .set_opt_hooks(())
+      .set_quality_comparator(ObjsAvg)
+      .set_termination(DiversityCollapse::new(0.1))
+      .solve_problem_with(&mut problem, Worsen)
+      .unwrap();
+    assert_eq!(facade.best_objs_history().rows(), 2);
+  }
+
+  #[test]
+  fn opt_facade_with_max_evaluations() {
+    let mut problem = dummy_mp_with_solutions();
+    let facade = OptFacade::new(10)
+      .set_opt_hooks(())
+      .set_quality_comparator(ObjsAvg)
+      .set_max_evaluations(8)
+      .solve_problem_with(&mut problem, Evaluate)
+      .unwrap();
+    assert_eq!(facade.best_objs_history().rows(), 2);
+    assert_eq!(facade.evaluations().objs(), &[8, 8]);
+  }
+
+  #[test]
+  fn opt_facade_with_evaluation_budget() {
+    let mut problem = dummy_mp_with_solutions();
+    let facade = OptFacade::new(10)
+      .set_opt_hooks(())
+      .set_quality_comparator(ObjsAvg)
+      .set_termination(Any((DiversityCollapse::new(0.0), EvaluationBudget::new(8))))
+      .solve_problem_with(&mut problem, Evaluate)
+      .unwrap();
+    assert_eq!(facade.best_objs_history().rows(), 2);
+    assert_eq!(facade.evaluations().objs(), &[8, 8]);
+  }
+
+  #[test]
+  fn opt_facade_with_cancellation() {
+    let token = CancellationToken::new();
+    let token_clone = token.clone();
+    let mut problem = dummy_mp_with_solutions();
+    let facade = OptFacade::new(10)
+      .set_cancellation(token)
+      .set_opt_hooks(
+        OptHooksFnBuilder::default()
+          .after_iter(move |_, progress: &Progress, _: Option<&_>| {
+            if progress.current_iteration() == 2 {
+              token_clone.cancel();
+            }
+            ControlFlow::Continue(())
+          })
+          .build(),
+      )
+      .set_quality_comparator(ObjsAvg)
+      .solve_problem_with(&mut problem, Evaluate)
+      .unwrap();
+    assert!(facade.was_cancelled());
+    assert_eq!(facade.best_objs_history().rows(), 3);
+    assert_eq!(facade.best_rslt().unwrap().obj_rslts(), &[0.0, 0.0]);
+  }
+
+  #[test]
+  fn opt_facade_with_clock() {
+    let clock = ManualClock::new();
+    let clock_clone = clock.clone();
+    let mut problem = dummy_mp_with_solutions();
+    let facade = OptFacade::new(10)
+      .set_clock(clock)
+      .set_max_duration(Duration::from_secs(5))
+      .set_opt_hooks(
+        OptHooksFnBuilder::default()
+          .after_iter(move |_, _: &Progress, _: Option<&_>| {
+            clock_clone.advance(Duration::from_secs(2));
+            ControlFlow::Continue(())
+          })
+          .build(),
+      )
+      .set_quality_comparator(ObjsAvg)
+      .solve_problem_with(&mut problem, Evaluate)
+      .unwrap();
+    assert_eq!(facade.best_objs_history().rows(), 4);
+  }
+
+  #[test]
+  fn opt_facade_with_restart() {
+    let mut problem = dummy_mp_with_solutions();
+    let facade = OptFacade::new(20)
+      .set_opt_hooks(())
+      .set_quality_comparator(ObjsAvg)
+      .set_restart(PopulationRestart::new(OsRng).set_max_restarts(2))
+      .set_stagnation(Pct::from_percent(1), 2)
+      .unwrap()
+      .solve_problem_with(&mut problem, Evaluate)
+      .unwrap();
+    assert_eq!(facade.restart().restarts(), 2);
+    assert_eq!(facade.best_objs_history().rows(), 6);
+    // All results are equivalent, thus each restart stores the whole population as elites
+    assert_eq!(problem.rslts().rslts_num(), 12);
+  }
+
+  #[test]
+  fn opt_facade_with_hooks() {
+    let mut iterations = Vec::new();
+    let mut problem = dummy_mp_with_solutions();
+    let facade = OptFacade::new(10)
+      .set_opt_hooks(
+        OptHooksFnBuilder::default()
+          .before_iter(|_, progress: &Progress, best: Option<&_>| {
+            iterations.push((progress.current_iteration(), best.is_some()));
+            if progress.current_iteration() == 2 {
+              ControlFlow::Break(())
+            } else {
+              ControlFlow::Continue(())
+            }
+          })
+          .build(),
+      )
+      .set_quality_comparator(ObjsAvg)
+      .solve_problem_with(&mut problem, Evaluate)
+      .unwrap();
+    assert_eq!(facade.best_objs_history().rows(), 3);
+    drop(facade);
+    assert_eq!(iterations, [(0, false), (1, true), (2, true)]);
+
+    let hooks: Box<dyn OptHooks<MpOrsVec<f64, [f64; 2]>, Problem>> = Box::new((
+      |_: &mut Problem, _: &Progress, _: Option<&_>| ControlFlow::Continue(()),
+      |_: &mut Problem, progress: &Progress, _: Option<&_>| {
+        if progress.current_iteration() == 4 {
+          ControlFlow::Break(())
+        } else {
+          ControlFlow::Continue(())
+        }
+      },
+      || {},
+      || {},
+    ));
+    let facade = OptFacade::new(10)
+      .set_opt_hooks(hooks)
+      .set_quality_comparator(ObjsAvg)
+      .solve_problem_with(&mut problem, Evaluate)
+      .unwrap();
+    assert_eq!(facade.best_objs_history().rows(), 4);
+  }
 }
@@ -0,0 +1,60 @@
+use crate::{
+  dr_matrix::DrMatrixVec,
+  gp::{GpEvaluations, GpOrs},
+};
+
+pub(crate) const OPT_FACADE_CHECKPOINT_VERSION: u32 = 2;
+
+/// Counters and stopping state of [`OptFacade`](super::OptFacade) between two iterations.
+///
+/// Created by `OptFacade::checkpoint` and consumed by `OptFacade::resume_problem_with`.
+///
+/// # Types
+///
+/// * `RC`: [`RestartCheckpoint`](super::RestartCheckpoint) state
+/// * `TC`: [`TerminationCheckpoint`](super::TerminationCheckpoint) state
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptFacadeCheckpoint<HCRS, OR, ORS, SCRS, SS, RC = (), TC = ()> {
+  pub(crate) best_idx_opt: Option<usize>,
+  pub(crate) best_objs_history: DrMatrixVec<OR>,
+  pub(crate) best_opt: Option<GpOrs<HCRS, ORS, SCRS, SS>>,
+  pub(crate) current_iteration: usize,
+  pub(crate) evaluations: GpEvaluations,
+  pub(crate) restart: RC,
+  pub(crate) stagnation_opt: Option<(OR, usize)>,
+  pub(crate) termination: TC,
+  pub(crate) version: u32,
+}
+
+impl<HCRS, OR, ORS, RC, SCRS, SS, TC> OptFacadeCheckpoint<HCRS, OR, ORS, SCRS, SS, RC, TC> {
+  /// Number of processed iterations
+  #[inline]
+  pub fn current_iteration(&self) -> usize {
+    self.current_iteration
+  }
+
+  /// Evaluations performed until the checkpoint
+  #[inline]
+  pub fn evaluations(&self) -> &GpEvaluations {
+    &self.evaluations
+  }
+
+  /// State of the restart policy
+  #[inline]
+  pub fn restart(&self) -> &RC {
+    &self.restart
+  }
+
+  /// State of the termination criterion
+  #[inline]
+  pub fn termination(&self) -> &TC {
+    &self.termination
+  }
+
+  /// Format version
+  #[inline]
+  pub fn version(&self) -> u32 {
+    self.version
+  }
+}
@@ -1,19 +1,44 @@
-pub trait OptHooks<P> {
+use crate::Progress;
+use alloc::boxed::Box;
+use core::ops::ControlFlow;
+
+/// Callbacks invoked by [`OptFacade`](crate::opt::OptFacade) during an optimization.
+///
+/// Implemented for `()`, for tuples of `(before_iter, after_iter, finished, init)` closures or
+/// function pointers (see [`OptHooksFnBuilder`](crate::opt::OptHooksFnBuilder)), for mutable
+/// references and for boxed trait objects.
+///
+/// # Types
+///
+/// * `B`: Storage of the best result found so far
+/// * `P`: Problem
+pub trait OptHooks<B, P> {
+  /// Called once before the first iteration.
   fn init(&mut self);
 
-  fn before_iter(&mut self, p: &mut P);
+  /// Called at the beginning of each iteration with the current progress and the best result
+  /// found so far. Returning [`ControlFlow::Break`] stops the optimization at the end of the
+  /// stopping verification of this iteration.
+  fn before_iter(&mut self, p: &mut P, progress: &Progress, best: Option<&B>) -> ControlFlow<()>;
 
-  fn after_iter(&mut self, p: &mut P);
+  /// Called after the stopping verification, before the solver modifies the problem.
+  /// Returning [`ControlFlow::Break`] stops the optimization immediately.
+  fn after_iter(&mut self, p: &mut P, progress: &Progress, best: Option<&B>) -> ControlFlow<()>;
 
+  /// Called once after the last iteration.
   fn finished(&mut self);
 }
 
-impl<P> OptHooks<P> for () {
+impl<B, P> OptHooks<B, P> for () {
   #[inline]
-  fn before_iter(&mut self, _: &mut P) {}
+  fn before_iter(&mut self, _: &mut P, _: &Progress, _: Option<&B>) -> ControlFlow<()> {
+    ControlFlow::Continue(())
+  }
 
   #[inline]
-  fn after_iter(&mut self, _: &mut P) {}
+  fn after_iter(&mut self, _: &mut P, _: &Progress, _: Option<&B>) -> ControlFlow<()> {
+    ControlFlow::Continue(())
+  }
 
   #[inline]
   fn finished(&mut self) {}
@@ -22,15 +47,21 @@ impl<P> OptHooks<P> for () {
   fn init(&mut self) {}
 }
 
-impl<P> OptHooks<P> for (fn(&mut P), fn(&mut P), fn(), fn()) {
+impl<AI, B, BI, FI, II, P> OptHooks<B, P> for (BI, AI, FI, II)
+where
+  AI: FnMut(&mut P, &Progress, Option<&B>) -> ControlFlow<()>,
+  BI: FnMut(&mut P, &Progress, Option<&B>) -> ControlFlow<()>,
+  FI: FnMut(),
+  II: FnMut(),
+{
   #[inline]
-  fn before_iter(&mut self, p: &mut P) {
-    (self.0)(p);
+  fn before_iter(&mut self, p: &mut P, progress: &Progress, best: Option<&B>) -> ControlFlow<()> {
+    (self.0)(p, progress, best)
   }
 
   #[inline]
-  fn after_iter(&mut self, p: &mut P) {
-    (self.1)(p);
+  fn after_iter(&mut self, p: &mut P, progress: &Progress, best: Option<&B>) -> ControlFlow<()> {
+    (self.1)(p, progress, best)
   }
 
   #[inline]
@@ -43,3 +74,53 @@ impl<P> OptHooks<P> for (fn(&mut P), fn(&mut P), fn(), fn()) {
     (self.3)();
   }
 }
+
+impl<B, P, T> OptHooks<B, P> for &mut T
+where
+  T: OptHooks<B, P> + ?Sized,
+{
+  #[inline]
+  fn before_iter(&mut self, p: &mut P, progress: &Progress, best: Option<&B>) -> ControlFlow<()> {
+    (**self).before_iter(p, progress, best)
+  }
+
+  #[inline]
+  fn after_iter(&mut self, p: &mut P, progress: &Progress, best: Option<&B>) -> ControlFlow<()> {
+    (**self).after_iter(p, progress, best)
+  }
+
+  #[inline]
+  fn finished(&mut self) {
+    (**self).finished();
+  }
+
+  #[inline]
+  fn init(&mut self) {
+    (**self).init();
+  }
+}
+
+impl<B, P, T> OptHooks<B, P> for Box<T>
+where
+  T: OptHooks<B, P> + ?Sized,
+{
+  #[inline]
+  fn before_iter(&mut self, p: &mut P, progress: &Progress, best: Option<&B>) -> ControlFlow<()> {
+    (**self).before_iter(p, progress, best)
+  }
+
+  #[inline]
+  fn after_iter(&mut self, p: &mut P, progress: &Progress, best: Option<&B>) -> ControlFlow<()> {
+    (**self).after_iter(p, progress, best)
+  }
+
+  #[inline]
+  fn finished(&mut self) {
+    (**self).finished();
+  }
+
+  #[inline]
+  fn init(&mut self) {
+    (**self).init();
+  }
+}
@@ -1,73 +1,96 @@
-use core::fmt;
+use crate::Progress;
+use core::{fmt, marker::PhantomData, ops::ControlFlow};
 
-type OptHooksFn<P> = (fn(&mut P), fn(&mut P), fn(), fn());
+type IterFn<B, P> = fn(&mut P, &Progress, Option<&B>) -> ControlFlow<()>;
 
-#[derive(Debug)]
-pub struct OptHooksFnBuilder<P> {
-  after_opt_move: Option<FnWrapper<P>>,
-  before_opt_move: Option<FnWrapper<P>>,
-  finished: Option<fn()>,
-  init: Option<fn()>,
+/// Builds a tuple of [`OptHooks`](crate::opt::OptHooks) closures. Unspecified hooks do nothing.
+pub struct OptHooksFnBuilder<AI, B, BI, FI, II, P> {
+  after_iter: AI,
+  before_iter: BI,
+  finished: FI,
+  init: II,
+  phantom: PhantomData<(B, P)>,
 }
 
-impl<P> OptHooksFnBuilder<P> {
+impl<AI, B, BI, FI, II, P> OptHooksFnBuilder<AI, B, BI, FI, II, P> {
   #[inline]
-  pub fn build(self) -> OptHooksFn<P> {
-    (
-      self.after_opt_move.unwrap_or_default().0,
-      self.before_opt_move.unwrap_or_default().0,
-      self.finished.unwrap_or(|| {}),
-      self.init.unwrap_or(|| {}),
-    )
+  pub fn build(self) -> (BI, AI, FI, II) {
+    (self.before_iter, self.after_iter, self.finished, self.init)
   }
 
   #[inline]
-  #[must_use]
-  pub fn after_iter(mut self, after_opt_move: fn(&mut P)) -> Self {
-    self.after_opt_move = Some(FnWrapper(after_opt_move));
-    self
+  pub fn after_iter<NAI>(self, after_iter: NAI) -> OptHooksFnBuilder<NAI, B, BI, FI, II, P>
+  where
+    NAI: FnMut(&mut P, &Progress, Option<&B>) -> ControlFlow<()>,
+  {
+    OptHooksFnBuilder {
+      after_iter,
+      before_iter: self.before_iter,
+      finished: self.finished,
+      init: self.init,
+      phantom: PhantomData,
+    }
   }
 
   #[inline]
-  #[must_use]
-  pub fn before_iter(mut self, before_opt_move: fn(&mut P)) -> Self {
-    self.before_opt_move = Some(FnWrapper(before_opt_move));
-    self
+  pub fn before_iter<NBI>(self, before_iter: NBI) -> OptHooksFnBuilder<AI, B, NBI, FI, II, P>
+  where
+    NBI: FnMut(&mut P, &Progress, Option<&B>) -> ControlFlow<()>,
+  {
+    OptHooksFnBuilder {
+      after_iter: self.after_iter,
+      before_iter,
+      finished: self.finished,
+      init: self.init,
+      phantom: PhantomData,
+    }
   }
 
   #[inline]
-  #[must_use]
-  pub fn finished(mut self, finished: fn()) -> Self {
-    self.finished = Some(finished);
-    self
+  pub fn finished<NFI>(self, finished: NFI) -> OptHooksFnBuilder<AI, B, BI, NFI, II, P>
+  where
+    NFI: FnMut(),
+  {
+    OptHooksFnBuilder {
+      after_iter: self.after_iter,
+      before_iter: self.before_iter,
+      finished,
+      init: self.init,
+      phantom: PhantomData,
+    }
   }
 
   #[inline]
-  #[must_use]
-  pub fn init(mut self, init: fn()) -> Self {
-    self.init = Some(init);
-    self
+  pub fn init<NII>(self, init: NII) -> OptHooksFnBuilder<AI, B, BI, FI, NII, P>
+  where
+    NII: FnMut(),
+  {
+    OptHooksFnBuilder {
+      after_iter: self.after_iter,
+      before_iter: self.before_iter,
+      finished: self.finished,
+      init,
+      phantom: PhantomData,
+    }
   }
 }
 
-impl<P> Default for OptHooksFnBuilder<P> {
+impl<AI, B, BI, FI, II, P> fmt::Debug for OptHooksFnBuilder<AI, B, BI, FI, II, P> {
   #[inline]
-  fn default() -> Self {
-    Self { after_opt_move: None, before_opt_move: None, finished: None, init: None }
-  }
-}
-
-struct FnWrapper<P>(fn(&mut P));
-
-impl<P> fmt::Debug for FnWrapper<P> {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    let pointer: *const fn(&mut P) = &self.0;
-    fmt::Pointer::fmt(&pointer, f)
+    f.debug_struct("OptHooksFnBuilder").finish_non_exhaustive()
   }
 }
 
-impl<P> Default for FnWrapper<P> {
+impl<B, P> Default for OptHooksFnBuilder<IterFn<B, P>, B, IterFn<B, P>, fn(), fn(), P> {
+  #[inline]
   fn default() -> Self {
-    FnWrapper(|_| {})
+    Self {
+      after_iter: |_, _, _| ControlFlow::Continue(()),
+      before_iter: |_, _, _| ControlFlow::Continue(()),
+      finished: || {},
+      init: || {},
+      phantom: PhantomData,
+    }
   }
 }
@@ -0,0 +1,54 @@
+#[cfg(feature = "rand")]
+mod population_restart;
+
+#[cfg(feature = "rand")]
+pub use population_restart::PopulationRestart;
+
+/// Restart policy applied by `OptFacade` when the stagnation criterion is met, instead of
+/// stopping the optimization. See `OptFacade::set_stagnation`.
+///
+/// Maximum iterations, evaluations and duration are shared by all restarts.
+pub trait Restart<P> {
+  /// Resets any internal state when solving is starting
+  #[inline]
+  fn init(&mut self, _: &P) {}
+
+  /// Modifies the population of `p` to start a new search. Returns `false` if the optimization
+  /// should stop instead.
+  fn restart(&mut self, p: &mut P) -> bool;
+
+  /// Called once after the last iteration
+  #[inline]
+  fn finished(&mut self, _: &mut P) {}
+}
+
+/// Restart policy whose state can be saved between two iterations and later restored. See
+/// [`OptFacade::checkpoint`](crate::opt::OptFacade::checkpoint).
+pub trait RestartCheckpoint<P>: Restart<P> {
+  /// Snapshot of the state. Serializable with the `serde` feature.
+  type Checkpoint;
+
+  /// Current state of the policy
+  fn checkpoint(&self) -> Self::Checkpoint;
+
+  /// Overwrites the state of the policy with `checkpoint`.
+  fn restore(&mut self, checkpoint: Self::Checkpoint);
+}
+
+/// Never restarts
+impl<P> Restart<P> for () {
+  #[inline]
+  fn restart(&mut self, _: &mut P) -> bool {
+    false
+  }
+}
+
+impl<P> RestartCheckpoint<P> for () {
+  type Checkpoint = ();
+
+  #[inline]
+  fn checkpoint(&self) -> Self::Checkpoint {}
+
+  #[inline]
+  fn restore(&mut self, _: Self::Checkpoint) {}
+}
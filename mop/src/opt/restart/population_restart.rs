@@ -0,0 +1,240 @@
+use crate::{
+  gp::{Gp, GpOrs},
+  opt::{Restart, RestartCheckpoint},
+  sampler::{Sampler, Uniform},
+  utils::verify_pareto_dominance,
+  Obj, Pct,
+};
+use cl_aux::{Clear, Push, SingleTypeStorage, WithCapacity};
+use core::cmp::Ordering;
+use rand::Rng;
+
+/// Keeps the non-dominated results of the stagnated population and replaces the others with
+/// solutions of a [`Sampler`], which defaults to [`Uniform`].
+///
+/// The population can grow at each restart (IPOP-style). Elites of all restarts are stored and
+/// appended to the final results of the problem.
+///
+/// Checkpoints store the elites and the number of restarts but not the state of the random
+/// number generator.
+///
+/// # Types
+///
+/// * `E`: Elites storage
+/// * `R`: Random number generator
+/// * `SA`: Sampler
+#[derive(Debug)]
+pub struct PopulationRestart<E, R, SA = Uniform> {
+  elites_opt: Option<E>,
+  max_restarts_opt: Option<usize>,
+  population_increase: Pct,
+  restarts: usize,
+  rng: R,
+  sampler: SA,
+}
+
+impl<E, R> PopulationRestart<E, R> {
+  #[inline]
+  pub fn new(rng: R) -> Self {
+    Self {
+      elites_opt: None,
+      max_restarts_opt: None,
+      population_increase: Pct::from_percent(0),
+      restarts: 0,
+      rng,
+      sampler: Uniform,
+    }
+  }
+}
+
+impl<E, R, SA> PopulationRestart<E, R, SA> {
+  /// Elites of all restarts of the current optimization
+  #[inline]
+  pub fn elites(&self) -> Option<&E> {
+    self.elites_opt.as_ref()
+  }
+
+  #[inline]
+  pub fn max_restarts(&self) -> Option<usize> {
+    self.max_restarts_opt
+  }
+
+  #[inline]
+  pub fn population_increase(&self) -> Pct {
+    self.population_increase
+  }
+
+  /// Number of restarts of the current optimization
+  #[inline]
+  pub fn restarts(&self) -> usize {
+    self.restarts
+  }
+
+  #[inline]
+  pub fn sampler(&self) -> &SA {
+    &self.sampler
+  }
+
+  /// Stops the optimization at the next stagnation after `max_restarts` restarts
+  #[inline]
+  #[must_use]
+  pub fn set_max_restarts(mut self, max_restarts: usize) -> Self {
+    self.max_restarts_opt = Some(max_restarts);
+    self
+  }
+
+  /// Increases the population size by a percentage of the current size at each restart, e.g.,
+  /// 100% doubles the population.
+  #[inline]
+  #[must_use]
+  pub fn set_population_increase(mut self, population_increase: Pct) -> Self {
+    self.population_increase = population_increase;
+    self
+  }
+
+  /// Generates the new solutions of each restart
+  #[inline]
+  pub fn set_sampler<NSA>(self, sampler: NSA) -> PopulationRestart<E, R, NSA> {
+    PopulationRestart {
+      elites_opt: self.elites_opt,
+      max_restarts_opt: self.max_restarts_opt,
+      population_increase: self.population_increase,
+      restarts: self.restarts,
+      rng: self.rng,
+      sampler,
+    }
+  }
+}
+
+impl<D, HC, HCR, HCRS, HCS, O, OR, ORS, OS, R, S, SA, SC, SCR, SCRS, SCS, SS>
+  Restart<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>
+  for PopulationRestart<GpOrs<HCRS, ORS, SCRS, SS>, R, SA>
+where
+  HCR: Clone + Default,
+  HCRS: AsRef<[HCR]>
+    + Clear
+    + Extend<HCR>
+    + SingleTypeStorage<Item = HCR>
+    + WithCapacity<Input = usize>,
+  HCS: AsRef<[HC]> + SingleTypeStorage<Item = HC>,
+  O: Obj<OR, S>,
+  OR: Clone + Default + PartialOrd,
+  ORS:
+    AsRef<[OR]> + Clear + Extend<OR> + SingleTypeStorage<Item = OR> + WithCapacity<Input = usize>,
+  OS: AsRef<[O]> + SingleTypeStorage<Item = O>,
+  R: Rng,
+  S: Clone,
+  SA: Sampler<D, S>,
+  SCR: Clone + Default,
+  SCRS: AsRef<[SCR]>
+    + Clear
+    + Extend<SCR>
+    + SingleTypeStorage<Item = SCR>
+    + WithCapacity<Input = usize>,
+  SCS: AsRef<[SC]> + SingleTypeStorage<Item = SC>,
+  SS: AsRef<[S]>
+    + Clear
+    + Extend<S>
+    + Push<S>
+    + SingleTypeStorage<Item = S>
+    + WithCapacity<Input = usize>,
+{
+  #[inline]
+  fn init(&mut self, _: &Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>) {
+    if let Some(elites) = self.elites_opt.as_mut() {
+      elites.clear();
+    }
+    self.restarts = 0;
+  }
+
+  #[inline]
+  fn restart(&mut self, p: &mut Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>) -> bool {
+    if self.max_restarts_opt.is_some_and(|max| self.restarts >= max) {
+      return false;
+    }
+    let (defs, rslts) = p.parts_mut();
+    let elites = self.elites_opt.get_or_insert_with(|| GpOrs::with_capacity(defs, 0));
+    let first_new_elite = elites.rslts_num();
+    for rslt in rslts.iter() {
+      let is_dominated = rslts.iter().any(|other| {
+        verify_pareto_dominance(defs.objs(), other.obj_rslts(), rslt.obj_rslts())
+          == Ordering::Greater
+      });
+      if !is_dominated && elites.constructor().or_ref(&rslt).is_none() {
+        return false;
+      }
+    }
+    let population_size = rslts.rslts_num();
+    let new_population_size =
+      population_size.saturating_add(self.population_increase.saturating_mul(&population_size));
+    rslts.clear();
+    for elite in elites.iter().skip(first_new_elite).take(new_population_size) {
+      if rslts.constructor().or_ref(&elite).is_none() {
+        return false;
+      }
+    }
+    let missing = new_population_size.saturating_sub(rslts.rslts_num());
+    let Ok(solutions) = self.sampler.sample(defs.domain(), missing, &mut self.rng) else {
+      return false;
+    };
+    if rslts.constructor().ors_s_iter(solutions.into_iter().map(Ok::<_, ()>)).is_none() {
+      return false;
+    }
+    self.restarts = self.restarts.saturating_add(1);
+    true
+  }
+
+  #[inline]
+  fn finished(&mut self, p: &mut Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>) {
+    if let Some(elites) = self.elites_opt.as_ref() {
+      let _ = p.rslts_mut().constructor().ors_ref(&elites.as_ref());
+    }
+  }
+}
+
+impl<D, E, HCRS, HCS, ORS, OS, R, SA, SCRS, SCS, SS>
+  RestartCheckpoint<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>> for PopulationRestart<E, R, SA>
+where
+  E: Clone,
+  Self: Restart<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
+{
+  type Checkpoint = (Option<E>, usize);
+
+  #[inline]
+  fn checkpoint(&self) -> Self::Checkpoint {
+    (self.elites_opt.clone(), self.restarts)
+  }
+
+  #[inline]
+  fn restore(&mut self, (elites_opt, restarts): Self::Checkpoint) {
+    self.elites_opt = elites_opt;
+    self.restarts = restarts;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    opt::{PopulationRestart, Restart},
+    utils::dummy_mp_with_solutions,
+    Pct,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn population_restart() {
+    let mut problem = dummy_mp_with_solutions();
+    let mut restart = PopulationRestart::new(OsRng)
+      .set_max_restarts(1)
+      .set_population_increase(Pct::from_percent(100));
+    restart.init(&problem);
+    let population_size = problem.rslts().rslts_num();
+    assert!(restart.restart(&mut problem));
+    assert_eq!(problem.rslts().rslts_num(), population_size * 2);
+    let elites_num = restart.elites().unwrap().rslts_num();
+    assert_eq!(elites_num, 1);
+    assert!(!restart.restart(&mut problem));
+    restart.finished(&mut problem);
+    assert_eq!(problem.rslts().rslts_num(), population_size * 2 + elites_num);
+  }
+}
@@ -0,0 +1,193 @@
+mod diversity_collapse;
+mod evaluation_budget;
+mod hypervolume_improvement;
+mod objs_stagnation_window;
+
+pub use diversity_collapse::DiversityCollapse;
+pub use evaluation_budget::EvaluationBudget;
+pub use hypervolume_improvement::HypervolumeImprovement;
+pub use objs_stagnation_window::ObjsStagnationWindow;
+
+/// Stopping criterion verified by `OptFacade` at each iteration, after `Solver::before_iter`.
+///
+/// Besides [`EvaluationBudget`], evaluation budgets can also be set through
+/// `OptFacade::set_max_evaluations`.
+pub trait Termination<P> {
+  /// Resets any internal state when solving is starting
+  #[inline]
+  fn init(&mut self) {}
+
+  /// Should the optimization stop?
+  fn should_stop(&mut self, p: &P) -> bool;
+}
+
+/// Termination criterion whose state can be saved between two iterations and later restored.
+/// See [`OptFacade::checkpoint`](crate::opt::OptFacade::checkpoint).
+pub trait TerminationCheckpoint<P>: Termination<P> {
+  /// Snapshot of the state. Serializable with the `serde` feature.
+  type Checkpoint;
+
+  /// Current state of the criterion
+  fn checkpoint(&self) -> Self::Checkpoint;
+
+  /// Overwrites the state of the criterion with `checkpoint`.
+  fn restore(&mut self, checkpoint: Self::Checkpoint);
+}
+
+/// Never stops
+impl<P> Termination<P> for () {
+  #[inline]
+  fn should_stop(&mut self, _: &P) -> bool {
+    false
+  }
+}
+
+impl<P> TerminationCheckpoint<P> for () {
+  type Checkpoint = ();
+
+  #[inline]
+  fn checkpoint(&self) -> Self::Checkpoint {}
+
+  #[inline]
+  fn restore(&mut self, _: Self::Checkpoint) {}
+}
+
+impl<F, P> Termination<P> for F
+where
+  F: FnMut(&P) -> bool,
+{
+  #[inline]
+  fn should_stop(&mut self, p: &P) -> bool {
+    self(p)
+  }
+}
+
+impl<F, P> TerminationCheckpoint<P> for F
+where
+  F: FnMut(&P) -> bool,
+{
+  type Checkpoint = ();
+
+  #[inline]
+  fn checkpoint(&self) -> Self::Checkpoint {}
+
+  #[inline]
+  fn restore(&mut self, _: Self::Checkpoint) {}
+}
+
+/// Stops when at least one of the inner criteria stops. Implemented for tuples.
+///
+/// All criteria are verified at each iteration, so stateful criteria are always up to date.
+#[derive(Clone, Debug)]
+pub struct Any<T>(pub T);
+
+/// Stops when all inner criteria stop. Implemented for tuples.
+///
+/// All criteria are verified at each iteration, so stateful criteria are always up to date.
+#[derive(Clone, Debug)]
+pub struct All<T>(pub T);
+
+macro_rules! impl_combinators {
+  ($($ty:ident $idx:tt),+) => {
+    impl<P, $($ty),+> Termination<P> for All<($($ty,)+)>
+    where
+      $($ty: Termination<P>,)+
+    {
+      #[inline]
+      fn init(&mut self) {
+        $(self.0.$idx.init();)+
+      }
+
+      #[inline]
+      fn should_stop(&mut self, p: &P) -> bool {
+        let mut rslt = true;
+        $(rslt &= self.0.$idx.should_stop(p);)+
+        rslt
+      }
+    }
+
+    impl<P, $($ty),+> Termination<P> for Any<($($ty,)+)>
+    where
+      $($ty: Termination<P>,)+
+    {
+      #[inline]
+      fn init(&mut self) {
+        $(self.0.$idx.init();)+
+      }
+
+      #[inline]
+      fn should_stop(&mut self, p: &P) -> bool {
+        let mut rslt = false;
+        $(rslt |= self.0.$idx.should_stop(p);)+
+        rslt
+      }
+    }
+
+    impl<P, $($ty),+> TerminationCheckpoint<P> for All<($($ty,)+)>
+    where
+      $($ty: TerminationCheckpoint<P>,)+
+    {
+      type Checkpoint = ($($ty::Checkpoint,)+);
+
+      #[inline]
+      fn checkpoint(&self) -> Self::Checkpoint {
+        ($(self.0.$idx.checkpoint(),)+)
+      }
+
+      #[inline]
+      fn restore(&mut self, checkpoint: Self::Checkpoint) {
+        $(self.0.$idx.restore(checkpoint.$idx);)+
+      }
+    }
+
+    impl<P, $($ty),+> TerminationCheckpoint<P> for Any<($($ty,)+)>
+    where
+      $($ty: TerminationCheckpoint<P>,)+
+    {
+      type Checkpoint = ($($ty::Checkpoint,)+);
+
+      #[inline]
+      fn checkpoint(&self) -> Self::Checkpoint {
+        ($(self.0.$idx.checkpoint(),)+)
+      }
+
+      #[inline]
+      fn restore(&mut self, checkpoint: Self::Checkpoint) {
+        $(self.0.$idx.restore(checkpoint.$idx);)+
+      }
+    }
+  };
+}
+
+impl_combinators!(A 0);
+impl_combinators!(A 0, B 1);
+impl_combinators!(A 0, B 1, C 2);
+impl_combinators!(A 0, B 1, C 2, D 3);
+impl_combinators!(A 0, B 1, C 2, D 3, E 4);
+impl_combinators!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+#[cfg(test)]
+mod tests {
+  use crate::opt::{All, Any, Termination};
+
+  #[test]
+  fn combinators() {
+    let yes: fn(&()) -> bool = |_| true;
+    let no: fn(&()) -> bool = |_| false;
+    assert!(Any((no, yes)).should_stop(&()));
+    assert!(!Any((no, no)).should_stop(&()));
+    assert!(All((yes, yes)).should_stop(&()));
+    assert!(!All((yes, no)).should_stop(&()));
+  }
+
+  #[test]
+  fn capturing_closure() {
+    let mut calls = 0;
+    let mut termination = |_: &()| {
+      calls += 1;
+      calls > 1
+    };
+    assert!(!Any((&mut termination,)).should_stop(&()));
+    assert!(Any((termination,)).should_stop(&()));
+  }
+}
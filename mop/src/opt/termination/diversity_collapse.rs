@@ -0,0 +1,70 @@
+use crate::{
+  gp::Gp,
+  opt::{Termination, TerminationCheckpoint},
+  utils::objs_diversity,
+};
+use alloc::vec::Vec;
+use cl_aux::SingleTypeStorage;
+use num_traits::NumCast;
+
+/// Stops when the diversity of the objective results of the population falls below
+/// `threshold`.
+///
+/// The diversity is the mean, over all objectives, of the standard deviation of the results
+/// normalized by their range, varying from 0 (all results are equal) to 1.
+#[derive(Clone, Debug)]
+pub struct DiversityCollapse {
+  buffer: Vec<[f64; 4]>,
+  threshold: f64,
+}
+
+impl DiversityCollapse {
+  #[inline]
+  pub fn new(threshold: f64) -> Self {
+    Self { buffer: Vec::new(), threshold }
+  }
+}
+
+impl<D, HCRS, HCS, OR, ORS, OS, SCRS, SCS, SS> Termination<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>
+  for DiversityCollapse
+where
+  OR: Copy + NumCast,
+  ORS: AsRef<[OR]> + SingleTypeStorage<Item = OR>,
+{
+  #[inline]
+  fn should_stop(&mut self, p: &Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>) -> bool {
+    let rows = p.rslts().obj_rslts.row_iter();
+    objs_diversity(rows, &mut self.buffer).is_some_and(|diversity| diversity < self.threshold)
+  }
+}
+
+impl<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>
+  TerminationCheckpoint<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>> for DiversityCollapse
+where
+  Self: Termination<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
+{
+  type Checkpoint = ();
+
+  #[inline]
+  fn checkpoint(&self) -> Self::Checkpoint {}
+
+  #[inline]
+  fn restore(&mut self, _: Self::Checkpoint) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    opt::{DiversityCollapse, Termination},
+    utils::{dummy_mp, dummy_mp_with_solutions},
+  };
+
+  #[test]
+  fn diversity_collapse() {
+    assert!(!DiversityCollapse::new(0.1).should_stop(&dummy_mp_with_solutions()));
+    let mut problem = dummy_mp();
+    let _ = problem.rslts_mut().constructor().or_os_iter([1.0, 1.0].into_iter(), [0.0, 0.0]);
+    let _ = problem.rslts_mut().constructor().or_os_iter([1.0, 1.0].into_iter(), [0.0, 0.0]);
+    assert!(DiversityCollapse::new(0.1).should_stop(&problem));
+  }
+}
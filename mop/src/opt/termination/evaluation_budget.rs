@@ -0,0 +1,105 @@
+use crate::{
+  gp::Gp,
+  opt::{Termination, TerminationCheckpoint},
+};
+
+/// Stops when an objective was evaluated `max_evaluations` times.
+///
+/// Evaluations are read from the [`GpEvaluationsCounter`](crate::gp::GpEvaluationsCounter) of
+/// the problem, which means that evaluations performed before solving are also accounted unless
+/// the counters are reset. Unlike `OptFacade::set_max_evaluations`, this criterion can be combined
+/// with others through [`Any`](crate::opt::Any) or [`All`](crate::opt::All).
+#[derive(Clone, Debug)]
+pub struct EvaluationBudget {
+  evaluations: usize,
+  max_evaluations: usize,
+  restored_evaluations: usize,
+}
+
+impl EvaluationBudget {
+  #[inline]
+  pub fn new(max_evaluations: usize) -> Self {
+    Self { evaluations: 0, max_evaluations, restored_evaluations: 0 }
+  }
+
+  /// Number of evaluations accounted so far
+  #[inline]
+  pub fn evaluations(&self) -> usize {
+    self.evaluations
+  }
+}
+
+impl<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS> Termination<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>
+  for EvaluationBudget
+{
+  #[inline]
+  fn init(&mut self) {
+    self.evaluations = 0;
+    self.restored_evaluations = 0;
+  }
+
+  #[inline]
+  fn should_stop(&mut self, p: &Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>) -> bool {
+    let objs = p.defs().evaluations().snapshot().max_objs();
+    self.evaluations = self.restored_evaluations.saturating_add(objs);
+    self.evaluations >= self.max_evaluations
+  }
+}
+
+/// The checkpoint is the number of accounted evaluations, which are added to the counters of
+/// the problem of the resumed optimization.
+impl<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>
+  TerminationCheckpoint<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>> for EvaluationBudget
+{
+  type Checkpoint = usize;
+
+  #[inline]
+  fn checkpoint(&self) -> Self::Checkpoint {
+    self.evaluations
+  }
+
+  #[inline]
+  fn restore(&mut self, checkpoint: Self::Checkpoint) {
+    self.evaluations = checkpoint;
+    self.restored_evaluations = checkpoint;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    gp::{GpOrsEvaluators, MpVec},
+    opt::{EvaluationBudget, Termination, TerminationCheckpoint},
+    utils::dummy_mp_with_solutions,
+    ObjDirection,
+  };
+  use core::ops::RangeInclusive;
+
+  type Problem =
+    MpVec<[RangeInclusive<f64>; 2], (ObjDirection, fn(&[f64; 2]) -> f64), f64, [f64; 2]>;
+
+  #[test]
+  fn evaluation_budget() {
+    let mut problem = dummy_mp_with_solutions();
+    let mut eb = EvaluationBudget::new(8);
+    Termination::<Problem>::init(&mut eb);
+    let (defs, rslts) = problem.parts_mut();
+    GpOrsEvaluators::eval_objs(defs, rslts);
+    assert!(!eb.should_stop(&problem));
+    let (defs, rslts) = problem.parts_mut();
+    GpOrsEvaluators::eval_objs(defs, rslts);
+    assert!(eb.should_stop(&problem));
+    assert_eq!(eb.evaluations(), 8);
+  }
+
+  #[test]
+  fn evaluation_budget_restore() {
+    let mut problem = dummy_mp_with_solutions();
+    let mut eb = EvaluationBudget::new(8);
+    TerminationCheckpoint::<Problem>::restore(&mut eb, 4);
+    let (defs, rslts) = problem.parts_mut();
+    GpOrsEvaluators::eval_objs(defs, rslts);
+    assert!(eb.should_stop(&problem));
+    assert_eq!(TerminationCheckpoint::<Problem>::checkpoint(&eb), 8);
+  }
+}
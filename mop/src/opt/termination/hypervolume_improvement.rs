@@ -0,0 +1,118 @@
+use crate::{
+  gp::Gp,
+  opt::{Termination, TerminationCheckpoint},
+  utils::hypervolume,
+  Obj, ObjDirection,
+};
+use alloc::{collections::VecDeque, vec::Vec};
+use cl_aux::SingleTypeStorage;
+use num_traits::NumCast;
+
+/// Stops when the hypervolume of the population improved less than `threshold` in the last
+/// `window` iterations.
+///
+/// `reference` is a point, in the objective space, that is worse than any relevant result.
+#[derive(Clone, Debug)]
+pub struct HypervolumeImprovement {
+  buffer: Vec<f64>,
+  history: VecDeque<f64>,
+  reference: Vec<f64>,
+  threshold: f64,
+  window: usize,
+}
+
+impl HypervolumeImprovement {
+  #[inline]
+  pub fn new(reference: Vec<f64>, threshold: f64, window: usize) -> Self {
+    Self {
+      buffer: Vec::new(),
+      history: VecDeque::with_capacity(window.saturating_add(1)),
+      reference,
+      threshold,
+      window,
+    }
+  }
+
+  /// Hypervolume of the last verified population
+  #[inline]
+  pub fn last_hypervolume(&self) -> Option<f64> {
+    self.history.back().copied()
+  }
+}
+
+impl<D, HCRS, HCS, O, OR, ORS, OS, S, SCRS, SCS, SS>
+  Termination<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>> for HypervolumeImprovement
+where
+  O: Obj<OR, S>,
+  OR: Copy + NumCast,
+  ORS: AsRef<[OR]> + SingleTypeStorage<Item = OR>,
+  OS: AsRef<[O]> + SingleTypeStorage<Item = O>,
+  SS: SingleTypeStorage<Item = S>,
+{
+  #[inline]
+  fn init(&mut self) {
+    self.history.clear();
+  }
+
+  #[inline]
+  fn should_stop(&mut self, p: &Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>) -> bool {
+    let objs = p.defs().objs();
+    let len = self.reference.len();
+    if objs.len() != len || len == 0 {
+      return false;
+    }
+    let sign = |obj: &O| if obj.obj_direction() == ObjDirection::Max { -1.0 } else { 1.0 };
+    self.buffer.clear();
+    for row in p.rslts().obj_rslts.row_iter() {
+      for (obj, obj_rslt) in objs.iter().zip(row) {
+        self.buffer.push(sign(obj) * obj_rslt.to_f64().unwrap_or(f64::INFINITY));
+      }
+    }
+    let reference: Vec<f64> =
+      objs.iter().zip(&self.reference).map(|(obj, r)| sign(obj) * r).collect();
+    let hv = hypervolume(self.buffer.chunks_exact(len), &reference);
+    if self.history.len() > self.window {
+      let _ = self.history.pop_front();
+    }
+    self.history.push_back(hv);
+    match (self.history.front(), self.history.len() > self.window) {
+      (Some(first), true) => hv - first < self.threshold,
+      _ => false,
+    }
+  }
+}
+
+impl<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>
+  TerminationCheckpoint<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>> for HypervolumeImprovement
+where
+  Self: Termination<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
+{
+  type Checkpoint = VecDeque<f64>;
+
+  #[inline]
+  fn checkpoint(&self) -> Self::Checkpoint {
+    self.history.clone()
+  }
+
+  #[inline]
+  fn restore(&mut self, checkpoint: Self::Checkpoint) {
+    self.history = checkpoint;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    opt::{HypervolumeImprovement, Termination},
+    utils::dummy_mp_with_solutions,
+  };
+
+  #[test]
+  fn hypervolume_improvement() {
+    let problem = dummy_mp_with_solutions();
+    let mut hi = HypervolumeImprovement::new(alloc::vec![16.0, 16.0], 0.1, 1);
+    assert!(!hi.should_stop(&problem));
+    assert_eq!(hi.last_hypervolume(), Some(96.0));
+    assert!(hi.should_stop(&problem));
+  }
+}
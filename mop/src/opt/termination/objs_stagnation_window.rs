@@ -0,0 +1,106 @@
+use crate::{
+  gp::Gp,
+  opt::{Termination, TerminationCheckpoint},
+  Obj, ObjDirection,
+};
+use alloc::{collections::VecDeque, vec::Vec};
+use cl_aux::SingleTypeStorage;
+use num_traits::NumCast;
+
+/// Stops when the best result of every objective, considered independently, changed less than
+/// `tolerance` in the last `window` iterations.
+#[derive(Clone, Debug)]
+pub struct ObjsStagnationWindow {
+  history: VecDeque<Vec<f64>>,
+  tolerance: f64,
+  window: usize,
+}
+
+impl ObjsStagnationWindow {
+  #[inline]
+  pub fn new(tolerance: f64, window: usize) -> Self {
+    Self { history: VecDeque::with_capacity(window.saturating_add(1)), tolerance, window }
+  }
+}
+
+impl<D, HCRS, HCS, O, OR, ORS, OS, S, SCRS, SCS, SS>
+  Termination<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>> for ObjsStagnationWindow
+where
+  O: Obj<OR, S>,
+  OR: Copy + NumCast,
+  ORS: AsRef<[OR]> + SingleTypeStorage<Item = OR>,
+  OS: AsRef<[O]> + SingleTypeStorage<Item = O>,
+  SS: SingleTypeStorage<Item = S>,
+{
+  #[inline]
+  fn init(&mut self) {
+    self.history.clear();
+  }
+
+  #[inline]
+  fn should_stop(&mut self, p: &Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>) -> bool {
+    let objs = p.defs().objs();
+    let mut bests = if self.history.len() > self.window {
+      self.history.pop_front().unwrap_or_default()
+    } else {
+      Vec::with_capacity(objs.len())
+    };
+    bests.clear();
+    bests.extend(objs.iter().map(|obj| match obj.obj_direction() {
+      ObjDirection::Max => f64::NEG_INFINITY,
+      ObjDirection::Min => f64::INFINITY,
+    }));
+    for row in p.rslts().obj_rslts.row_iter() {
+      for ((obj, best), obj_rslt) in objs.iter().zip(bests.iter_mut()).zip(row) {
+        let value = if let Some(elem) = obj_rslt.to_f64() { elem } else { continue };
+        *best = match obj.obj_direction() {
+          ObjDirection::Max => best.max(value),
+          ObjDirection::Min => best.min(value),
+        };
+      }
+    }
+    self.history.push_back(bests);
+    match (self.history.front(), self.history.back(), self.history.len() > self.window) {
+      (Some(first), Some(last), true) => first
+        .iter()
+        .zip(last)
+        .all(|(a, b)| a == b || num_traits::Float::abs(a - b) <= self.tolerance),
+      _ => false,
+    }
+  }
+}
+
+impl<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>
+  TerminationCheckpoint<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>> for ObjsStagnationWindow
+where
+  Self: Termination<Gp<D, HCRS, HCS, ORS, OS, SCRS, SCS, SS>>,
+{
+  type Checkpoint = VecDeque<Vec<f64>>;
+
+  #[inline]
+  fn checkpoint(&self) -> Self::Checkpoint {
+    self.history.clone()
+  }
+
+  #[inline]
+  fn restore(&mut self, checkpoint: Self::Checkpoint) {
+    self.history = checkpoint;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    opt::{ObjsStagnationWindow, Termination},
+    utils::dummy_mp_with_solutions,
+  };
+
+  #[test]
+  fn objs_stagnation_window() {
+    let problem = dummy_mp_with_solutions();
+    let mut osw = ObjsStagnationWindow::new(0.0, 2);
+    assert!(!osw.should_stop(&problem));
+    assert!(!osw.should_stop(&problem));
+    assert!(osw.should_stop(&problem));
+  }
+}
@@ -0,0 +1,94 @@
+//! Generation of the solutions of initial or restarted populations
+
+mod halton;
+mod latin_hypercube;
+mod opposition;
+mod sobol;
+mod uniform;
+
+use crate::{Domain, NumVarDomain, NumVarDomains};
+use alloc::vec::Vec;
+pub use halton::*;
+pub use latin_hypercube::*;
+pub use opposition::*;
+use rand::Rng;
+pub use sobol::*;
+pub use uniform::*;
+
+/// Generates a set of solutions of a domain at once, which allows spreading them over the
+/// domain instead of drawing each one independently.
+pub trait Sampler<D, S> {
+  type Error;
+
+  /// `num` solutions of `domain`
+  fn sample<R>(&self, domain: &D, num: usize, rng: &mut R) -> Result<Vec<S>, Self::Error>
+  where
+    R: Rng;
+}
+
+/// Creates `num` random solutions and overwrites the variables that have numeric domains with
+/// the values returned by `unit`, which maps the index of a solution and the index of a
+/// variable to a value of `[0, 1]`. Values are spread over the encoded space of each variable.
+fn sample_unit_cube<D, F, R, S, T>(
+  domain: &D,
+  num: usize,
+  rng: &mut R,
+  mut unit: F,
+) -> crate::Result<Vec<S>>
+where
+  D: Domain<S> + NumVarDomains<T>,
+  D::Error: Into<crate::Error>,
+  F: FnMut(usize, usize) -> crate::Result<f64>,
+  R: Rng,
+  S: AsMut<[T]>,
+{
+  let mut rslt = Vec::with_capacity(num);
+  for s_idx in 0..num {
+    let mut s = domain.new_random_solution(rng).map_err(Into::into)?;
+    for (var_idx, var) in s.as_mut().iter_mut().enumerate() {
+      let Some(var_domain) = domain.var_domain(var_idx) else {
+        continue;
+      };
+      let Some([start, end]) = var_domain.encoded_bounds() else {
+        continue;
+      };
+      if let Some(elem) = var_domain.decode(start + unit(s_idx, var_idx)? * (end - start)) {
+        *var = elem;
+      }
+    }
+    rslt.push(s);
+  }
+  Ok(rslt)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::sampler::{Halton, LatinHypercube, Opposition, Sampler, Sobol};
+  use alloc::vec::Vec;
+  use rand::rngs::OsRng;
+
+  fn strata(solutions: &[[f64; 3]], var_idx: usize) -> Vec<usize> {
+    let mut rslt: Vec<_> =
+      solutions.iter().map(|s| (s[var_idx] * solutions.len() as f64) as usize).collect();
+    rslt.sort_unstable();
+    rslt
+  }
+
+  #[test]
+  fn samplers() {
+    let domain = [0.0..=1.0, 0.0..=1.0, 0.0..=1.0];
+    let lhs: Vec<[f64; 3]> = LatinHypercube.sample(&domain, 10, &mut OsRng).unwrap();
+    let sobol: Vec<[f64; 3]> = Sobol.sample(&domain, 8, &mut OsRng).unwrap();
+    for var_idx in 0..3 {
+      assert_eq!(strata(&lhs, var_idx), (0..10).collect::<Vec<_>>());
+      assert_eq!(strata(&sobol, var_idx), (0..8).collect::<Vec<_>>());
+    }
+    let halton: Vec<[f64; 3]> = Halton.sample(&domain, 20, &mut OsRng).unwrap();
+    assert!(halton.iter().flatten().all(|var| domain[0].contains(var)));
+    let opposition: Vec<[f64; 3]> = Opposition::new(Sobol).sample(&domain, 5, &mut OsRng).unwrap();
+    assert_eq!(opposition.len(), 5);
+    assert!((opposition[0][1] + opposition[1][1] - 1.0).abs() < 1e-12);
+    let big_domain = alloc::vec![0.0..=1.0; 22];
+    assert!(Sampler::<_, Vec<f64>>::sample(&Sobol, &big_domain, 1, &mut OsRng).is_err());
+  }
+}
@@ -0,0 +1,70 @@
+use crate::{
+  sampler::{sample_unit_cube, Sampler},
+  Domain, NumVarDomains, Solution,
+};
+use alloc::vec::Vec;
+use rand::Rng;
+
+/// Halton low-discrepancy sequence.
+///
+/// The numeric variable of index `idx` uses the radical inverse in the base of the `idx`-th prime
+/// number. Each call applies a random shift modulo 1 to every dimension, so different calls
+/// return different populations. Correlations between dimensions grow with the base, which makes
+/// [`Sobol`](crate::sampler::Sobol) preferable for many variables.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Halton;
+
+impl<D, S> Sampler<D, S> for Halton
+where
+  D: Domain<S> + NumVarDomains<S::Var>,
+  D::Error: Into<crate::Error>,
+  S: AsMut<[S::Var]> + Solution,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn sample<R>(&self, domain: &D, num: usize, rng: &mut R) -> crate::Result<Vec<S>>
+  where
+    R: Rng,
+  {
+    let dims = domain.var_domains_num();
+    let bases = primes(dims);
+    let shifts: Vec<f64> = (0..dims).map(|_| rng.gen()).collect();
+    sample_unit_cube(domain, num, rng, |s_idx, var_idx| {
+      let base = crate::Error::opt_rslt(bases.get(var_idx))?;
+      let shift = crate::Error::opt_rslt(shifts.get(var_idx))?;
+      let value = radical_inverse(s_idx.wrapping_add(1), *base)? + shift;
+      Ok(value - num_traits::Float::floor(value))
+    })
+  }
+}
+
+/// The first `num` prime numbers
+fn primes(num: usize) -> Vec<usize> {
+  let mut rslt = Vec::with_capacity(num);
+  let mut candidate: usize = 2;
+  while rslt.len() < num {
+    if rslt
+      .iter()
+      .take_while(|prime| *prime * *prime <= candidate)
+      .all(|prime| !candidate.is_multiple_of(*prime))
+    {
+      rslt.push(candidate);
+    }
+    candidate = candidate.saturating_add(1);
+  }
+  rslt
+}
+
+/// Mirrors the digits of `idx` written in `base` around the decimal point
+fn radical_inverse(mut idx: usize, base: usize) -> crate::Result<f64> {
+  let base_f64: f64 = crate::Error::cast_rslt(base)?;
+  let mut fraction = 1.0;
+  let mut rslt = 0.0;
+  while idx > 0 {
+    fraction /= base_f64;
+    rslt += crate::Error::cast_rslt::<_, f64>(idx % base)? * fraction;
+    idx /= base;
+  }
+  Ok(rslt)
+}
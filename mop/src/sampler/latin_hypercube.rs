@@ -0,0 +1,45 @@
+use crate::{
+  sampler::{sample_unit_cube, Sampler},
+  Domain, NumVarDomains, Solution,
+};
+use alloc::vec::Vec;
+use rand::{seq::SliceRandom, Rng};
+
+/// Latin hypercube sampling.
+///
+/// The encoded space of each numeric variable is split into `num` strata of equal width and
+/// each stratum receives exactly one solution, so every variable is evenly covered even for
+/// small populations.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LatinHypercube;
+
+impl<D, S> Sampler<D, S> for LatinHypercube
+where
+  D: Domain<S> + NumVarDomains<S::Var>,
+  D::Error: Into<crate::Error>,
+  S: AsMut<[S::Var]> + Solution,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn sample<R>(&self, domain: &D, num: usize, rng: &mut R) -> crate::Result<Vec<S>>
+  where
+    R: Rng,
+  {
+    let strata: Vec<Vec<usize>> = (0..domain.var_domains_num())
+      .map(|_| {
+        let mut permutation: Vec<usize> = (0..num).collect();
+        permutation.shuffle(rng);
+        permutation
+      })
+      .collect();
+    let offsets: Vec<f64> = (0..num.saturating_mul(strata.len())).map(|_| rng.gen()).collect();
+    let num_f64: f64 = crate::Error::cast_rslt(num)?;
+    sample_unit_cube(domain, num, rng, |s_idx, var_idx| {
+      let stratum = crate::Error::opt_rslt(strata.get(var_idx).and_then(|elem| elem.get(s_idx)))?;
+      let offset_idx = s_idx.saturating_mul(strata.len()).saturating_add(var_idx);
+      let offset = crate::Error::opt_rslt(offsets.get(offset_idx))?;
+      Ok((crate::Error::cast_rslt::<_, f64>(*stratum)? + offset) / num_f64)
+    })
+  }
+}
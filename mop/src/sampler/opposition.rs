@@ -0,0 +1,64 @@
+use crate::{sampler::Sampler, NumVarDomain, NumVarDomains, Solution};
+use alloc::vec::Vec;
+use rand::Rng;
+
+/// Opposition-based initialization.
+///
+/// Half of the solutions are generated by `SA` and the other half are their opposites, i.e.,
+/// every numeric variable `var` of the encoded space `[start, end]` becomes `start + end - var`.
+/// Opposite points cover the regions that the first half missed.
+///
+/// # Types
+///
+/// * `SA`: Sampler
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Opposition<SA> {
+  sampler: SA,
+}
+
+impl<SA> Opposition<SA> {
+  #[inline]
+  pub fn new(sampler: SA) -> Self {
+    Self { sampler }
+  }
+}
+
+impl<D, S, SA> Sampler<D, S> for Opposition<SA>
+where
+  D: NumVarDomains<S::Var>,
+  S: AsMut<[S::Var]> + Clone + Solution,
+  S::Var: Copy,
+  SA: Sampler<D, S>,
+  SA::Error: Into<crate::Error>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn sample<R>(&self, domain: &D, num: usize, rng: &mut R) -> crate::Result<Vec<S>>
+  where
+    R: Rng,
+  {
+    let mut rslt = Vec::with_capacity(num);
+    for s in self.sampler.sample(domain, num.div_ceil(2), rng).map_err(Into::into)? {
+      let mut opposite = s.clone();
+      for (idx, var) in opposite.as_mut().iter_mut().enumerate() {
+        let Some(var_domain) = domain.var_domain(idx) else {
+          continue;
+        };
+        let (Some([start, end]), Some(value)) =
+          (var_domain.encoded_bounds(), var_domain.encode(*var))
+        else {
+          continue;
+        };
+        if let Some(elem) = var_domain.decode(start + end - value) {
+          *var = elem;
+        }
+      }
+      rslt.push(s);
+      if rslt.len() < num {
+        rslt.push(opposite);
+      }
+    }
+    Ok(rslt)
+  }
+}
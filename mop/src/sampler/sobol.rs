@@ -0,0 +1,115 @@
+use crate::{
+  sampler::{sample_unit_cube, Sampler},
+  Domain, NumVarDomains, Solution,
+};
+use alloc::vec::Vec;
+use rand::Rng;
+
+const BITS: usize = 32;
+// Degree, coefficients and initial direction numbers of the primitive polynomials of the
+// dimensions after the first one. S. Joe and F. Y. Kuo; Constructing Sobol sequences with better
+// two-dimensional projections (new-joe-kuo-6.21201).
+const POLYNOMIALS: [(usize, u32, &[u32]); Sobol::MAX_DIMS - 1] = [
+  (1, 0, &[1]),
+  (2, 1, &[1, 3]),
+  (3, 1, &[1, 3, 1]),
+  (3, 2, &[1, 1, 1]),
+  (4, 1, &[1, 1, 3, 3]),
+  (4, 4, &[1, 3, 5, 13]),
+  (5, 2, &[1, 1, 5, 5, 17]),
+  (5, 4, &[1, 1, 5, 5, 5]),
+  (5, 7, &[1, 1, 7, 11, 19]),
+  (5, 11, &[1, 1, 5, 1, 1]),
+  (5, 13, &[1, 1, 1, 3, 11]),
+  (5, 14, &[1, 3, 5, 5, 31]),
+  (6, 1, &[1, 3, 3, 9, 7, 49]),
+  (6, 13, &[1, 1, 1, 15, 21, 21]),
+  (6, 16, &[1, 3, 1, 13, 27, 49]),
+  (6, 19, &[1, 1, 1, 15, 7, 5]),
+  (6, 22, &[1, 3, 1, 15, 13, 25]),
+  (6, 25, &[1, 1, 5, 5, 19, 61]),
+  (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+  (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+];
+// 2^32
+const SCALE: f64 = 4_294_967_296.0;
+
+/// Sobol low-discrepancy sequence.
+///
+/// Each call applies a random digital shift to every dimension, so different calls return
+/// different populations. Supports up to [`Sobol::MAX_DIMS`] numeric variables, which is enough
+/// for most initial populations; larger domains return an error.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sobol;
+
+impl Sobol {
+  /// Maximum number of numeric variables
+  pub const MAX_DIMS: usize = 21;
+}
+
+impl<D, S> Sampler<D, S> for Sobol
+where
+  D: Domain<S> + NumVarDomains<S::Var>,
+  D::Error: Into<crate::Error>,
+  S: AsMut<[S::Var]> + Solution,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn sample<R>(&self, domain: &D, num: usize, rng: &mut R) -> crate::Result<Vec<S>>
+  where
+    R: Rng,
+  {
+    let dims = domain.var_domains_num();
+    if dims > Self::MAX_DIMS {
+      return Err(crate::Error::Other("Sobol sequences support up to 21 variables"));
+    }
+    let directions: Vec<[u32; BITS]> = (0..dims).map(directions).collect();
+    let mut points: Vec<u32> = (0..dims).map(|_| rng.gen()).collect();
+    let mut values = Vec::with_capacity(num.saturating_mul(dims));
+    for idx in 0..num {
+      values.extend(points.iter().map(|point| f64::from(*point) / SCALE));
+      let bit = crate::Error::cast_rslt::<_, usize>(idx.trailing_ones())?.min(BITS - 1);
+      for (point, direction) in points.iter_mut().zip(&directions) {
+        *point ^= crate::Error::opt_rslt(direction.get(bit))?;
+      }
+    }
+    sample_unit_cube(domain, num, rng, |s_idx, var_idx| {
+      let idx = s_idx.saturating_mul(dims).saturating_add(var_idx);
+      crate::Error::opt_rslt(values.get(idx).copied())
+    })
+  }
+}
+
+/// Direction numbers of the dimension of index `dim`
+fn directions(dim: usize) -> [u32; BITS] {
+  let mut rslt = [0; BITS];
+  let Some((degree, coefficients, initial)) =
+    dim.checked_sub(1).and_then(|idx| POLYNOMIALS.get(idx))
+  else {
+    for (bit, direction) in rslt.iter_mut().enumerate() {
+      *direction = 1 << (BITS - 1).saturating_sub(bit);
+    }
+    return rslt;
+  };
+  for bit in 0..BITS {
+    let value = if let Some(elem) = initial.get(bit) {
+      elem << (BITS - 1).saturating_sub(bit)
+    } else {
+      let prev = |shift: usize| {
+        bit.checked_sub(shift).and_then(|idx| rslt.get(idx)).copied().unwrap_or_default()
+      };
+      let mut direction = prev(*degree) ^ (prev(*degree) >> degree);
+      for shift in 1..*degree {
+        if (coefficients >> degree.saturating_sub(1).saturating_sub(shift)) & 1 == 1 {
+          direction ^= prev(shift);
+        }
+      }
+      direction
+    };
+    if let Some(direction) = rslt.get_mut(bit) {
+      *direction = value;
+    }
+  }
+  rslt
+}
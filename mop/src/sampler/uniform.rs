@@ -0,0 +1,22 @@
+use crate::{sampler::Sampler, Domain};
+use alloc::vec::Vec;
+use rand::Rng;
+
+/// Draws each solution independently through [`Domain::new_random_solution`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Uniform;
+
+impl<D, S> Sampler<D, S> for Uniform
+where
+  D: Domain<S>,
+{
+  type Error = D::Error;
+
+  #[inline]
+  fn sample<R>(&self, domain: &D, num: usize, rng: &mut R) -> Result<Vec<S>, Self::Error>
+  where
+    R: Rng,
+  {
+    (0..num).map(|_| domain.new_random_solution(rng)).collect()
+  }
+}
@@ -1,11 +1,29 @@
+use alloc::{boxed::Box, vec::Vec};
 use core::mem::swap;
 
 /// Solution is a set/tuple of flattened and indexed variables.
+///
+/// # Migration
+///
+/// [`Solution::Var`] is a required associated type, so custom implementations written before
+/// its introduction must now declare the type of their variables, e.g., `type Var = f64;`.
 pub trait Solution {
+  /// Maximum number of variables. `usize::MAX` for dynamic-length storages.
   const MAX_LEN: usize;
 
+  /// Variable type, used by operators that insert, remove or replace variables
+  type Var;
+
   fn has_var(&self, idx: usize) -> bool;
 
+  /// Inserts `var` at `idx`, shifting all subsequent variables. Fixed-length solutions return
+  /// [`crate::Error::FixedLengthSolution`].
+  #[inline]
+  fn insert_var(&mut self, _idx: usize, _var: Self::Var) -> crate::Result<()> {
+    Err(crate::Error::FixedLengthSolution)
+  }
+
+  /// Swaps the variables of index `idx`, which must exist in both solutions.
   fn inter_swap(&mut self, other: &mut Self, idx: usize);
 
   fn intra_swap(&mut self, a: usize, b: usize);
@@ -16,11 +34,20 @@ pub trait Solution {
   }
 
   fn len(&self) -> usize;
+
+  /// Removes the variable of index `idx`, shifting all subsequent variables. Fixed-length
+  /// solutions return [`crate::Error::FixedLengthSolution`].
+  #[inline]
+  fn remove_var(&mut self, _idx: usize) -> crate::Result<Self::Var> {
+    Err(crate::Error::FixedLengthSolution)
+  }
 }
 
 impl<T, const N: usize> Solution for [T; N] {
   const MAX_LEN: usize = N;
 
+  type Var = T;
+
   #[inline]
   fn has_var(&self, idx: usize) -> bool {
     idx < self.len()
@@ -46,6 +73,51 @@ impl<T, const N: usize> Solution for [T; N] {
 impl<T, const N: usize> Solution for arrayvec::ArrayVec<T, N> {
   const MAX_LEN: usize = N;
 
+  type Var = T;
+
+  #[inline]
+  fn has_var(&self, idx: usize) -> bool {
+    idx < self.len()
+  }
+
+  #[inline]
+  fn insert_var(&mut self, idx: usize, var: Self::Var) -> crate::Result<()> {
+    if idx > self.len() {
+      return Err(crate::Error::EmptyElement);
+    }
+    self.try_insert(idx, var).map_err(|_err| crate::Error::InsufficientCapacity)
+  }
+
+  #[inline]
+  fn inter_swap(&mut self, other: &mut Self, idx: usize) {
+    assert!(idx < self.len());
+    swap(&mut self[idx], &mut other[idx]);
+  }
+
+  #[inline]
+  fn intra_swap(&mut self, a: usize, b: usize) {
+    self.swap(a, b);
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.len()
+  }
+
+  #[inline]
+  fn remove_var(&mut self, idx: usize) -> crate::Result<Self::Var> {
+    if idx >= self.len() {
+      return Err(crate::Error::EmptyElement);
+    }
+    Ok(self.remove(idx))
+  }
+}
+
+impl<T> Solution for Box<[T]> {
+  const MAX_LEN: usize = usize::MAX;
+
+  type Var = T;
+
   #[inline]
   fn has_var(&self, idx: usize) -> bool {
     idx < self.len()
@@ -62,10 +134,54 @@ impl<T, const N: usize> Solution for arrayvec::ArrayVec<T, N> {
     self.swap(a, b);
   }
 
+  #[inline]
+  fn len(&self) -> usize {
+    (**self).len()
+  }
+}
+
+impl<T> Solution for Vec<T> {
+  const MAX_LEN: usize = usize::MAX;
+
+  type Var = T;
+
+  #[inline]
+  fn has_var(&self, idx: usize) -> bool {
+    idx < self.len()
+  }
+
+  #[inline]
+  fn insert_var(&mut self, idx: usize, var: Self::Var) -> crate::Result<()> {
+    if idx > self.len() {
+      return Err(crate::Error::EmptyElement);
+    }
+    self.insert(idx, var);
+    Ok(())
+  }
+
+  #[inline]
+  fn inter_swap(&mut self, other: &mut Self, idx: usize) {
+    assert!(idx < self.len());
+    swap(&mut self[idx], &mut other[idx]);
+  }
+
+  #[inline]
+  fn intra_swap(&mut self, a: usize, b: usize) {
+    self.swap(a, b);
+  }
+
   #[inline]
   fn len(&self) -> usize {
     self.len()
   }
+
+  #[inline]
+  fn remove_var(&mut self, idx: usize) -> crate::Result<Self::Var> {
+    if idx >= self.len() {
+      return Err(crate::Error::EmptyElement);
+    }
+    Ok(self.remove(idx))
+  }
 }
 
 #[cfg(feature = "ndstruct")]
@@ -77,6 +193,8 @@ where
 {
   const MAX_LEN: usize = D;
 
+  type Var = DATA;
+
   #[inline]
   fn has_var(&self, idx: usize) -> bool {
     idx < self.len()
@@ -98,3 +216,35 @@ where
     self.data().len()
   }
 }
+
+#[cfg(all(feature = "derive", feature = "rand", test))]
+mod tests {
+  use crate::{Domain, Solution};
+  use rand::rngs::OsRng;
+
+  #[derive(Debug, PartialEq, Solution)]
+  struct Params {
+    #[mop(range = 0.0..=1.0)]
+    momentum: f64,
+    #[mop(range = 1e-4..=1e-1)]
+    rate: f64,
+    #[mop(range = 2.0..=3.0)]
+    width: f64,
+  }
+
+  #[test]
+  fn derived_solution() {
+    let mut a = ParamsDomain.new_random_solution(&mut OsRng).unwrap();
+    assert!((0.0..=1.0).contains(&a.momentum) && (2.0..=3.0).contains(&a.width));
+    let mut b = Params { momentum: 0.5, rate: 0.01, width: 2.5 };
+    let width = a.width;
+    a.inter_swap(&mut b, 2);
+    b.intra_swap(0, 2);
+    assert_eq!((a.width, b.momentum, b.width), (2.5, width, 0.5));
+    assert_eq!((a.len(), a.has_var(3), ParamsDomain.len()), (3, false, 3));
+    a.rate = 0.5;
+    assert_eq!(ParamsDomain.out_of_bounds_vars(&a), Some(alloc::vec![1]));
+    assert_eq!(ParamsDomain.clamp(&mut a), Some(()));
+    assert_eq!(a.rate, 1e-1);
+  }
+}
@@ -16,4 +16,116 @@ pub trait Solver<P> {
   /// Verifies or modifies `P` when solving is starting
   #[inline]
   fn init(&mut self, _: &mut P) {}
+
+  /// Called when the population of `P` was replaced by a restart policy, possibly with a
+  /// different number of results. The next call is `before_iter`.
+  #[inline]
+  fn restarted(&mut self, _: &mut P) {}
+
+  /// Informs the current progress of the optimization. Called before each `after_iter`.
+  #[inline]
+  fn update_progress(&mut self, _: &Progress) {}
+}
+
+impl<P, T> Solver<P> for &mut T
+where
+  T: Solver<P>,
+{
+  type Error = T::Error;
+
+  #[inline]
+  fn after_iter(&mut self, p: &mut P) -> Result<(), Self::Error> {
+    (**self).after_iter(p)
+  }
+
+  #[inline]
+  fn before_iter(&mut self, p: &mut P) -> Result<(), Self::Error> {
+    (**self).before_iter(p)
+  }
+
+  #[inline]
+  fn finished(&mut self, p: &mut P) {
+    (**self).finished(p);
+  }
+
+  #[inline]
+  fn init(&mut self, p: &mut P) {
+    (**self).init(p);
+  }
+
+  #[inline]
+  fn restarted(&mut self, p: &mut P) {
+    (**self).restarted(p);
+  }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    (**self).update_progress(progress);
+  }
+}
+
+/// Solver whose state can be saved between two iterations and later restored to continue the
+/// same optimization, possibly in another process.
+///
+/// Checkpoints are taken after [`OptFacade::solve_problem_with`](crate::opt::OptFacade) returns
+/// and are given back to [`OptFacade::resume_problem_with`](crate::opt::OptFacade).
+pub trait SolverCheckpoint<P>: Solver<P> {
+  /// Snapshot of the state. Serializable with the `serde` feature.
+  type Checkpoint;
+
+  /// Current state of the solver, including the parts of `p` that are managed by the solver.
+  fn checkpoint(&self, p: &P) -> Self::Checkpoint;
+
+  /// Overwrites the state of the solver and the managed parts of `p` with `checkpoint`.
+  fn restore(&mut self, p: &mut P, checkpoint: Self::Checkpoint) -> Result<(), Self::Error>;
+}
+
+/// Progress of an optimization
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Progress {
+  current_iteration: usize,
+  diversity: Option<f64>,
+  max_iterations: usize,
+}
+
+impl Progress {
+  #[inline]
+  pub const fn new(current_iteration: usize, max_iterations: usize) -> Self {
+    Self { current_iteration, diversity: None, max_iterations }
+  }
+
+  #[inline]
+  pub const fn current_iteration(&self) -> usize {
+    self.current_iteration
+  }
+
+  /// Diversity of the current population, from 0 (all results are equal) to 1. Only available
+  /// if informed by the solver.
+  #[inline]
+  pub const fn diversity(&self) -> Option<f64> {
+    self.diversity
+  }
+
+  #[inline]
+  pub const fn max_iterations(&self) -> usize {
+    self.max_iterations
+  }
+
+  /// Fraction of the maximum number of iterations that has already been processed, from 0 to 1.
+  #[inline]
+  pub fn ratio(&self) -> f64 {
+    if self.max_iterations == 0 {
+      1.0
+    } else {
+      (self.current_iteration as f64 / self.max_iterations as f64).min(1.0)
+    }
+  }
+
+  #[inline]
+  #[must_use]
+  pub fn set_diversity(mut self, diversity: Option<f64>) -> Self {
+    self.diversity = diversity;
+    self
+  }
 }
@@ -1,2 +1,5 @@
 pub mod genetic_algorithm;
+mod solver_rng;
 mod utils;
+
+pub use solver_rng::SolverRng;
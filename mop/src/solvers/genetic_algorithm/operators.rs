@@ -1,3 +1,22 @@
+macro_rules! impl_set_schedule {
+  ($ty:ident) => {
+    impl $ty {
+      /// Re-evaluates the probability at each iteration according to `schedule`.
+      #[inline]
+      #[must_use]
+      pub fn set_schedule(
+        mut self,
+        schedule: $crate::solvers::genetic_algorithm::operators::probability_schedule::ProbabilitySchedule,
+      ) -> Self {
+        self.probability.set_schedule(schedule);
+        self
+      }
+    }
+  };
+}
+
+pub mod adaptive_selection;
 pub mod crossover;
 pub mod mating_selection;
 pub mod mutation;
+pub mod probability_schedule;
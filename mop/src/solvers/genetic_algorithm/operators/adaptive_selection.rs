@@ -0,0 +1,272 @@
+//! Adaptive operator selection
+//!
+//! Rules used by composite operators to choose, at each generation, one of several underlying
+//! operators. The credit of an operator is the improvement of the mean fitness of the offspring
+//! it generated over the mean fitness of their parents, both measured right before and right
+//! after the operator.
+
+use crate::solvers::utils::spin_roulette_wheel;
+use alloc::vec::Vec;
+use rand::Rng;
+
+/// How the estimated quality of each operator is turned into a choice.
+#[derive(Clone, Copy, Debug)]
+pub enum AdaptiveStrategy {
+  /// Adaptive Pursuit
+  ///
+  /// The probability of the best operator moves towards `1 - (K - 1) * p_min` with rate `beta`
+  /// while the others move towards `p_min`.
+  AdaptivePursuit {
+    /// Adaptation rate of the quality estimates, from 0 to 1.
+    alpha: f64,
+    /// Learning rate of the probabilities, from 0 to 1.
+    beta: f64,
+    /// Minimum probability of each operator. Clamped to `1 / K`.
+    p_min: f64,
+  },
+  /// Probability Matching
+  ///
+  /// Probabilities are proportional to the estimated quality of each operator.
+  ProbabilityMatching {
+    /// Adaptation rate of the quality estimates, from 0 to 1.
+    alpha: f64,
+    /// Minimum probability of each operator. Clamped to `1 / K`.
+    p_min: f64,
+  },
+  /// Upper Confidence Bound multi-armed bandit
+  ///
+  /// Chooses the operator with the greatest `quality + exploration * sqrt(2 * ln(N) / n)`.
+  Ucb {
+    /// Adaptation rate of the quality estimates, from 0 to 1.
+    alpha: f64,
+    /// Weight of the exploration term.
+    exploration: f64,
+  },
+}
+
+impl AdaptiveStrategy {
+  fn alpha(&self) -> f64 {
+    match *self {
+      Self::AdaptivePursuit { alpha, .. }
+      | Self::ProbabilityMatching { alpha, .. }
+      | Self::Ucb { alpha, .. } => alpha,
+    }
+  }
+}
+
+impl Default for AdaptiveStrategy {
+  #[inline]
+  fn default() -> Self {
+    Self::AdaptivePursuit { alpha: 0.3, beta: 0.3, p_min: 0.05 }
+  }
+}
+
+/// Learned statistics of an adaptive operator, stored by solver checkpoints.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AdaptiveState {
+  before: Vec<f64>,
+  pending: Option<usize>,
+  probabilities: Vec<f64>,
+  qualities: Vec<f64>,
+  uses: Vec<usize>,
+}
+
+/// Book-keeping of an adaptive operator.
+#[derive(Clone, Debug)]
+pub(crate) struct AdaptiveSelector {
+  after: Vec<f64>,
+  before: Vec<f64>,
+  pending: Option<usize>,
+  probabilities: Vec<f64>,
+  qualities: Vec<f64>,
+  strategy: AdaptiveStrategy,
+  uses: Vec<usize>,
+}
+
+impl AdaptiveSelector {
+  pub(crate) fn new(strategy: AdaptiveStrategy) -> Self {
+    Self {
+      after: Vec::new(),
+      before: Vec::new(),
+      pending: None,
+      probabilities: Vec::new(),
+      qualities: Vec::new(),
+      strategy,
+      uses: Vec::new(),
+    }
+  }
+
+  /// Chooses the index of one of `operators_num` operators.
+  pub(crate) fn choose<R>(&mut self, rng: &mut R, operators_num: usize) -> usize
+  where
+    R: Rng,
+  {
+    if self.qualities.len() != operators_num {
+      let probability =
+        crate::Error::cast_rslt::<_, f64>(operators_num).map_or(0.0, |len| 1.0 / len);
+      self.probabilities = (0..operators_num).map(|_| probability).collect();
+      self.qualities = (0..operators_num).map(|_| 0.0).collect();
+      self.uses = (0..operators_num).map(|_| 0).collect();
+    }
+    let idx = if let AdaptiveStrategy::Ucb { exploration, .. } = self.strategy {
+      self.ucb_idx(exploration)
+    } else {
+      spin_roulette_wheel(rng, &self.probabilities, 1.0)
+    };
+    if let Some(elem) = self.uses.get_mut(idx) {
+      *elem = elem.saturating_add(1);
+    }
+    idx
+  }
+
+  /// Discards the parent fitnesses of the last recorded operator, whose offspring won't be
+  /// evaluated.
+  pub(crate) fn clear_pending(&mut self) {
+    self.before.clear();
+    self.pending = None;
+  }
+
+  /// Overwrites the learned statistics with `state`.
+  pub(crate) fn set_state(&mut self, state: AdaptiveState) {
+    let AdaptiveState { before, pending, probabilities, qualities, uses } = state;
+    self.before = before;
+    self.pending = pending;
+    self.probabilities = probabilities;
+    self.qualities = qualities;
+    self.uses = uses;
+  }
+
+  pub(crate) fn probabilities(&self) -> &[f64] {
+    &self.probabilities
+  }
+
+  pub(crate) fn qualities(&self) -> &[f64] {
+    &self.qualities
+  }
+
+  /// Stores the fitnesses, filled by `cb`, of the parents of the offspring that the operator
+  /// `idx` is about to generate.
+  pub(crate) fn record(&mut self, idx: usize, cb: impl FnOnce(&mut Vec<f64>)) {
+    self.before.clear();
+    cb(&mut self.before);
+    self.pending = Some(idx);
+  }
+
+  /// Credits the last recorded operator with the improvement of the offspring fitnesses, filled
+  /// by `cb`, over the recorded parent fitnesses.
+  pub(crate) fn reward(&mut self, cb: impl FnOnce(&mut Vec<f64>)) {
+    let idx = if let Some(elem) = self.pending.take() { elem } else { return };
+    self.after.clear();
+    cb(&mut self.after);
+    let (Some(after), Some(before)) = (mean(&self.after), mean(&self.before)) else {
+      return;
+    };
+    let reward = (after - before).max(0.0);
+    if !reward.is_finite() {
+      return;
+    }
+    let alpha = self.strategy.alpha();
+    if let Some(quality) = self.qualities.get_mut(idx) {
+      *quality += alpha * (reward - *quality);
+    }
+    self.update_probabilities();
+  }
+
+  /// Copy of the learned statistics
+  pub(crate) fn state(&self) -> AdaptiveState {
+    AdaptiveState {
+      before: self.before.clone(),
+      pending: self.pending,
+      probabilities: self.probabilities.clone(),
+      qualities: self.qualities.clone(),
+      uses: self.uses.clone(),
+    }
+  }
+
+  fn ucb_idx(&self, exploration: f64) -> usize {
+    if let Some(idx) = self.uses.iter().position(|&uses| uses == 0) {
+      return idx;
+    }
+    let total = self.uses.iter().fold(0usize, |acc, uses| acc.saturating_add(*uses));
+    let Ok(total) = crate::Error::cast_rslt::<_, f64>(total) else {
+      return 0;
+    };
+    let mut best_idx = 0;
+    let mut best_value = f64::NEG_INFINITY;
+    for (idx, (quality, uses)) in self.qualities.iter().zip(&self.uses).enumerate() {
+      let Ok(uses) = crate::Error::cast_rslt::<_, f64>(*uses) else {
+        continue;
+      };
+      let value =
+        quality + exploration * num_traits::Float::sqrt(2.0 * num_traits::Float::ln(total) / uses);
+      if value > best_value {
+        best_idx = idx;
+        best_value = value;
+      }
+    }
+    best_idx
+  }
+
+  fn update_probabilities(&mut self) {
+    let Ok(len) = crate::Error::cast_rslt::<_, f64>(self.qualities.len()) else {
+      return;
+    };
+    match self.strategy {
+      AdaptiveStrategy::AdaptivePursuit { beta, p_min, .. } => {
+        let p_min = p_min.clamp(0.0, 1.0 / len);
+        let p_max = 1.0 - (len - 1.0) * p_min;
+        let mut best_idx = 0;
+        let mut best_quality = f64::NEG_INFINITY;
+        for (idx, quality) in self.qualities.iter().enumerate() {
+          if *quality > best_quality {
+            best_idx = idx;
+            best_quality = *quality;
+          }
+        }
+        for (idx, probability) in self.probabilities.iter_mut().enumerate() {
+          let target = if idx == best_idx { p_max } else { p_min };
+          *probability += beta * (target - *probability);
+        }
+      }
+      AdaptiveStrategy::ProbabilityMatching { p_min, .. } => {
+        let p_min = p_min.clamp(0.0, 1.0 / len);
+        let total: f64 = self.qualities.iter().sum();
+        for (probability, quality) in self.probabilities.iter_mut().zip(&self.qualities) {
+          *probability =
+            if total > 0.0 { p_min + (1.0 - len * p_min) * quality / total } else { 1.0 / len };
+        }
+      }
+      AdaptiveStrategy::Ucb { .. } => {}
+    }
+  }
+}
+
+/// Arithmetic mean of `values`, if any
+fn mean(values: &[f64]) -> Option<f64> {
+  let len: f64 = crate::Error::cast_rslt(values.len()).ok()?;
+  (len > 0.0).then(|| values.iter().sum::<f64>() / len)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::solvers::genetic_algorithm::operators::adaptive_selection::{
+    AdaptiveSelector, AdaptiveStrategy,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn adaptive_selector() {
+    let strategy = AdaptiveStrategy::AdaptivePursuit { alpha: 1.0, beta: 1.0, p_min: 0.1 };
+    let mut selector = AdaptiveSelector::new(strategy);
+    let _ = selector.choose(&mut OsRng, 2);
+    selector.record(1, |buffer| buffer.extend([1.0, 1.0]));
+    selector.reward(|buffer| buffer.extend([3.0, 1.0]));
+    assert_eq!(selector.qualities(), &[0.0, 1.0]);
+    assert!((selector.probabilities()[1] - 0.9).abs() < 1e-9);
+    selector.record(0, |buffer| buffer.extend([1.0, 1.0]));
+    selector.clear_pending();
+    selector.reward(|buffer| buffer.extend([9.0, 9.0]));
+    assert_eq!(selector.qualities(), &[0.0, 1.0]);
+  }
+}
@@ -1,23 +1,222 @@
+mod adaptive_crossover;
+mod bit_multi_point;
+mod bit_uniform;
+mod blx_alpha;
+#[cfg(feature = "ndstruct")]
+mod csl_order;
+mod cut_and_splice;
 mod multi_point;
+mod single_arithmetic;
+mod uniform;
+mod whole_arithmetic;
 
+use crate::{
+  gp::MpOrs,
+  solvers::{
+    genetic_algorithm::operators::adaptive_selection::AdaptiveState, utils::two_asc_rnd_num,
+  },
+  Pct, Progress,
+};
+pub use adaptive_crossover::AdaptiveCrossover;
+use alloc::vec::{self, Vec};
+pub use bit_multi_point::BitMultiPoint;
+pub use bit_uniform::BitUniform;
+pub use blx_alpha::BlxAlpha;
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+#[cfg(feature = "ndstruct")]
+pub use csl_order::CslOrder;
+pub use cut_and_splice::CutAndSplice;
 pub use multi_point::MultiPoint;
+use rand::Rng;
+pub use single_arithmetic::SingleArithmetic;
+pub use uniform::Uniform;
+pub use whole_arithmetic::WholeArithmetic;
 
 pub trait Crossover<T> {
   type Error;
 
-  fn crossover(
-    &self,
+  fn crossover<R>(
+    &mut self,
+    rng: &mut R,
     source: &mut T,
     destination: &mut T,
     filling_num: usize,
-  ) -> Result<(), Self::Error>;
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng;
+
+  /// Appends the [`AdaptiveState`] of this operator and of its inner operators, if any, to
+  /// `states`. Used by solver checkpoints.
+  #[inline]
+  fn adaptive_states(&self, _: &mut Vec<AdaptiveState>) {}
+
+  /// Called by solvers once the objectives of the offspring generated by the last crossover
+  /// were evaluated.
+  #[inline]
+  fn offspring_evaluated(&mut self, _: &T) {}
+
+  /// Called by solvers when the population was replaced by a restart.
+  #[inline]
+  fn restarted(&mut self) {}
+
+  /// Consumes, in the same order, the states appended by [`Crossover::adaptive_states`].
+  #[inline]
+  fn set_adaptive_states(&mut self, _: &mut vec::IntoIter<AdaptiveState>) {}
+
+  /// Called by solvers before each generation with the current optimization progress.
+  #[inline]
+  fn update_progress(&mut self, _: &Progress) {}
 }
 
 impl<T> Crossover<T> for () {
   type Error = core::convert::Infallible;
 
   #[inline]
-  fn crossover(&self, _: &mut T, _: &mut T, _: usize) -> Result<(), Self::Error> {
+  fn crossover<R>(&mut self, _: &mut R, _: &mut T, _: &mut T, _: usize) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
     Ok(())
   }
 }
+
+/// A fixed set of crossovers that can be individually applied. Implemented for tuples.
+pub trait Crossovers<T> {
+  /// Applies the crossover of index `idx`.
+  fn crossover_nth<R>(
+    &mut self,
+    idx: usize,
+    rng: &mut R,
+    source: &mut T,
+    destination: &mut T,
+    filling_num: usize,
+  ) -> crate::Result<()>
+  where
+    R: Rng;
+
+  /// Forwards [`Crossover::adaptive_states`] to all crossovers.
+  fn adaptive_states(&self, states: &mut Vec<AdaptiveState>);
+
+  /// Forwards [`Crossover::offspring_evaluated`] to all crossovers.
+  fn offspring_evaluated(&mut self, offspring: &T);
+
+  /// Forwards [`Crossover::restarted`] to all crossovers.
+  fn restarted(&mut self);
+
+  /// Forwards [`Crossover::set_adaptive_states`] to all crossovers.
+  fn set_adaptive_states(&mut self, states: &mut vec::IntoIter<AdaptiveState>);
+
+  /// Forwards [`Crossover::update_progress`] to all crossovers.
+  fn update_progress(&mut self, progress: &Progress);
+
+  /// Number of crossovers
+  fn operators_num(&self) -> usize;
+}
+
+macro_rules! impl_crossovers {
+  ($($ty:ident $idx:tt),+) => {
+    impl<T, $($ty),+> Crossovers<T> for ($($ty,)+)
+    where
+      $($ty: Crossover<T>, crate::Error: From<$ty::Error>,)+
+    {
+      #[inline]
+      fn crossover_nth<R>(
+        &mut self,
+        idx: usize,
+        rng: &mut R,
+        source: &mut T,
+        destination: &mut T,
+        filling_num: usize,
+      ) -> crate::Result<()>
+      where
+        R: Rng,
+      {
+        match idx {
+          $($idx => Ok(self.$idx.crossover(rng, source, destination, filling_num)?),)+
+          _ => Err(crate::Error::EmptyElement),
+        }
+      }
+
+      #[inline]
+      fn adaptive_states(&self, states: &mut Vec<AdaptiveState>) {
+        $(self.$idx.adaptive_states(states);)+
+      }
+
+      #[inline]
+      fn offspring_evaluated(&mut self, offspring: &T) {
+        $(self.$idx.offspring_evaluated(offspring);)+
+      }
+
+      #[inline]
+      fn restarted(&mut self) {
+        $(self.$idx.restarted();)+
+      }
+
+      #[inline]
+      fn set_adaptive_states(&mut self, states: &mut vec::IntoIter<AdaptiveState>) {
+        $(self.$idx.set_adaptive_states(states);)+
+      }
+
+      #[inline]
+      fn update_progress(&mut self, progress: &Progress) {
+        $(self.$idx.update_progress(progress);)+
+      }
+
+      #[inline]
+      fn operators_num(&self) -> usize {
+        [$($idx),+].len()
+      }
+    }
+  };
+}
+
+impl_crossovers!(A 0, B 1);
+impl_crossovers!(A 0, B 1, C 2);
+impl_crossovers!(A 0, B 1, C 2, D 3);
+impl_crossovers!(A 0, B 1, C 2, D 3, E 4);
+
+/// Fills `destination` with copies of random pairs of `source` and, according to `probability`,
+/// applies `cb` to the solutions of each copied pair.
+pub(crate) fn mate_pairs<F, OR, ORS, R, S, SS>(
+  rng: &mut R,
+  source: &mut MpOrs<ORS, SS>,
+  destination: &mut MpOrs<ORS, SS>,
+  filling_num: usize,
+  probability: Pct,
+  mut cb: F,
+) -> crate::Result<()>
+where
+  F: FnMut(&mut R, &mut S, &mut S),
+  OR: Copy,
+  ORS: AsRef<[OR]>
+    + AsMut<[OR]>
+    + Clear
+    + Extend<OR>
+    + SingleTypeStorage<Item = OR>
+    + Truncate<Input = usize>,
+  R: Rng,
+  S: Clone,
+  SS: AsRef<[S]>
+    + AsMut<[S]>
+    + Clear
+    + Push<S>
+    + SingleTypeStorage<Item = S>
+    + Truncate<Input = usize>,
+{
+  destination.clear();
+  while destination.rslts_num() < filling_num {
+    let [a, b] = two_asc_rnd_num(rng, 0..source.rslts_num());
+    let first = crate::Error::opt_rslt(source.get(a))?;
+    let second = crate::Error::opt_rslt(source.get(b))?;
+    let _ = destination.constructor().or_ref(&first);
+    let _ = destination.constructor().or_ref(&second);
+    if probability.is_in_rnd_pbty(rng) {
+      let a = destination.rslts_num() - 2;
+      let b = destination.rslts_num() - 1;
+      let [mut first, mut second] = crate::Error::opt_rslt(destination.get_two_mut(a, b))?;
+      cb(rng, *first.solution_mut(), *second.solution_mut());
+    }
+  }
+  destination.truncate(filling_num);
+  Ok(())
+}
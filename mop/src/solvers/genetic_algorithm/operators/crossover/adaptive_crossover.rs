@@ -0,0 +1,138 @@
+use crate::{
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::{
+    adaptive_selection::{AdaptiveSelector, AdaptiveState, AdaptiveStrategy},
+    crossover::{Crossover, Crossovers},
+    mating_selection::FitnessExtractor,
+  },
+  Progress,
+};
+use alloc::vec::{self, Vec};
+use rand::Rng;
+
+/// Applies, at each generation, one of the crossovers of the `operators` tuple.
+///
+/// Each crossover is credited with the improvement of the `fitness` of its evaluated offspring
+/// over the fitness of the mating pool and `strategy` turns these credits into a choice.
+#[derive(Clone, Debug)]
+pub struct AdaptiveCrossover<FE, OPS> {
+  fitness: FE,
+  operators: OPS,
+  selector: AdaptiveSelector,
+}
+
+impl<FE, OPS> AdaptiveCrossover<FE, OPS> {
+  #[inline]
+  pub fn new(fitness: FE, operators: OPS, strategy: AdaptiveStrategy) -> Self {
+    AdaptiveCrossover { fitness, operators, selector: AdaptiveSelector::new(strategy) }
+  }
+
+  /// Current selection probabilities of each crossover.
+  #[inline]
+  pub fn probabilities(&self) -> &[f64] {
+    self.selector.probabilities()
+  }
+
+  /// Current estimated quality of each crossover.
+  #[inline]
+  pub fn qualities(&self) -> &[f64] {
+    self.selector.qualities()
+  }
+}
+
+impl<FE, OPS, ORS, SS> Crossover<MpOrs<ORS, SS>> for AdaptiveCrossover<FE, OPS>
+where
+  FE: FitnessExtractor<(), MpOrs<ORS, SS>>,
+  OPS: Crossovers<MpOrs<ORS, SS>>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn adaptive_states(&self, states: &mut Vec<AdaptiveState>) {
+    states.push(self.selector.state());
+    self.operators.adaptive_states(states);
+  }
+
+  #[inline]
+  fn crossover<R>(
+    &mut self,
+    rng: &mut R,
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    let idx = self.selector.choose(rng, self.operators.operators_num());
+    let fitness = &self.fitness;
+    self.selector.record(idx, |buffer| fitness.fitnesses(&(), source, buffer));
+    self.operators.crossover_nth(idx, rng, source, destination, filling_num)
+  }
+
+  #[inline]
+  fn offspring_evaluated(&mut self, offspring: &MpOrs<ORS, SS>) {
+    self.operators.offspring_evaluated(offspring);
+    let fitness = &self.fitness;
+    self.selector.reward(|buffer| fitness.fitnesses(&(), offspring, buffer));
+  }
+
+  #[inline]
+  fn restarted(&mut self) {
+    self.operators.restarted();
+    self.selector.clear_pending();
+  }
+
+  #[inline]
+  fn set_adaptive_states(&mut self, states: &mut vec::IntoIter<AdaptiveState>) {
+    if let Some(state) = states.next() {
+      self.selector.set_state(state);
+    }
+    self.operators.set_adaptive_states(states);
+  }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.operators.update_progress(progress);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    gp::MpOrRef,
+    solvers::genetic_algorithm::operators::{
+      adaptive_selection::AdaptiveStrategy,
+      crossover::{AdaptiveCrossover, Crossover, MultiPoint, Uniform},
+    },
+    utils::dummy_mp,
+    Pct,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn adaptive_crossover() {
+    let mut problem = dummy_mp();
+    let mut destination = problem.rslts_mut().clone();
+    let source = problem.rslts_mut();
+    let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [2.0, 3.0]);
+    let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [1.0, 2.0]);
+    let mut ac = AdaptiveCrossover::new(
+      |rslt: &MpOrRef<'_, f64, [f64; 2]>| -rslt.obj_rslts().iter().sum::<f64>(),
+      (
+        MultiPoint::new(1, Pct::from_percent(100)),
+        Uniform::new(Pct::from_percent(100), Pct::from_percent(100)),
+      ),
+      AdaptiveStrategy::ProbabilityMatching { alpha: 1.0, p_min: 0.1 },
+    );
+    ac.crossover(&mut OsRng, source, &mut destination, 2).unwrap();
+    let mut evaluated = destination.clone();
+    evaluated.clear();
+    for rslt in destination.iter() {
+      let _ = evaluated.constructor().or_os_iter([0.0, 0.0].iter().cloned(), **rslt.solution());
+    }
+    ac.offspring_evaluated(&evaluated);
+    assert!(ac.qualities().contains(&12.0));
+    assert!(ac.probabilities().iter().any(|probability| (probability - 0.9).abs() < 1e-9));
+  }
+}
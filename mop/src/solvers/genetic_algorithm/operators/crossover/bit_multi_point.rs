@@ -0,0 +1,101 @@
+use crate::{
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::crossover::{mate_pairs, Crossover},
+  BitString, Pct,
+};
+use alloc::vec::Vec;
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+use rand::Rng;
+
+/// Multi-point crossover of [`BitString`]s. Up to `points` random cut points split the strings
+/// into segments and every other segment is swapped, a packed word at a time.
+#[derive(Clone, Debug)]
+pub struct BitMultiPoint {
+  cuts: Vec<usize>,
+  points: usize,
+  probability: Pct,
+}
+
+impl BitMultiPoint {
+  #[inline]
+  pub fn new(points: usize, probability: Pct) -> Self {
+    BitMultiPoint { cuts: Vec::with_capacity(points), points, probability }
+  }
+}
+
+impl<OR, ORS, SS> Crossover<MpOrs<ORS, SS>> for BitMultiPoint
+where
+  OR: Copy,
+  ORS: AsRef<[OR]>
+    + AsMut<[OR]>
+    + Clear
+    + Extend<OR>
+    + SingleTypeStorage<Item = OR>
+    + Truncate<Input = usize>,
+  SS: AsRef<[BitString]>
+    + AsMut<[BitString]>
+    + Clear
+    + Push<BitString>
+    + SingleTypeStorage<Item = BitString>
+    + Truncate<Input = usize>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn crossover<R>(
+    &mut self,
+    rng: &mut R,
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    let Self { cuts, points, probability } = self;
+    mate_pairs(rng, source, destination, filling_num, *probability, |rng, first, second| {
+      let len = first.len().min(second.len());
+      if len < 2 {
+        return;
+      }
+      cuts.clear();
+      cuts.extend((0..*points).map(|_| rng.gen_range(1..len)));
+      cuts.sort_unstable();
+      cuts.dedup();
+      cuts.push(len);
+      for segment in cuts.chunks_exact(2) {
+        if let [start, end] = *segment {
+          first.swap_masked(second, start..end, || u64::MAX);
+        }
+      }
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::crossover::{BitMultiPoint, Crossover},
+    utils::dummy_mp_with_domain,
+    BitString, BitStringDomain, ObjDirection, Pct,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn bit_multi_point() {
+    let obj: fn(&BitString) -> usize = BitString::count_ones;
+    let mut problem = dummy_mp_with_domain(BitStringDomain::new(100), (ObjDirection::Max, obj), 2);
+    let mut destination = problem.rslts_mut().clone();
+    let source = problem.rslts_mut();
+    let _ = source.constructor().or_os_iter([0].into_iter(), BitString::new(100));
+    let _ = source.constructor().or_os_iter([100].into_iter(), (0..100).map(|_| true).collect());
+    BitMultiPoint::new(1, Pct::from_percent(100))
+      .crossover(&mut OsRng, source, &mut destination, 2)
+      .unwrap();
+    let first = (*destination.get(0).unwrap().solution()).clone();
+    let ones = first.iter_ones().collect::<alloc::vec::Vec<_>>();
+    assert_eq!(ones.len() + destination.get(1).unwrap().solution().count_ones(), 100);
+    assert!(ones.first().is_some_and(|first_one| *first_one > 0));
+    assert!(ones.windows(2).all(|window| window[1] == window[0] + 1));
+  }
+}
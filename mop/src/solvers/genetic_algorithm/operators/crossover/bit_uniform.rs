@@ -0,0 +1,83 @@
+use crate::{
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::crossover::{mate_pairs, Crossover},
+  BitString, Pct,
+};
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+use rand::Rng;
+
+/// Uniform crossover of [`BitString`]s. Each bit is swapped with a 50% chance using random
+/// masks over the packed words.
+#[derive(Clone, Debug)]
+pub struct BitUniform {
+  probability: Pct,
+}
+
+impl BitUniform {
+  #[inline]
+  pub fn new(probability: Pct) -> Self {
+    BitUniform { probability }
+  }
+}
+
+impl<OR, ORS, SS> Crossover<MpOrs<ORS, SS>> for BitUniform
+where
+  OR: Copy,
+  ORS: AsRef<[OR]>
+    + AsMut<[OR]>
+    + Clear
+    + Extend<OR>
+    + SingleTypeStorage<Item = OR>
+    + Truncate<Input = usize>,
+  SS: AsRef<[BitString]>
+    + AsMut<[BitString]>
+    + Clear
+    + Push<BitString>
+    + SingleTypeStorage<Item = BitString>
+    + Truncate<Input = usize>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn crossover<R>(
+    &mut self,
+    rng: &mut R,
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    mate_pairs(rng, source, destination, filling_num, self.probability, |rng, first, second| {
+      first.swap_masked(second, 0..usize::MAX, || rng.gen());
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::crossover::{BitUniform, Crossover},
+    utils::dummy_mp_with_domain,
+    BitString, BitStringDomain, ObjDirection, Pct,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn bit_uniform() {
+    let obj: fn(&BitString) -> usize = BitString::count_ones;
+    let mut problem = dummy_mp_with_domain(BitStringDomain::new(100), (ObjDirection::Max, obj), 2);
+    let mut destination = problem.rslts_mut().clone();
+    let source = problem.rslts_mut();
+    let _ = source.constructor().or_os_iter([0].into_iter(), BitString::new(100));
+    let _ = source.constructor().or_os_iter([100].into_iter(), (0..100).map(|_| true).collect());
+    BitUniform::new(Pct::from_percent(100))
+      .crossover(&mut OsRng, source, &mut destination, 2)
+      .unwrap();
+    let first = (*destination.get(0).unwrap().solution()).clone();
+    let second = (*destination.get(1).unwrap().solution()).clone();
+    assert_eq!(first.count_ones() + second.count_ones(), 100);
+    assert!(first.iter().zip(second.iter()).all(|(a, b)| a != b));
+  }
+}
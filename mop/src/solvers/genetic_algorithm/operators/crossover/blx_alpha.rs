@@ -0,0 +1,116 @@
+use crate::{
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::crossover::{mate_pairs, Crossover},
+  NumVarDomain, NumVarDomains, Pct, Solution,
+};
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+use rand::Rng;
+
+/// Eshelman and Schaffer; Real-Coded Genetic Algorithms and Interval-Schemata
+///
+/// Each variable of a child is uniformly sampled from the interval spanned by the parents,
+/// extended on both sides by `alpha` times its length. Intervals are computed in the encoded
+/// space of each variable domain and results are clamped to `domain`.
+#[derive(Clone, Debug)]
+pub struct BlxAlpha<D> {
+  alpha: f64,
+  domain: D,
+  probability: Pct,
+}
+
+impl<D> BlxAlpha<D> {
+  #[inline]
+  pub fn new(alpha: f64, domain: D, probability: Pct) -> Self {
+    assert!(alpha >= 0.0);
+    BlxAlpha { alpha, domain, probability }
+  }
+
+  fn sample<R, T, V>(&self, rng: &mut R, a: T, b: T, domain: &V) -> Option<T>
+  where
+    R: Rng,
+    V: NumVarDomain<T>,
+  {
+    let (a, b) = (domain.encode(a)?, domain.encode(b)?);
+    let (min, max) = if a < b { (a, b) } else { (b, a) };
+    let extension = self.alpha * (max - min);
+    let [start, end] = domain.encoded_bounds()?;
+    let lower = (min - extension).max(start);
+    let upper = (max + extension).min(end);
+    let value = if lower < upper { rng.gen_range(lower..=upper) } else { lower };
+    domain.decode(value)
+  }
+}
+
+impl<D, OR, ORS, S, SS, T> Crossover<MpOrs<ORS, SS>> for BlxAlpha<D>
+where
+  D: NumVarDomains<T>,
+  OR: Copy,
+  ORS: AsRef<[OR]>
+    + AsMut<[OR]>
+    + Clear
+    + Extend<OR>
+    + SingleTypeStorage<Item = OR>
+    + Truncate<Input = usize>,
+  S: AsMut<[T]> + Clone + Solution<Var = T>,
+  SS: AsRef<[S]>
+    + AsMut<[S]>
+    + Clear
+    + Push<S>
+    + SingleTypeStorage<Item = S>
+    + Truncate<Input = usize>,
+  T: Copy,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn crossover<R>(
+    &mut self,
+    rng: &mut R,
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    mate_pairs(rng, source, destination, filling_num, self.probability, |rng, first, second| {
+      let iter = first.as_mut().iter_mut().zip(second.as_mut().iter_mut());
+      for (var_idx, (a, b)) in iter.enumerate() {
+        let Some(domain) = self.domain.var_domain(var_idx) else {
+          break;
+        };
+        let new_a = self.sample(rng, *a, *b, domain);
+        let new_b = self.sample(rng, *a, *b, domain);
+        if let (Some(new_a), Some(new_b)) = (new_a, new_b) {
+          *a = new_a;
+          *b = new_b;
+        }
+      }
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::crossover::{BlxAlpha, Crossover},
+    utils::dummy_mp,
+    Pct,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn blx_alpha() {
+    let mut problem = dummy_mp();
+    let mut destination = problem.rslts_mut().clone();
+    let source = problem.rslts_mut();
+    let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [2.0, 3.0]);
+    let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [1.0, 4.0]);
+    let mut blx = BlxAlpha::new(0.5, [0.0..=2.0, 3.5..=10.0], Pct::from_percent(100));
+    blx.crossover(&mut OsRng, source, &mut destination, 2).unwrap();
+    for rslt in destination.iter() {
+      assert!((0.5..=2.0).contains(&rslt.solution()[0]));
+      assert!((3.5..=4.5).contains(&rslt.solution()[1]));
+    }
+  }
+}
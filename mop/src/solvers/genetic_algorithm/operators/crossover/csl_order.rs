@@ -0,0 +1,156 @@
+use crate::{
+  gp::MpOrs,
+  solvers::{
+    genetic_algorithm::operators::{
+      crossover::{mate_pairs, Crossover},
+      probability_schedule::ScheduledPct,
+    },
+    utils::two_asc_rnd_num,
+  },
+  Pct, Progress,
+};
+use alloc::vec::Vec;
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+use ndstruct::csl::Csl;
+use rand::Rng;
+
+/// Order crossover for [`Csl`] solutions whose values are permutations of each other, e.g.,
+/// customers distributed over routes.
+///
+/// Each offspring keeps its own sparsity pattern as well as a random contiguous segment of its
+/// values. The remaining positions receive the missing values in the order they appear in the
+/// other parent, so no value is duplicated or lost.
+#[derive(Clone, Debug)]
+pub struct CslOrder {
+  probability: ScheduledPct,
+}
+
+impl CslOrder {
+  #[inline]
+  pub fn new(probability: Pct) -> Self {
+    CslOrder { probability: ScheduledPct::new(probability) }
+  }
+
+  /// Overwrites the values of `own` that are outside of a random segment with the values of
+  /// `other` that aren't inside the segment.
+  fn fill<DATA, R>(rng: &mut R, own: &mut [DATA], other: &[DATA])
+  where
+    DATA: Clone + PartialEq,
+    R: Rng,
+  {
+    if own.len() < 2 {
+      return;
+    }
+    let [start, end] = two_asc_rnd_num(rng, 0..own.len() + 1);
+    let mut kept: Vec<&DATA> = own[start..end].iter().collect();
+    let mut missing = Vec::with_capacity(own.len() - kept.len());
+    for value in other {
+      if let Some(idx) = kept.iter().position(|elem| *elem == value) {
+        let _ = kept.swap_remove(idx);
+      } else {
+        missing.push(value.clone());
+      }
+    }
+    let (before, after) = own.split_at_mut(start);
+    let outer = before.iter_mut().chain(after[end - start..].iter_mut());
+    for (elem, value) in outer.zip(missing) {
+      *elem = value;
+    }
+  }
+}
+
+impl_set_schedule!(CslOrder);
+
+impl<DATA, DS, IS, OR, ORS, OS, SS, const D: usize> Crossover<MpOrs<ORS, SS>> for CslOrder
+where
+  Csl<DS, IS, OS, D>: Clone,
+  DATA: Clone + PartialEq,
+  DS: AsMut<[DATA]> + AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OR: Copy,
+  ORS: AsRef<[OR]>
+    + AsMut<[OR]>
+    + Clear
+    + Extend<OR>
+    + SingleTypeStorage<Item = OR>
+    + Truncate<Input = usize>,
+  OS: AsRef<[usize]>,
+  SS: AsRef<[Csl<DS, IS, OS, D>]>
+    + AsMut<[Csl<DS, IS, OS, D>]>
+    + Clear
+    + Push<Csl<DS, IS, OS, D>>
+    + SingleTypeStorage<Item = Csl<DS, IS, OS, D>>
+    + Truncate<Input = usize>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn crossover<R>(
+    &mut self,
+    rng: &mut R,
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    mate_pairs(
+      rng,
+      source,
+      destination,
+      filling_num,
+      self.probability.pct(),
+      |rng, first, second| {
+        let first_data = first.data().to_vec();
+        Self::fill(rng, first.data_mut(), second.data());
+        Self::fill(rng, second.data_mut(), &first_data);
+      },
+    )
+  }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.probability.update(progress);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::crossover::{Crossover, CslOrder},
+    utils::dummy_mp_with_domain,
+    ObjDirection, Pct,
+  };
+  use alloc::{vec, vec::Vec};
+  use ndstruct::csl::Csl;
+  use rand::rngs::OsRng;
+
+  type CslVec = Csl<Vec<usize>, Vec<usize>, Vec<usize>, 2>;
+
+  #[test]
+  fn csl_order() {
+    let obj: fn(&CslVec) -> usize = CslVec::nnz;
+    let mut problem =
+      dummy_mp_with_domain([0..=4, 0..=4, 0..=4, 0..=4, 0..=4], (ObjDirection::Min, obj), 2);
+    let mut destination = problem.rslts_mut().clone();
+    let source = problem.rslts_mut();
+    let first = CslVec::new([2, 5], vec![0, 1, 2, 3, 4], vec![0, 1, 2, 0, 1], vec![0, 3, 5]);
+    let second = CslVec::new([2, 5], vec![4, 3, 2, 1, 0], vec![0, 1, 0, 1, 2], vec![0, 2, 5]);
+    let _ = source.constructor().or_os_iter([5].into_iter(), first.unwrap());
+    let _ = source.constructor().or_os_iter([5].into_iter(), second.unwrap());
+    CslOrder::new(Pct::from_percent(100))
+      .crossover(&mut OsRng, source, &mut destination, 2)
+      .unwrap();
+    let mut offs = Vec::new();
+    for idx in 0..2 {
+      let solution = (*destination.get(idx).unwrap().solution()).clone();
+      let mut data = solution.data().to_vec();
+      data.sort_unstable();
+      assert_eq!(data, [0, 1, 2, 3, 4]);
+      offs.push(solution.offs().to_vec());
+    }
+    offs.sort_unstable();
+    assert_eq!(offs, [vec![0, 2, 5], vec![0, 3, 5]]);
+  }
+}
@@ -0,0 +1,171 @@
+use crate::{
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::crossover::{mate_pairs, Crossover},
+  Pct, Solution,
+};
+use alloc::vec::Vec;
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+use core::ops::RangeInclusive;
+use rand::Rng;
+
+/// Variable-length crossover. Each parent is cut at an independent random point and the tails
+/// are exchanged, so offspring lengths can differ from the lengths of their parents.
+///
+/// Cut points are chosen in a way that both offspring lengths stay within `len`, which should
+/// match the minimum and maximum lengths of the domain, and within `Solution::MAX_LEN`.
+#[derive(Clone, Debug)]
+pub struct CutAndSplice {
+  len: RangeInclusive<usize>,
+  probability: Pct,
+}
+
+impl CutAndSplice {
+  #[inline]
+  pub fn new(len: RangeInclusive<usize>, probability: Pct) -> Self {
+    CutAndSplice { len, probability }
+  }
+
+  fn cut_and_splice<S>(
+    buffer: &mut Vec<S::Var>,
+    first: &mut S,
+    second: &mut S,
+    [first_cut, second_cut]: [usize; 2],
+  ) -> crate::Result<()>
+  where
+    S: Solution,
+  {
+    if first_cut == second_cut && first.len() == second.len() {
+      for idx in first_cut..first.len() {
+        first.inter_swap(second, idx);
+      }
+      return Ok(());
+    }
+    buffer.clear();
+    while first.len() > first_cut {
+      buffer.push(first.remove_var(first.len().saturating_sub(1))?);
+    }
+    let first_tail_len = buffer.len();
+    while second.len() > second_cut {
+      buffer.push(second.remove_var(second.len().saturating_sub(1))?);
+    }
+    while buffer.len() > first_tail_len {
+      let var = crate::Error::opt_rslt(buffer.pop())?;
+      first.insert_var(first.len(), var)?;
+    }
+    while let Some(var) = buffer.pop() {
+      second.insert_var(second.len(), var)?;
+    }
+    Ok(())
+  }
+
+  /// Cut point of the second parent that keeps both offspring within the length bounds.
+  fn second_cut<R>(
+    &self,
+    rng: &mut R,
+    max_len: usize,
+    lens: [usize; 2],
+    first_cut: usize,
+  ) -> Option<usize>
+  where
+    R: Rng,
+  {
+    let cast = |elem: usize| crate::Error::cast_rslt::<_, i128>(elem).ok();
+    let [first_len, second_len] = lens;
+    let (first_len, second_len) = (cast(first_len)?, cast(second_len)?);
+    let (first_cut, max_len, min_len) =
+      (cast(first_cut)?, cast(max_len)?, cast(*self.len.start())?);
+    let lower = second_len
+      .saturating_sub(max_len.saturating_sub(first_cut))
+      .max(min_len.saturating_sub(first_len).saturating_add(first_cut))
+      .max(0);
+    let upper = second_len
+      .saturating_sub(min_len.saturating_sub(first_cut))
+      .min(max_len.saturating_sub(first_len).saturating_add(first_cut))
+      .min(second_len);
+    if lower > upper {
+      return None;
+    }
+    usize::try_from(rng.gen_range(lower..=upper)).ok()
+  }
+}
+
+impl<OR, ORS, S, SS> Crossover<MpOrs<ORS, SS>> for CutAndSplice
+where
+  OR: Copy,
+  ORS: AsRef<[OR]>
+    + AsMut<[OR]>
+    + Clear
+    + Extend<OR>
+    + SingleTypeStorage<Item = OR>
+    + Truncate<Input = usize>,
+  S: Clone + Solution,
+  SS: AsRef<[S]>
+    + AsMut<[S]>
+    + Clear
+    + Push<S>
+    + SingleTypeStorage<Item = S>
+    + Truncate<Input = usize>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn crossover<R>(
+    &mut self,
+    rng: &mut R,
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    let max_len = (*self.len.end()).min(S::MAX_LEN);
+    let mut buffer = Vec::new();
+    let mut rslt = Ok(());
+    mate_pairs(rng, source, destination, filling_num, self.probability, |rng, first, second| {
+      if rslt.is_err() {
+        return;
+      }
+      let lens = [first.len(), second.len()];
+      let first_cut = rng.gen_range(0..=lens[0]);
+      if let Some(second_cut) = self.second_cut(rng, max_len, lens, first_cut) {
+        rslt = Self::cut_and_splice(&mut buffer, first, second, [first_cut, second_cut]);
+      }
+    })?;
+    rslt
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::crossover::{Crossover, CutAndSplice},
+    utils::dummy_mp_with_domain,
+    ObjDirection, Pct, VariableLengthDomain,
+  };
+  use alloc::{vec, vec::Vec};
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn cut_and_splice() {
+    let obj: fn(&Vec<u8>) -> usize = Vec::len;
+    let mut problem = dummy_mp_with_domain(
+      VariableLengthDomain::new(2..=6, 0..=1).unwrap(),
+      (ObjDirection::Min, obj),
+      2,
+    );
+    let mut destination = problem.rslts_mut().clone();
+    let source = problem.rslts_mut();
+    let _ = source.constructor().or_os_iter([2].into_iter(), vec![0, 0]);
+    let _ = source.constructor().or_os_iter([5].into_iter(), vec![1; 5]);
+    let mut cas = CutAndSplice::new(2..=6, Pct::from_percent(100));
+    for _ in 0..10 {
+      cas.crossover(&mut OsRng, source, &mut destination, 2).unwrap();
+      let first = (*destination.get(0).unwrap().solution()).clone();
+      let second = (*destination.get(1).unwrap().solution()).clone();
+      assert_eq!(first.len() + second.len(), 7);
+      assert!((2..=6).contains(&first.len()) && (2..=6).contains(&second.len()));
+      assert_eq!(first.iter().chain(&second).filter(|var| **var == 0).count(), 2);
+    }
+  }
+}
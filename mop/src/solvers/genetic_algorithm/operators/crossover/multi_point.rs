@@ -1,22 +1,25 @@
 use crate::{
   gp::MpOrs,
-  solvers::{genetic_algorithm::operators::crossover::Crossover, utils::two_asc_rnd_num},
-  Pct, Solution,
+  solvers::genetic_algorithm::operators::{
+    crossover::{mate_pairs, Crossover},
+    probability_schedule::ScheduledPct,
+  },
+  Pct, Progress, Solution,
 };
 use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
 use core::ops::Div;
-use rand::rngs::OsRng;
+use rand::Rng;
 
 #[derive(Clone, Debug)]
 pub struct MultiPoint {
   divisor: usize,
-  probability: Pct,
+  probability: ScheduledPct,
 }
 
 impl MultiPoint {
   #[inline]
   pub fn new(points: usize, probability: Pct) -> Self {
-    MultiPoint { divisor: points.saturating_add(1), probability }
+    MultiPoint { divisor: points.saturating_add(1), probability: ScheduledPct::new(probability) }
   }
 
   /// Swap data of individuals
@@ -24,7 +27,7 @@ impl MultiPoint {
   where
     S: Solution,
   {
-    let len = first.len();
+    let len = first.len().min(second.len());
     let chunk_len = len.div(self.divisor);
     for var_start in (0..self.divisor).map(|i| i * chunk_len).step_by(2) {
       for var_idx in var_start..var_start + chunk_len {
@@ -34,6 +37,8 @@ impl MultiPoint {
   }
 }
 
+impl_set_schedule!(MultiPoint);
+
 impl<OR, ORS, S, SS> Crossover<MpOrs<ORS, SS>> for MultiPoint
 where
   OR: Copy,
@@ -54,29 +59,24 @@ where
   type Error = crate::Error;
 
   #[inline]
-  fn crossover(
-    &self,
+  fn crossover<R>(
+    &mut self,
+    rng: &mut R,
     source: &mut MpOrs<ORS, SS>,
     destination: &mut MpOrs<ORS, SS>,
     filling_num: usize,
-  ) -> Result<(), Self::Error> {
-    destination.clear();
-    let mut rng = OsRng;
-    while destination.rslts_num() < filling_num {
-      let [a, b] = two_asc_rnd_num(&mut rng, 0..source.rslts_num());
-      let first = crate::Error::opt_rslt(source.get(a))?;
-      let second = crate::Error::opt_rslt(source.get(b))?;
-      let _ = destination.constructor().or_ref(&first);
-      let _ = destination.constructor().or_ref(&second);
-      if self.probability.is_in_rnd_pbty(&mut rng) {
-        let a = destination.rslts_num() - 2;
-        let b = destination.rslts_num() - 1;
-        let [mut first, mut second] = crate::Error::opt_rslt(destination.get_two_mut(a, b))?;
-        self.swap_data_of_indvs(*first.solution_mut(), *second.solution_mut());
-      }
-    }
-    destination.truncate(filling_num);
-    Ok(())
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    mate_pairs(rng, source, destination, filling_num, self.probability.pct(), |_, first, second| {
+      self.swap_data_of_indvs(first, second);
+    })
+  }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.probability.update(progress);
   }
 }
 
@@ -88,6 +88,7 @@ mod tests {
     utils::dummy_mp,
     Pct,
   };
+  use rand::rngs::OsRng;
 
   #[test]
   fn multi_point() {
@@ -96,8 +97,8 @@ mod tests {
     let source = problem.rslts_mut();
     let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [2.0, 3.0]);
     let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [1.0, 2.0]);
-    let mp = MultiPoint::new(1, Pct::from_percent(100));
-    mp.crossover(source, &mut destination, 2).unwrap();
+    let mut mp = MultiPoint::new(1, Pct::from_percent(100));
+    mp.crossover(&mut OsRng, source, &mut destination, 2).unwrap();
     assert_eq!(destination.get(0).unwrap(), MpOrRef::new(&[], &[4.0, 8.0], &[], &[1.0, 3.0]));
     assert_eq!(destination.get(1).unwrap(), MpOrRef::new(&[], &[2.0, 4.0], &[], &[2.0, 2.0]));
   }
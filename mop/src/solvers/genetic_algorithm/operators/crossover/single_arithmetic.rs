@@ -0,0 +1,116 @@
+use crate::{
+  gp::MpOrs,
+  solvers::{
+    genetic_algorithm::operators::crossover::{mate_pairs, Crossover},
+    utils::blend,
+  },
+  NumVarDomains, Pct, Solution,
+};
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+use num_traits::NumCast;
+use rand::Rng;
+
+/// Michalewicz; Genetic Algorithms + Data Structures = Evolution Programs
+///
+/// Like [`WholeArithmetic`](super::WholeArithmetic) but only a single random variable is
+/// combined.
+///
+/// Variables are blended linearly unless a domain is set through `set_domain`, in which case
+/// they are blended in the encoded space of each variable domain.
+#[derive(Clone, Debug)]
+pub struct SingleArithmetic<D = ()> {
+  domain: D,
+  probability: Pct,
+}
+
+impl SingleArithmetic {
+  #[inline]
+  pub fn new(probability: Pct) -> Self {
+    SingleArithmetic { domain: (), probability }
+  }
+}
+
+impl<D> SingleArithmetic<D> {
+  /// Blends variables in the encoded space of `domain`, e.g., a log scale.
+  #[inline]
+  pub fn set_domain<ND>(self, domain: ND) -> SingleArithmetic<ND> {
+    SingleArithmetic { domain, probability: self.probability }
+  }
+}
+
+impl<D, OR, ORS, S, SS, T> Crossover<MpOrs<ORS, SS>> for SingleArithmetic<D>
+where
+  D: NumVarDomains<T>,
+  OR: Copy,
+  ORS: AsRef<[OR]>
+    + AsMut<[OR]>
+    + Clear
+    + Extend<OR>
+    + SingleTypeStorage<Item = OR>
+    + Truncate<Input = usize>,
+  S: AsMut<[T]> + Clone + Solution<Var = T>,
+  SS: AsRef<[S]>
+    + AsMut<[S]>
+    + Clear
+    + Push<S>
+    + SingleTypeStorage<Item = S>
+    + Truncate<Input = usize>,
+  T: Copy + NumCast,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn crossover<R>(
+    &mut self,
+    rng: &mut R,
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    mate_pairs(rng, source, destination, filling_num, self.probability, |rng, first, second| {
+      let len = first.as_mut().len().min(second.as_mut().len());
+      if len == 0 {
+        return;
+      }
+      let var_idx = rng.gen_range(0..len);
+      let weight = rng.gen::<f64>();
+      let (a, b) = (&mut first.as_mut()[var_idx], &mut second.as_mut()[var_idx]);
+      let domain = self.domain.var_domain(var_idx);
+      if let (Some(new_a), Some(new_b)) =
+        (blend(*a, *b, weight, domain), blend(*b, *a, weight, domain))
+      {
+        *a = new_a;
+        *b = new_b;
+      }
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::crossover::{Crossover, SingleArithmetic},
+    utils::dummy_mp,
+    Pct,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn single_arithmetic() {
+    let mut problem = dummy_mp();
+    let mut destination = problem.rslts_mut().clone();
+    let source = problem.rslts_mut();
+    let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [2.0, 3.0]);
+    let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [1.0, 4.0]);
+    let mut sa = SingleArithmetic::new(Pct::from_percent(100));
+    sa.crossover(&mut OsRng, source, &mut destination, 2).unwrap();
+    let first = *destination.get(0).unwrap().solution();
+    let second = *destination.get(1).unwrap().solution();
+    assert!((first[0] + second[0] - 3.0).abs() < 0.001);
+    assert!((first[1] + second[1] - 7.0).abs() < 0.001);
+    assert!(first[0] == 2.0 || first[1] == 3.0);
+  }
+}
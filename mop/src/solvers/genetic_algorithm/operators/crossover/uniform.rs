@@ -0,0 +1,85 @@
+use crate::{
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::crossover::{mate_pairs, Crossover},
+  Pct, Solution,
+};
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+use rand::Rng;
+
+/// Swaps each variable of two solutions according to `gene_probability`.
+#[derive(Clone, Debug)]
+pub struct Uniform {
+  gene_probability: Pct,
+  probability: Pct,
+}
+
+impl Uniform {
+  #[inline]
+  pub fn new(gene_probability: Pct, probability: Pct) -> Self {
+    Uniform { gene_probability, probability }
+  }
+}
+
+impl<OR, ORS, S, SS> Crossover<MpOrs<ORS, SS>> for Uniform
+where
+  OR: Copy,
+  ORS: AsRef<[OR]>
+    + AsMut<[OR]>
+    + Clear
+    + Extend<OR>
+    + SingleTypeStorage<Item = OR>
+    + Truncate<Input = usize>,
+  S: Clone + Solution,
+  SS: AsRef<[S]>
+    + AsMut<[S]>
+    + Clear
+    + Push<S>
+    + SingleTypeStorage<Item = S>
+    + Truncate<Input = usize>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn crossover<R>(
+    &mut self,
+    rng: &mut R,
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    mate_pairs(rng, source, destination, filling_num, self.probability, |rng, first, second| {
+      for var_idx in 0..first.len().min(second.len()) {
+        if self.gene_probability.is_in_rnd_pbty(rng) {
+          first.inter_swap(second, var_idx);
+        }
+      }
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    gp::MpOrRef,
+    solvers::genetic_algorithm::operators::crossover::{Crossover, Uniform},
+    utils::dummy_mp,
+    Pct,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn uniform() {
+    let mut problem = dummy_mp();
+    let mut destination = problem.rslts_mut().clone();
+    let source = problem.rslts_mut();
+    let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [2.0, 3.0]);
+    let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [1.0, 2.0]);
+    let mut u = Uniform::new(Pct::from_percent(100), Pct::from_percent(100));
+    u.crossover(&mut OsRng, source, &mut destination, 2).unwrap();
+    assert_eq!(destination.get(0).unwrap(), MpOrRef::new(&[], &[4.0, 8.0], &[], &[1.0, 2.0]));
+    assert_eq!(destination.get(1).unwrap(), MpOrRef::new(&[], &[2.0, 4.0], &[], &[2.0, 3.0]));
+  }
+}
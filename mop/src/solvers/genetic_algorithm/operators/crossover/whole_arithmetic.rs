@@ -0,0 +1,130 @@
+use crate::{
+  gp::MpOrs,
+  solvers::{
+    genetic_algorithm::operators::crossover::{mate_pairs, Crossover},
+    utils::blend,
+  },
+  NumVarDomains, Pct, Solution,
+};
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+use num_traits::NumCast;
+use rand::Rng;
+
+/// Michalewicz; Genetic Algorithms + Data Structures = Evolution Programs
+///
+/// Both children are the same random convex combination of their parents, which keeps all
+/// variables within the domain of the parents.
+///
+/// Variables are blended linearly unless a domain is set through `set_domain`, in which case
+/// they are blended in the encoded space of each variable domain.
+#[derive(Clone, Debug)]
+pub struct WholeArithmetic<D = ()> {
+  domain: D,
+  probability: Pct,
+}
+
+impl WholeArithmetic {
+  #[inline]
+  pub fn new(probability: Pct) -> Self {
+    WholeArithmetic { domain: (), probability }
+  }
+}
+
+impl<D> WholeArithmetic<D> {
+  /// Blends variables in the encoded space of `domain`, e.g., a log scale.
+  #[inline]
+  pub fn set_domain<ND>(self, domain: ND) -> WholeArithmetic<ND> {
+    WholeArithmetic { domain, probability: self.probability }
+  }
+}
+
+impl<D, OR, ORS, S, SS, T> Crossover<MpOrs<ORS, SS>> for WholeArithmetic<D>
+where
+  D: NumVarDomains<T>,
+  OR: Copy,
+  ORS: AsRef<[OR]>
+    + AsMut<[OR]>
+    + Clear
+    + Extend<OR>
+    + SingleTypeStorage<Item = OR>
+    + Truncate<Input = usize>,
+  S: AsMut<[T]> + Clone + Solution<Var = T>,
+  SS: AsRef<[S]>
+    + AsMut<[S]>
+    + Clear
+    + Push<S>
+    + SingleTypeStorage<Item = S>
+    + Truncate<Input = usize>,
+  T: Copy + NumCast,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn crossover<R>(
+    &mut self,
+    rng: &mut R,
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    mate_pairs(rng, source, destination, filling_num, self.probability, |rng, first, second| {
+      let weight = rng.gen::<f64>();
+      let iter = first.as_mut().iter_mut().zip(second.as_mut().iter_mut());
+      for (var_idx, (a, b)) in iter.enumerate() {
+        let domain = self.domain.var_domain(var_idx);
+        if let (Some(new_a), Some(new_b)) =
+          (blend(*a, *b, weight, domain), blend(*b, *a, weight, domain))
+        {
+          *a = new_a;
+          *b = new_b;
+        }
+      }
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::crossover::{Crossover, WholeArithmetic},
+    utils::dummy_mp,
+    Pct, ScaledRange,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn whole_arithmetic() {
+    let mut problem = dummy_mp();
+    let mut destination = problem.rslts_mut().clone();
+    let source = problem.rslts_mut();
+    let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [2.0, 3.0]);
+    let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [1.0, 4.0]);
+    let mut wa = WholeArithmetic::new(Pct::from_percent(100));
+    wa.crossover(&mut OsRng, source, &mut destination, 2).unwrap();
+    let first = *destination.get(0).unwrap().solution();
+    let second = *destination.get(1).unwrap().solution();
+    assert!((first[0] + second[0] - 3.0).abs() < 0.001);
+    assert!((first[1] + second[1] - 7.0).abs() < 0.001);
+    assert!((1.0..=2.0).contains(&first[0]) && (3.0..=4.0).contains(&first[1]));
+  }
+
+  #[test]
+  fn whole_arithmetic_with_domain() {
+    let mut problem = dummy_mp();
+    let mut destination = problem.rslts_mut().clone();
+    let source = problem.rslts_mut();
+    let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [1e-4, 0.0]);
+    let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [1.0, 1.0]);
+    let domain =
+      [ScaledRange::log(1e-4..=1.0).unwrap(), ScaledRange::quantized(0.0..=1.0, 0.25).unwrap()];
+    let mut wa = WholeArithmetic::new(Pct::from_percent(100)).set_domain(domain.clone());
+    wa.crossover(&mut OsRng, source, &mut destination, 2).unwrap();
+    let first = *destination.get(0).unwrap().solution();
+    let second = *destination.get(1).unwrap().solution();
+    assert!((first[0].log10() + second[0].log10() + 4.0).abs() < 0.001);
+    assert!(domain[1].contains(first[1]) && domain[1].contains(second[1]));
+  }
+}
@@ -1,27 +1,91 @@
+mod exponential_rank;
+mod linear_rank;
+mod random;
+mod roulette;
+mod spea2_raw_fitness;
+mod stochastic_universal_sampling;
 mod tournament;
 
+use crate::gp::{MpOrRef, MpOrs};
+use alloc::vec::Vec;
+use cl_aux::SingleTypeStorage;
+pub use exponential_rank::ExponentialRank;
+pub use linear_rank::LinearRank;
+use rand::Rng;
+pub use random::Random;
+pub use roulette::Roulette;
+pub use spea2_raw_fitness::Spea2RawFitness;
+pub use stochastic_universal_sampling::StochasticUniversalSampling;
 pub use tournament::Tournament;
 
+/// Extracts a scalar fitness of each result for fitness-based selection schemes.
+///
+/// Greater values are better.
+pub trait FitnessExtractor<M, T>
+where
+  M: ?Sized,
+{
+  /// Pushes the fitness of every result of `source` into `buffer`, in order.
+  fn fitnesses(&self, misc: &M, source: &T, buffer: &mut Vec<f64>);
+}
+
+impl<F, O, OR, ORS, S, SS> FitnessExtractor<[O], MpOrs<ORS, SS>> for F
+where
+  F: Fn(&[O], &MpOrRef<'_, OR, S>) -> f64,
+  ORS: AsRef<[OR]> + SingleTypeStorage<Item = OR>,
+  SS: AsRef<[S]> + SingleTypeStorage<Item = S>,
+{
+  #[inline]
+  fn fitnesses(&self, objs: &[O], source: &MpOrs<ORS, SS>, buffer: &mut Vec<f64>) {
+    buffer.extend(source.iter().map(|rslt| self(objs, &rslt)));
+  }
+}
+
+impl<F, OR, ORS, S, SS> FitnessExtractor<(), MpOrs<ORS, SS>> for F
+where
+  F: Fn(&MpOrRef<'_, OR, S>) -> f64,
+  ORS: AsRef<[OR]> + SingleTypeStorage<Item = OR>,
+  SS: AsRef<[S]> + SingleTypeStorage<Item = S>,
+{
+  #[inline]
+  fn fitnesses(&self, _: &(), source: &MpOrs<ORS, SS>, buffer: &mut Vec<f64>) {
+    buffer.extend(source.iter().map(|rslt| self(&rslt)));
+  }
+}
+
 pub trait MatingSelection<M, T>
 where
   M: ?Sized,
 {
   type Error;
 
-  fn mating_selection(
+  fn mating_selection<R>(
     &self,
+    rng: &mut R,
     misc: &M,
     source: &mut T,
     destination: &mut T,
     filling_num: usize,
-  ) -> Result<(), Self::Error>;
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng;
 }
 
 impl<M, T> MatingSelection<M, T> for () {
   type Error = core::convert::Infallible;
 
   #[inline]
-  fn mating_selection(&self, _: &M, _: &mut T, _: &mut T, _: usize) -> Result<(), Self::Error> {
+  fn mating_selection<R>(
+    &self,
+    _: &mut R,
+    _: &M,
+    _: &mut T,
+    _: &mut T,
+    _: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
     Ok(())
   }
 }
@@ -0,0 +1,113 @@
+use crate::{
+  gp::MpOrs,
+  solvers::{
+    genetic_algorithm::operators::mating_selection::{FitnessExtractor, MatingSelection},
+    utils::{ascending_idxs, spin_roulette_wheel},
+  },
+};
+use alloc::vec::Vec;
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+use rand::Rng;
+
+/// Exponential ranking selection.
+///
+/// The result ranked at `n` (`0` is the best) has a weight of `base.powi(n)`, where `base` is
+/// within `(0.0, 1.0]` and defaults to `0.5`. Lower bases impose a greater selection pressure.
+#[derive(Clone, Debug)]
+pub struct ExponentialRank<FE> {
+  base: f64,
+  fitness_extractor: FE,
+}
+
+impl<FE> ExponentialRank<FE> {
+  #[inline]
+  pub fn new(fitness_extractor: FE, base: f64) -> Self {
+    assert!(base > 0.0 && base <= 1.0);
+    ExponentialRank { base, fitness_extractor }
+  }
+}
+
+impl<FE> Default for ExponentialRank<FE>
+where
+  FE: Default,
+{
+  #[inline]
+  fn default() -> Self {
+    Self::new(FE::default(), 0.5)
+  }
+}
+
+impl<FE, O, OR, ORS, S, SS> MatingSelection<[O], MpOrs<ORS, SS>> for ExponentialRank<FE>
+where
+  FE: FitnessExtractor<[O], MpOrs<ORS, SS>>,
+  OR: Copy,
+  ORS: AsRef<[OR]> + Clear + Extend<OR> + SingleTypeStorage<Item = OR> + Truncate<Input = usize>,
+  S: Clone,
+  SS: AsRef<[S]> + Clear + Push<S> + SingleTypeStorage<Item = S> + Truncate<Input = usize>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mating_selection<R>(
+    &self,
+    rng: &mut R,
+    objs: &[O],
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    destination.clear();
+    let mut fitnesses = Vec::with_capacity(source.rslts_num());
+    self.fitness_extractor.fitnesses(objs, source, &mut fitnesses);
+    let mut weights = alloc::vec![0.0; fitnesses.len()];
+    let mut weight = 1.0;
+    for idx in ascending_idxs(&fitnesses).into_iter().rev() {
+      weights[idx] = weight;
+      weight *= self.base;
+    }
+    let total = weights.iter().sum();
+    while destination.rslts_num() < filling_num {
+      let idx = spin_roulette_wheel(rng, &weights, total);
+      let winner = crate::Error::opt_rslt(source.get(idx))?;
+      let _ = destination.constructor().or_ref(&winner);
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    gp::MpOrRef,
+    solvers::genetic_algorithm::operators::mating_selection::{
+      ExponentialRank, MatingSelection, Spea2RawFitness,
+    },
+    utils::dummy_mp,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn exponential_rank() {
+    let mut problem = dummy_mp();
+    let (defs, source) = problem.parts_mut();
+    let mut destination = source.clone();
+    let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [2.0, 3.0]);
+    let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [1.0, 2.0]);
+    let ms = ExponentialRank::new(
+      |_: &[_], r: &MpOrRef<'_, f64, [f64; 2]>| -r.obj_rslts()[0],
+      f64::MIN_POSITIVE,
+    );
+    ms.mating_selection(&mut OsRng, defs.objs(), source, &mut destination, 2).unwrap();
+    assert_eq!(destination.get(0), source.get(1));
+    assert_eq!(destination.get(1), source.get(1));
+  }
+
+  #[test]
+  fn exponential_rank_default_has_valid_base() {
+    let ms = ExponentialRank::<Spea2RawFitness>::default();
+    assert!(ms.base > 0.0 && ms.base <= 1.0);
+  }
+}
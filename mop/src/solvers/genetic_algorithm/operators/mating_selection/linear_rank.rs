@@ -0,0 +1,122 @@
+use crate::{
+  gp::MpOrs,
+  solvers::{
+    genetic_algorithm::operators::mating_selection::{FitnessExtractor, MatingSelection},
+    utils::{ascending_idxs, spin_roulette_wheel},
+  },
+};
+use alloc::vec::Vec;
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+use rand::Rng;
+
+/// Linear ranking selection.
+///
+/// Only the relative order of the fitnesses matters. `pressure`, from `1.0` to `2.0`, is the
+/// expected number of times the best result is selected in relation to the average result and
+/// defaults to `2.0`.
+#[derive(Clone, Debug)]
+pub struct LinearRank<FE> {
+  fitness_extractor: FE,
+  pressure: f64,
+}
+
+impl<FE> LinearRank<FE> {
+  #[inline]
+  pub fn new(fitness_extractor: FE, pressure: f64) -> Self {
+    assert!((1.0..=2.0).contains(&pressure));
+    LinearRank { fitness_extractor, pressure }
+  }
+}
+
+impl<FE> Default for LinearRank<FE>
+where
+  FE: Default,
+{
+  #[inline]
+  fn default() -> Self {
+    Self::new(FE::default(), 2.0)
+  }
+}
+
+impl<FE, O, OR, ORS, S, SS> MatingSelection<[O], MpOrs<ORS, SS>> for LinearRank<FE>
+where
+  FE: FitnessExtractor<[O], MpOrs<ORS, SS>>,
+  OR: Copy,
+  ORS: AsRef<[OR]> + Clear + Extend<OR> + SingleTypeStorage<Item = OR> + Truncate<Input = usize>,
+  S: Clone,
+  SS: AsRef<[S]> + Clear + Push<S> + SingleTypeStorage<Item = S> + Truncate<Input = usize>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mating_selection<R>(
+    &self,
+    rng: &mut R,
+    objs: &[O],
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    destination.clear();
+    let mut fitnesses = Vec::with_capacity(source.rslts_num());
+    self.fitness_extractor.fitnesses(objs, source, &mut fitnesses);
+    let len = fitnesses.len();
+    let mut weights = alloc::vec![1.0; len];
+    if len > 1 {
+      let len_f64 = crate::Error::cast_rslt::<_, f64>(len)?;
+      let base = (2.0 - self.pressure) / len_f64;
+      let step = 2.0 * (self.pressure - 1.0) / (len_f64 * (len_f64 - 1.0));
+      for (rank, idx) in ascending_idxs(&fitnesses).into_iter().enumerate() {
+        weights[idx] = base + crate::Error::cast_rslt::<_, f64>(rank)? * step;
+      }
+    }
+    let total = weights.iter().sum();
+    while destination.rslts_num() < filling_num {
+      let idx = spin_roulette_wheel(rng, &weights, total);
+      let winner = crate::Error::opt_rslt(source.get(idx))?;
+      let _ = destination.constructor().or_ref(&winner);
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    gp::MpOrRef,
+    solvers::genetic_algorithm::operators::mating_selection::{
+      LinearRank, MatingSelection, Spea2RawFitness,
+    },
+    utils::dummy_mp,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn linear_rank() {
+    let mut problem = dummy_mp();
+    let (defs, source) = problem.parts_mut();
+    let mut destination = source.clone();
+    let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [2.0, 3.0]);
+    let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [1.0, 2.0]);
+    let ms = LinearRank::new(|_: &[_], r: &MpOrRef<'_, f64, [f64; 2]>| -r.obj_rslts()[0], 2.0);
+    ms.mating_selection(&mut OsRng, defs.objs(), source, &mut destination, 2).unwrap();
+    assert_eq!(destination.get(0), source.get(1));
+    assert_eq!(destination.get(1), source.get(1));
+  }
+
+  #[test]
+  fn linear_rank_default_favours_the_best() {
+    let mut problem = dummy_mp();
+    let (defs, source) = problem.parts_mut();
+    let mut destination = source.clone();
+    let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [2.0, 3.0]);
+    let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [1.0, 2.0]);
+    let ms = LinearRank::<Spea2RawFitness>::default();
+    ms.mating_selection(&mut OsRng, defs.objs(), source, &mut destination, 2).unwrap();
+    assert_eq!(destination.get(0), source.get(1));
+    assert_eq!(destination.get(1), source.get(1));
+  }
+}
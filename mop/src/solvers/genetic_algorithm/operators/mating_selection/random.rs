@@ -0,0 +1,63 @@
+use crate::{gp::MpOrs, solvers::genetic_algorithm::operators::mating_selection::MatingSelection};
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+use rand::Rng;
+
+/// Uniform selection without any pressure.
+#[derive(Clone, Debug, Default)]
+pub struct Random;
+
+impl<O, OR, ORS, S, SS> MatingSelection<[O], MpOrs<ORS, SS>> for Random
+where
+  OR: Copy,
+  ORS: AsRef<[OR]> + Clear + Extend<OR> + SingleTypeStorage<Item = OR> + Truncate<Input = usize>,
+  S: Clone,
+  SS: AsRef<[S]> + Clear + Push<S> + SingleTypeStorage<Item = S> + Truncate<Input = usize>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mating_selection<R>(
+    &self,
+    rng: &mut R,
+    _: &[O],
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    destination.clear();
+    while destination.rslts_num() < filling_num {
+      let idx = rng.gen_range(0..source.rslts_num().max(1));
+      let winner = crate::Error::opt_rslt(source.get(idx))?;
+      let _ = destination.constructor().or_ref(&winner);
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::mating_selection::{MatingSelection, Random},
+    utils::dummy_mp,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn random() {
+    let mut problem = dummy_mp();
+    let (defs, source) = problem.parts_mut();
+    let mut destination = source.clone();
+    let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [2.0, 3.0]);
+    Random.mating_selection(&mut OsRng, defs.objs(), source, &mut destination, 3).unwrap();
+    assert_eq!(destination.rslts_num(), 3);
+    assert_eq!(destination.get(2), source.get(0));
+    let mut empty = destination.clone();
+    empty.clear();
+    assert!(Random
+      .mating_selection(&mut OsRng, defs.objs(), &mut empty, &mut destination, 1)
+      .is_err());
+  }
+}
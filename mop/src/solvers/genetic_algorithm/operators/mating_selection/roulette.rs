@@ -0,0 +1,84 @@
+use crate::{
+  gp::MpOrs,
+  solvers::{
+    genetic_algorithm::operators::mating_selection::{FitnessExtractor, MatingSelection},
+    utils::{spin_roulette_wheel, windowed_weights},
+  },
+};
+use alloc::vec::Vec;
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+use rand::Rng;
+
+/// Fitness-proportional selection.
+///
+/// Fitnesses are shifted so that the worst result of the pool has no chance of being selected. If
+/// all results have the same fitness, the selection is uniform.
+#[derive(Clone, Debug, Default)]
+pub struct Roulette<FE> {
+  fitness_extractor: FE,
+}
+
+impl<FE> Roulette<FE> {
+  #[inline]
+  pub fn new(fitness_extractor: FE) -> Self {
+    Roulette { fitness_extractor }
+  }
+}
+
+impl<FE, O, OR, ORS, S, SS> MatingSelection<[O], MpOrs<ORS, SS>> for Roulette<FE>
+where
+  FE: FitnessExtractor<[O], MpOrs<ORS, SS>>,
+  OR: Copy,
+  ORS: AsRef<[OR]> + Clear + Extend<OR> + SingleTypeStorage<Item = OR> + Truncate<Input = usize>,
+  S: Clone,
+  SS: AsRef<[S]> + Clear + Push<S> + SingleTypeStorage<Item = S> + Truncate<Input = usize>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mating_selection<R>(
+    &self,
+    rng: &mut R,
+    objs: &[O],
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    destination.clear();
+    let mut weights = Vec::with_capacity(source.rslts_num());
+    self.fitness_extractor.fitnesses(objs, source, &mut weights);
+    let total = windowed_weights(&mut weights);
+    while destination.rslts_num() < filling_num {
+      let idx = spin_roulette_wheel(rng, &weights, total);
+      let winner = crate::Error::opt_rslt(source.get(idx))?;
+      let _ = destination.constructor().or_ref(&winner);
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    gp::MpOrRef,
+    solvers::genetic_algorithm::operators::mating_selection::{MatingSelection, Roulette},
+    utils::dummy_mp,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn roulette() {
+    let mut problem = dummy_mp();
+    let (defs, source) = problem.parts_mut();
+    let mut destination = source.clone();
+    let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [2.0, 3.0]);
+    let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [1.0, 2.0]);
+    let ms = Roulette::new(|_: &[_], r: &MpOrRef<'_, f64, [f64; 2]>| -r.obj_rslts()[0]);
+    ms.mating_selection(&mut OsRng, defs.objs(), source, &mut destination, 2).unwrap();
+    assert_eq!(destination.get(0), source.get(1));
+    assert_eq!(destination.get(1), source.get(1));
+  }
+}
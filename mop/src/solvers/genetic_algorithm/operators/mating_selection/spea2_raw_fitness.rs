@@ -0,0 +1,63 @@
+use crate::{
+  gp::MpOrs, solvers::genetic_algorithm::operators::mating_selection::FitnessExtractor,
+  utils::verify_pareto_dominance, Obj,
+};
+use alloc::vec::Vec;
+use cl_aux::SingleTypeStorage;
+use core::cmp::Ordering;
+
+/// Raw fitness of SPEA2, i.e., without density information.
+///
+/// The raw fitness of a result is the sum of the strengths of all results that dominate it and
+/// the extracted value is `1 / (1 + raw)`, which means that non-dominated results have the
+/// greatest fitness.
+#[derive(Clone, Debug, Default)]
+pub struct Spea2RawFitness;
+
+impl<O, OR, ORS, S, SS> FitnessExtractor<[O], MpOrs<ORS, SS>> for Spea2RawFitness
+where
+  O: Obj<OR, S>,
+  OR: PartialOrd,
+  ORS: AsRef<[OR]> + SingleTypeStorage<Item = OR>,
+  SS: AsRef<[S]> + SingleTypeStorage<Item = S>,
+{
+  #[inline]
+  fn fitnesses(&self, objs: &[O], source: &MpOrs<ORS, SS>, buffer: &mut Vec<f64>) {
+    let rslts_num = source.rslts_num();
+    let mut dominance = alloc::vec![Ordering::Equal; rslts_num.saturating_mul(rslts_num)];
+    let mut strengths = alloc::vec![0.0; rslts_num];
+    for (fst_idx, fst) in source.iter().enumerate() {
+      for (sec_idx, sec) in source.iter().enumerate() {
+        let ordering = verify_pareto_dominance(objs, fst.obj_rslts(), sec.obj_rslts());
+        if ordering == Ordering::Greater {
+          strengths[fst_idx] += 1.0;
+        }
+        dominance[fst_idx * rslts_num + sec_idx] = ordering;
+      }
+    }
+    buffer.extend((0..rslts_num).map(|idx| {
+      let raw: f64 = (0..rslts_num)
+        .filter(|other| dominance[other * rslts_num + idx] == Ordering::Greater)
+        .map(|other| strengths[other])
+        .sum();
+      1.0 / (1.0 + raw)
+    }));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::mating_selection::{FitnessExtractor, Spea2RawFitness},
+    utils::dummy_mp_with_solutions,
+  };
+  use alloc::vec::Vec;
+
+  #[test]
+  fn spea2_raw_fitness() {
+    let problem = dummy_mp_with_solutions();
+    let mut buffer = Vec::new();
+    Spea2RawFitness.fitnesses(problem.defs().objs(), problem.rslts(), &mut buffer);
+    assert_eq!(buffer, [1.0, 1.0 / 4.0, 1.0 / 6.0, 1.0 / 7.0]);
+  }
+}
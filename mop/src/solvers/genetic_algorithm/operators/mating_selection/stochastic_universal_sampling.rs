@@ -0,0 +1,108 @@
+use crate::{
+  gp::MpOrs,
+  solvers::{
+    genetic_algorithm::operators::mating_selection::{FitnessExtractor, MatingSelection},
+    utils::windowed_weights,
+  },
+};
+use alloc::vec::Vec;
+use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
+use rand::Rng;
+
+/// Baker; Reducing bias and inefficiency in the selection algorithm
+///
+/// Fitness-proportional selection that uses a single spin with `filling_num` equally spaced
+/// pointers, which has the minimum spread among the proportional schemes. Fitnesses are shifted
+/// like in [`Roulette`](super::Roulette).
+#[derive(Clone, Debug, Default)]
+pub struct StochasticUniversalSampling<FE> {
+  fitness_extractor: FE,
+}
+
+impl<FE> StochasticUniversalSampling<FE> {
+  #[inline]
+  pub fn new(fitness_extractor: FE) -> Self {
+    StochasticUniversalSampling { fitness_extractor }
+  }
+}
+
+impl<FE, O, OR, ORS, S, SS> MatingSelection<[O], MpOrs<ORS, SS>> for StochasticUniversalSampling<FE>
+where
+  FE: FitnessExtractor<[O], MpOrs<ORS, SS>>,
+  OR: Copy,
+  ORS: AsRef<[OR]> + Clear + Extend<OR> + SingleTypeStorage<Item = OR> + Truncate<Input = usize>,
+  S: Clone,
+  SS: AsRef<[S]> + Clear + Push<S> + SingleTypeStorage<Item = S> + Truncate<Input = usize>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mating_selection<R>(
+    &self,
+    rng: &mut R,
+    objs: &[O],
+    source: &mut MpOrs<ORS, SS>,
+    destination: &mut MpOrs<ORS, SS>,
+    filling_num: usize,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    destination.clear();
+    if filling_num == 0 {
+      return Ok(());
+    }
+    let mut weights = Vec::with_capacity(source.rslts_num());
+    self.fitness_extractor.fitnesses(objs, source, &mut weights);
+    let mut total = windowed_weights(&mut weights);
+    if !(total > 0.0 && total.is_finite()) {
+      weights.iter_mut().for_each(|weight| *weight = 1.0);
+      total = crate::Error::cast_rslt(weights.len())?;
+    }
+    let step = total / crate::Error::cast_rslt::<_, f64>(filling_num)?;
+    let mut pointer = rng.gen::<f64>() * step;
+    let mut cumulative = 0.0;
+    for (idx, weight) in weights.iter().enumerate() {
+      cumulative += weight;
+      while pointer < cumulative && destination.rslts_num() < filling_num {
+        let winner = crate::Error::opt_rslt(source.get(idx))?;
+        let _ = destination.constructor().or_ref(&winner);
+        pointer += step;
+      }
+    }
+    // Floating point errors can leave the last pointers out of the wheel
+    while destination.rslts_num() < filling_num {
+      let winner = crate::Error::opt_rslt(source.get(weights.len().saturating_sub(1)))?;
+      let _ = destination.constructor().or_ref(&winner);
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    gp::MpOrRef,
+    solvers::genetic_algorithm::operators::mating_selection::{
+      MatingSelection, StochasticUniversalSampling,
+    },
+    utils::dummy_mp,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn stochastic_universal_sampling() {
+    let mut problem = dummy_mp();
+    let (defs, source) = problem.parts_mut();
+    let mut destination = source.clone();
+    let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [2.0, 3.0]);
+    let _ = source.constructor().or_os_iter([3.0, 8.0].iter().cloned(), [2.0, 3.0]);
+    let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [1.0, 2.0]);
+    let ms =
+      StochasticUniversalSampling::new(|_: &[_], r: &MpOrRef<'_, f64, [f64; 2]>| -r.obj_rslts()[0]);
+    ms.mating_selection(&mut OsRng, defs.objs(), source, &mut destination, 3).unwrap();
+    assert_eq!(destination.get(0), source.get(1));
+    assert_eq!(destination.get(1), source.get(2));
+    assert_eq!(destination.get(2), source.get(2));
+  }
+}
@@ -4,7 +4,7 @@ use crate::{
   solvers::genetic_algorithm::operators::mating_selection::MatingSelection,
 };
 use cl_aux::{Clear, Push, SingleTypeStorage, Truncate};
-use rand::{rngs::OsRng, Rng};
+use rand::Rng;
 
 #[derive(Clone, Debug, Default)]
 pub struct Tournament<QC> {
@@ -30,15 +30,18 @@ where
   type Error = crate::Error;
 
   #[inline]
-  fn mating_selection(
+  fn mating_selection<R>(
     &self,
+    rng: &mut R,
     objs: &[O],
     source: &mut MpOrs<ORS, SS>,
     destination: &mut MpOrs<ORS, SS>,
     filling_num: usize,
-  ) -> Result<(), Self::Error> {
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
     destination.clear();
-    let mut rng = OsRng;
     while destination.rslts_num() < filling_num {
       let winner_opt = source.get(rng.gen_range(0..source.rslts_num()));
       let mut winner = crate::Error::opt_rslt(winner_opt)?;
@@ -62,6 +65,7 @@ mod tests {
     solvers::genetic_algorithm::operators::mating_selection::{MatingSelection, Tournament},
     utils::dummy_mp,
   };
+  use rand::rngs::OsRng;
 
   #[test]
   fn tournament() {
@@ -71,7 +75,7 @@ mod tests {
     let _ = source.constructor().or_os_iter([4.0, 8.0].iter().cloned(), [2.0, 3.0]);
     let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [1.0, 2.0]);
     let mp = Tournament::new(999, ObjsAvg);
-    mp.mating_selection(defs.objs(), source, &mut destination, 1).unwrap();
+    mp.mating_selection(&mut OsRng, defs.objs(), source, &mut destination, 1).unwrap();
     assert_eq!(destination.get(0), source.get(1));
   }
 }
@@ -1,20 +1,187 @@
+mod adaptive_mutation;
+mod add_gene;
+mod bit_flip;
+mod cauchy;
+#[cfg(feature = "ndstruct")]
+mod csl_lines;
+#[cfg(feature = "ndstruct")]
+mod csl_merge;
+#[cfg(feature = "ndstruct")]
+mod csl_move;
+#[cfg(feature = "ndstruct")]
+mod csl_split;
+#[cfg(feature = "ndstruct")]
+mod csl_swap;
+mod delete_gene;
+mod gaussian;
+mod mixed;
 mod random_domain_assignments;
+mod self_adaptive;
+mod self_adaptive_gaussian;
 mod swap;
 
+use crate::{solvers::genetic_algorithm::operators::adaptive_selection::AdaptiveState, Progress};
+pub use adaptive_mutation::AdaptiveMutation;
+pub use add_gene::AddGene;
+use alloc::vec::{self, Vec};
+pub use bit_flip::BitFlip;
+pub use cauchy::Cauchy;
+#[cfg(feature = "ndstruct")]
+pub use csl_merge::CslMerge;
+#[cfg(feature = "ndstruct")]
+pub use csl_move::CslMove;
+#[cfg(feature = "ndstruct")]
+pub use csl_split::CslSplit;
+#[cfg(feature = "ndstruct")]
+pub use csl_swap::CslSwap;
+pub use delete_gene::DeleteGene;
+pub use gaussian::Gaussian;
+pub use mixed::Mixed;
+use rand::Rng;
 pub use random_domain_assignments::RandomDomainAssignments;
+pub use self_adaptive::SelfAdaptive;
+pub use self_adaptive_gaussian::SelfAdaptiveGaussian;
 pub use swap::Swap;
 
 pub trait Mutation<M, T> {
   type Error;
 
-  fn mutation(&self, misc: &M, source: &mut T) -> Result<(), Self::Error>;
+  fn mutation<R>(&mut self, rng: &mut R, misc: &M, source: &mut T) -> Result<(), Self::Error>
+  where
+    R: Rng;
+
+  /// Appends the [`AdaptiveState`] of this operator and of its inner operators, if any, to
+  /// `states`. Used by solver checkpoints.
+  #[inline]
+  fn adaptive_states(&self, _: &mut Vec<AdaptiveState>) {}
+
+  /// Called by solvers once the objectives of the offspring modified by the last mutation were
+  /// evaluated.
+  #[inline]
+  fn offspring_evaluated(&mut self, _: &T) {}
+
+  /// Whether solvers must evaluate the objectives of `source` before calling
+  /// [`Mutation::mutation`], e.g., to measure the effect of the mutation alone. These evaluations
+  /// count towards evaluation budgets.
+  #[inline]
+  fn needs_evaluated_source(&self) -> bool {
+    false
+  }
+
+  /// Called by solvers when the population was replaced by a restart.
+  #[inline]
+  fn restarted(&mut self) {}
+
+  /// Consumes, in the same order, the states appended by [`Mutation::adaptive_states`].
+  #[inline]
+  fn set_adaptive_states(&mut self, _: &mut vec::IntoIter<AdaptiveState>) {}
+
+  /// Called by solvers before each generation with the current optimization progress.
+  #[inline]
+  fn update_progress(&mut self, _: &Progress) {}
+}
+
+/// A fixed set of mutations that can be individually applied. Implemented for tuples.
+pub trait Mutations<M, T> {
+  /// Applies the mutation of index `idx`.
+  fn mutation_nth<R>(
+    &mut self,
+    idx: usize,
+    rng: &mut R,
+    misc: &M,
+    source: &mut T,
+  ) -> crate::Result<()>
+  where
+    R: Rng;
+
+  /// Forwards [`Mutation::adaptive_states`] to all mutations.
+  fn adaptive_states(&self, states: &mut Vec<AdaptiveState>);
+
+  /// Forwards [`Mutation::offspring_evaluated`] to all mutations.
+  fn offspring_evaluated(&mut self, offspring: &T);
+
+  /// Forwards [`Mutation::restarted`] to all mutations.
+  fn restarted(&mut self);
+
+  /// Forwards [`Mutation::set_adaptive_states`] to all mutations.
+  fn set_adaptive_states(&mut self, states: &mut vec::IntoIter<AdaptiveState>);
+
+  /// Forwards [`Mutation::update_progress`] to all mutations.
+  fn update_progress(&mut self, progress: &Progress);
+
+  /// Number of mutations
+  fn operators_num(&self) -> usize;
 }
 
+macro_rules! impl_mutations {
+  ($($ty:ident $idx:tt),+) => {
+    impl<M, T, $($ty),+> Mutations<M, T> for ($($ty,)+)
+    where
+      $($ty: Mutation<M, T>, crate::Error: From<$ty::Error>,)+
+    {
+      #[inline]
+      fn mutation_nth<R>(
+        &mut self,
+        idx: usize,
+        rng: &mut R,
+        misc: &M,
+        source: &mut T,
+      ) -> crate::Result<()>
+      where
+        R: Rng,
+      {
+        match idx {
+          $($idx => Ok(self.$idx.mutation(rng, misc, source)?),)+
+          _ => Err(crate::Error::EmptyElement),
+        }
+      }
+
+      #[inline]
+      fn adaptive_states(&self, states: &mut Vec<AdaptiveState>) {
+        $(self.$idx.adaptive_states(states);)+
+      }
+
+      #[inline]
+      fn offspring_evaluated(&mut self, offspring: &T) {
+        $(self.$idx.offspring_evaluated(offspring);)+
+      }
+
+      #[inline]
+      fn restarted(&mut self) {
+        $(self.$idx.restarted();)+
+      }
+
+      #[inline]
+      fn set_adaptive_states(&mut self, states: &mut vec::IntoIter<AdaptiveState>) {
+        $(self.$idx.set_adaptive_states(states);)+
+      }
+
+      #[inline]
+      fn update_progress(&mut self, progress: &Progress) {
+        $(self.$idx.update_progress(progress);)+
+      }
+
+      #[inline]
+      fn operators_num(&self) -> usize {
+        [$($idx),+].len()
+      }
+    }
+  };
+}
+
+impl_mutations!(A 0, B 1);
+impl_mutations!(A 0, B 1, C 2);
+impl_mutations!(A 0, B 1, C 2, D 3);
+impl_mutations!(A 0, B 1, C 2, D 3, E 4);
+
 impl<M, T> Mutation<M, T> for () {
   type Error = core::convert::Infallible;
 
   #[inline]
-  fn mutation(&self, _: &M, _: &mut T) -> Result<(), Self::Error> {
+  fn mutation<R>(&mut self, _: &mut R, _: &M, _: &mut T) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
     Ok(())
   }
 }
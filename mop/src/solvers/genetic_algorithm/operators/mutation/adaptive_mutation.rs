@@ -0,0 +1,145 @@
+use crate::{
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::{
+    adaptive_selection::{AdaptiveSelector, AdaptiveState, AdaptiveStrategy},
+    mating_selection::FitnessExtractor,
+    mutation::{Mutation, Mutations},
+  },
+  Progress,
+};
+use alloc::vec::{self, Vec};
+use rand::Rng;
+
+/// Applies, at each generation, one of the mutations of the `operators` tuple.
+///
+/// Each mutation is credited with the improvement of the `fitness` of its evaluated offspring
+/// over the fitness they had before being mutated and `strategy` turns these credits into a
+/// choice. Solvers evaluate the offspring of the crossover before mutating them, so crossovers
+/// don't influence these credits.
+///
+/// This additional evaluation of the whole population is counted like any other, so each
+/// generation costs twice the objective evaluations of a non-adaptive mutation and a budget set
+/// through [`OptFacade::set_max_evaluations`](crate::opt::OptFacade::set_max_evaluations) lasts
+/// about half of the generations.
+#[derive(Clone, Debug)]
+pub struct AdaptiveMutation<FE, OPS> {
+  fitness: FE,
+  operators: OPS,
+  selector: AdaptiveSelector,
+}
+
+impl<FE, OPS> AdaptiveMutation<FE, OPS> {
+  #[inline]
+  pub fn new(fitness: FE, operators: OPS, strategy: AdaptiveStrategy) -> Self {
+    AdaptiveMutation { fitness, operators, selector: AdaptiveSelector::new(strategy) }
+  }
+
+  /// Current selection probabilities of each mutation.
+  #[inline]
+  pub fn probabilities(&self) -> &[f64] {
+    self.selector.probabilities()
+  }
+
+  /// Current estimated quality of each mutation.
+  #[inline]
+  pub fn qualities(&self) -> &[f64] {
+    self.selector.qualities()
+  }
+}
+
+impl<FE, M, OPS, ORS, SS> Mutation<M, MpOrs<ORS, SS>> for AdaptiveMutation<FE, OPS>
+where
+  FE: FitnessExtractor<(), MpOrs<ORS, SS>>,
+  OPS: Mutations<M, MpOrs<ORS, SS>>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn adaptive_states(&self, states: &mut Vec<AdaptiveState>) {
+    states.push(self.selector.state());
+    self.operators.adaptive_states(states);
+  }
+
+  #[inline]
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    misc: &M,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    let idx = self.selector.choose(rng, self.operators.operators_num());
+    let fitness = &self.fitness;
+    self.selector.record(idx, |buffer| fitness.fitnesses(&(), source, buffer));
+    self.operators.mutation_nth(idx, rng, misc, source)
+  }
+
+  #[inline]
+  fn offspring_evaluated(&mut self, offspring: &MpOrs<ORS, SS>) {
+    self.operators.offspring_evaluated(offspring);
+    let fitness = &self.fitness;
+    self.selector.reward(|buffer| fitness.fitnesses(&(), offspring, buffer));
+  }
+
+  #[inline]
+  fn needs_evaluated_source(&self) -> bool {
+    true
+  }
+
+  #[inline]
+  fn restarted(&mut self) {
+    self.operators.restarted();
+    self.selector.clear_pending();
+  }
+
+  #[inline]
+  fn set_adaptive_states(&mut self, states: &mut vec::IntoIter<AdaptiveState>) {
+    if let Some(state) = states.next() {
+      self.selector.set_state(state);
+    }
+    self.operators.set_adaptive_states(states);
+  }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.operators.update_progress(progress);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    gp::MpOrRef,
+    solvers::genetic_algorithm::operators::{
+      adaptive_selection::AdaptiveStrategy,
+      mutation::{AdaptiveMutation, Gaussian, Mutation, Swap},
+    },
+    utils::dummy_mp,
+    Pct,
+  };
+  use core::ops::RangeInclusive;
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn adaptive_mutation() {
+    let mut problem = dummy_mp();
+    let source = problem.rslts_mut();
+    let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [5.0, 5.0]);
+    let mut am = AdaptiveMutation::new(
+      |rslt: &MpOrRef<'_, f64, [f64; 2]>| -rslt.obj_rslts().iter().sum::<f64>(),
+      (Gaussian::new(0.5, 10, Pct::from_percent(100)), Swap::new(1, Pct::from_percent(100))),
+      AdaptiveStrategy::Ucb { alpha: 1.0, exploration: 1.0 },
+    );
+    am.mutation(&mut OsRng, &[0.0..=10.0, 4.0..=6.0], source).unwrap();
+    let mut evaluated = source.clone();
+    evaluated.clear();
+    let _ = evaluated.constructor().or_os_iter([0.0, 4.0].iter().cloned(), [5.0, 5.0]);
+    Mutation::<[RangeInclusive<f64>; 2], _>::offspring_evaluated(&mut am, &evaluated);
+    assert_eq!(am.qualities(), &[2.0, 0.0]);
+    let [first, second] = **source.get(0).unwrap().solution();
+    am.mutation(&mut OsRng, &[0.0..=10.0, 4.0..=6.0], source).unwrap();
+    assert_eq!(**source.get(0).unwrap().solution(), [second, first]);
+  }
+}
@@ -0,0 +1,95 @@
+use crate::{
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::{mutation::Mutation, probability_schedule::ScheduledPct},
+  Domain, Pct, Progress, Solution,
+};
+use cl_aux::SingleTypeStorage;
+use rand::Rng;
+
+/// Inserts `times` random variables at random positions of variable-length solutions while
+/// their lengths are less than the maximum length of the domain.
+#[derive(Clone, Debug)]
+pub struct AddGene {
+  probability: ScheduledPct,
+  times: usize,
+}
+
+impl AddGene {
+  #[inline]
+  pub fn new(times: usize, probability: Pct) -> Self {
+    AddGene { probability: ScheduledPct::new(probability), times }
+  }
+}
+
+impl_set_schedule!(AddGene);
+
+impl<D, OR, ORS, S, SS> Mutation<D, MpOrs<ORS, SS>> for AddGene
+where
+  D: Domain<S>,
+  ORS: AsMut<[OR]> + SingleTypeStorage<Item = OR>,
+  S: Solution,
+  S::Var: Default,
+  SS: AsMut<[S]> + SingleTypeStorage<Item = S>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    domain: &D,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    let max_len = domain.max_len().min(S::MAX_LEN);
+    for mut result in source.iter_mut() {
+      if !self.probability.pct().is_in_rnd_pbty(rng) {
+        continue;
+      }
+      let solution = result.solution_mut();
+      for _ in 0..self.times {
+        if solution.len() >= max_len {
+          break;
+        }
+        let var_idx = rng.gen_range(0..=solution.len());
+        solution.insert_var(var_idx, S::Var::default())?;
+        domain.set_rnd_domain(solution, var_idx, rng);
+      }
+    }
+    Ok(())
+  }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.probability.update(progress);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::mutation::{AddGene, Mutation},
+    utils::dummy_mp_with_domain,
+    ObjDirection, Pct, VariableLengthDomain,
+  };
+  use alloc::{vec, vec::Vec};
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn add_gene() {
+    let obj: fn(&Vec<u8>) -> usize = Vec::len;
+    let mut problem = dummy_mp_with_domain(
+      VariableLengthDomain::new(1..=4, 5..=9).unwrap(),
+      (ObjDirection::Min, obj),
+      1,
+    );
+    let (defs, source) = problem.parts_mut();
+    let _ = source.constructor().or_os_iter([1].into_iter(), vec![0]);
+    AddGene::new(5, Pct::from_percent(100)).mutation(&mut OsRng, defs.domain(), source).unwrap();
+    let solution = source.get(0).unwrap().solution().to_vec();
+    assert_eq!(solution.len(), 4);
+    assert_eq!(solution.iter().filter(|var| (5..=9).contains(*var)).count(), 3);
+  }
+}
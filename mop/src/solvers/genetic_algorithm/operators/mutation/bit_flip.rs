@@ -0,0 +1,87 @@
+use crate::{
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::{mutation::Mutation, probability_schedule::ScheduledPct},
+  BitString, Pct, Progress,
+};
+use cl_aux::SingleTypeStorage;
+use rand::Rng;
+
+/// Flips each bit of a [`BitString`] according to `bit_probability`. Flips are accumulated into
+/// masks and applied one packed word at a time.
+#[derive(Clone, Debug)]
+pub struct BitFlip {
+  bit_probability: Pct,
+  probability: ScheduledPct,
+}
+
+impl BitFlip {
+  #[inline]
+  pub fn new(bit_probability: Pct, probability: Pct) -> Self {
+    BitFlip { bit_probability, probability: ScheduledPct::new(probability) }
+  }
+}
+
+impl_set_schedule!(BitFlip);
+
+impl<M, OR, ORS, SS> Mutation<M, MpOrs<ORS, SS>> for BitFlip
+where
+  ORS: AsMut<[OR]> + SingleTypeStorage<Item = OR>,
+  SS: AsMut<[BitString]> + SingleTypeStorage<Item = BitString>,
+{
+  type Error = core::convert::Infallible;
+
+  #[inline]
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    _: &M,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    for mut individual in source.iter_mut() {
+      if !self.probability.pct().is_in_rnd_pbty(rng) {
+        continue;
+      }
+      let solution = individual.solution_mut();
+      for word_idx in 0..solution.words().len() {
+        let mut mask = 0;
+        for bit in 0..u64::BITS {
+          if self.bit_probability.is_in_rnd_pbty(rng) {
+            mask |= 1 << bit;
+          }
+        }
+        solution.xor_word(word_idx, mask);
+      }
+    }
+    Ok(())
+  }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.probability.update(progress);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::mutation::{BitFlip, Mutation},
+    utils::dummy_mp_with_domain,
+    BitString, BitStringDomain, ObjDirection, Pct,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn bit_flip() {
+    let obj: fn(&BitString) -> usize = BitString::count_ones;
+    let mut problem = dummy_mp_with_domain(BitStringDomain::new(70), (ObjDirection::Max, obj), 1);
+    let (defs, source) = problem.parts_mut();
+    let _ = source.constructor().or_os_iter([0].into_iter(), BitString::new(70));
+    BitFlip::new(Pct::from_percent(100), Pct::from_percent(100))
+      .mutation(&mut OsRng, defs.domain(), source)
+      .unwrap();
+    assert_eq!(source.get(0).unwrap().solution().count_ones(), 70);
+  }
+}
@@ -0,0 +1,88 @@
+use crate::{
+  gp::MpOrs,
+  solvers::{
+    genetic_algorithm::operators::mutation::Mutation,
+    utils::{perturb, standard_cauchy},
+  },
+  NumVarDomains, Pct, Solution,
+};
+use cl_aux::SingleTypeStorage;
+use rand::Rng;
+
+/// Adds a Cauchy distributed perturbation to `times` random variables. Heavier tails make long
+/// jumps more likely than in [`Gaussian`](super::Gaussian).
+///
+/// The scale parameter is `scale` times the width of the domain of each variable and results are
+/// clamped to the domain.
+#[derive(Clone, Debug)]
+pub struct Cauchy {
+  probability: Pct,
+  scale: f64,
+  times: usize,
+}
+
+impl Cauchy {
+  #[inline]
+  pub fn new(scale: f64, times: usize, probability: Pct) -> Self {
+    assert!(scale >= 0.0);
+    Cauchy { probability, scale, times }
+  }
+}
+
+impl<D, OR, ORS, S, SS, T> Mutation<D, MpOrs<ORS, SS>> for Cauchy
+where
+  D: NumVarDomains<T>,
+  ORS: AsMut<[OR]> + SingleTypeStorage<Item = OR>,
+  S: AsMut<[T]> + Solution<Var = T>,
+  SS: AsMut<[S]> + SingleTypeStorage<Item = S>,
+  T: Copy,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    domain: &D,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    for mut result in source.iter_mut() {
+      let vars = result.solution_mut().as_mut();
+      let len = vars.len().min(domain.var_domains_num());
+      if len > 0 && self.probability.is_in_rnd_pbty(rng) {
+        for _ in 0..self.times {
+          let var_idx = rng.gen_range(0..len);
+          let delta = self.scale * standard_cauchy(rng);
+          let var_domain = crate::Error::opt_rslt(domain.var_domain(var_idx))?;
+          crate::Error::opt_rslt(perturb(&mut vars[var_idx], var_domain, delta))?;
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::mutation::{Cauchy, Mutation},
+    utils::dummy_mp,
+    Pct,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn cauchy() {
+    let mut problem = dummy_mp();
+    let source = problem.rslts_mut();
+    let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [5.0, 5.0]);
+    let mut cauchy = Cauchy::new(0.5, 10, Pct::from_percent(100));
+    cauchy.mutation(&mut OsRng, &[0.0..=10.0, 4.0..=6.0], source).unwrap();
+    let solution = **source.get(0).unwrap().solution();
+    assert_ne!(solution, [5.0, 5.0]);
+    assert!((0.0..=10.0).contains(&solution[0]) && (4.0..=6.0).contains(&solution[1]));
+  }
+}
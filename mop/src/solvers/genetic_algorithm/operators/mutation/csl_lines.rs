@@ -0,0 +1,66 @@
+//! Innermost lines of [`Csl`] solutions seen as editable sequences of indices and values.
+
+use alloc::vec::Vec;
+use cl_aux::{Push, SingleTypeStorage};
+use ndstruct::csl::Csl;
+
+/// Indices and values of an innermost line
+pub(crate) type Line<DATA> = Vec<(usize, DATA)>;
+
+/// Maximum number of values of an innermost line of `csl`
+pub(crate) fn line_capacity<DS, IS, OS, const D: usize>(csl: &Csl<DS, IS, OS, D>) -> usize {
+  csl.dims().last().copied().unwrap_or_default()
+}
+
+/// Copies of all innermost lines of `csl`
+pub(crate) fn lines<DATA, DS, IS, OS, const D: usize>(csl: &Csl<DS, IS, OS, D>) -> Vec<Line<DATA>>
+where
+  DATA: Clone,
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  let first_off = csl.offs().first().copied().unwrap_or_default();
+  csl
+    .offs()
+    .windows(2)
+    .map(|window| {
+      let range = window[0] - first_off..window[1] - first_off;
+      csl.indcs()[range.clone()].iter().copied().zip(csl.data()[range].iter().cloned()).collect()
+    })
+    .collect()
+}
+
+/// Gives the indices `0..line.len()` to the values of `line` according to their order.
+pub(crate) fn renumber<DATA>(line: &mut Line<DATA>) {
+  for (idx, (line_idx, _)) in line.iter_mut().enumerate() {
+    *line_idx = idx;
+  }
+}
+
+/// Replaces the content of `csl` by `lines` while keeping its dimensions.
+pub(crate) fn set_lines<DATA, DS, IS, OS, const D: usize>(
+  csl: &mut Csl<DS, IS, OS, D>,
+  lines: Vec<Line<DATA>>,
+) -> crate::Result<()>
+where
+  DS: AsRef<[DATA]> + Default + Push<DATA> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]> + Default + Push<usize>,
+  OS: AsRef<[usize]> + Default + Push<usize>,
+{
+  let mut data = DS::default();
+  let mut indcs = IS::default();
+  let mut offs = OS::default();
+  let mut nnz = 0;
+  offs.push(nnz).map_err(|_e| crate::Error::InsufficientCapacity)?;
+  for line in lines {
+    for (idx, value) in line {
+      data.push(value).map_err(|_e| crate::Error::InsufficientCapacity)?;
+      indcs.push(idx).map_err(|_e| crate::Error::InsufficientCapacity)?;
+      nnz += 1;
+    }
+    offs.push(nnz).map_err(|_e| crate::Error::InsufficientCapacity)?;
+  }
+  *csl = Csl::new(*csl.dims(), data, indcs, offs)?;
+  Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::{
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::{
+    mutation::{csl_lines, Mutation},
+    probability_schedule::ScheduledPct,
+  },
+  Pct, Progress,
+};
+use cl_aux::{Push, SingleTypeStorage};
+use core::mem::take;
+use ndstruct::csl::Csl;
+use rand::{seq::IteratorRandom, Rng};
+
+/// Appends `times` random innermost lines of [`Csl`] solutions to other random lines when the
+/// result fits into a line, e.g., two routes into one route.
+#[derive(Clone, Debug)]
+pub struct CslMerge {
+  probability: ScheduledPct,
+  times: usize,
+}
+
+impl CslMerge {
+  #[inline]
+  pub fn new(times: usize, probability: Pct) -> Self {
+    CslMerge { probability: ScheduledPct::new(probability), times }
+  }
+}
+
+impl_set_schedule!(CslMerge);
+
+impl<DATA, DS, IS, M, OR, ORS, OS, SS, const D: usize> Mutation<M, MpOrs<ORS, SS>> for CslMerge
+where
+  DATA: Clone,
+  DS: AsRef<[DATA]> + Default + Push<DATA> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]> + Default + Push<usize>,
+  ORS: AsMut<[OR]> + SingleTypeStorage<Item = OR>,
+  OS: AsRef<[usize]> + Default + Push<usize>,
+  SS: AsMut<[Csl<DS, IS, OS, D>]> + SingleTypeStorage<Item = Csl<DS, IS, OS, D>>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    _: &M,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    for mut result in source.iter_mut() {
+      if !self.probability.pct().is_in_rnd_pbty(rng) {
+        continue;
+      }
+      let solution = result.solution_mut();
+      let capacity = csl_lines::line_capacity(solution);
+      let mut lines = csl_lines::lines(solution);
+      let mut has_merged = false;
+      for _ in 0..self.times {
+        let Some(from) = (0..lines.len()).filter(|idx| !lines[*idx].is_empty()).choose(rng) else {
+          break;
+        };
+        let from_len = lines[from].len();
+        let Some(to) = (0..lines.len())
+          .filter(|idx| {
+            *idx != from && !lines[*idx].is_empty() && lines[*idx].len() + from_len <= capacity
+          })
+          .choose(rng)
+        else {
+          continue;
+        };
+        let tail = take(&mut lines[from]);
+        lines[to].extend(tail);
+        csl_lines::renumber(&mut lines[to]);
+        has_merged = true;
+      }
+      if has_merged {
+        csl_lines::set_lines(solution, lines)?;
+      }
+    }
+    Ok(())
+  }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.probability.update(progress);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::mutation::{CslMerge, Mutation},
+    utils::dummy_mp_with_domain,
+    ObjDirection, Pct,
+  };
+  use alloc::{vec, vec::Vec};
+  use ndstruct::csl::Csl;
+  use rand::rngs::OsRng;
+
+  type CslVec = Csl<Vec<usize>, Vec<usize>, Vec<usize>, 2>;
+
+  #[test]
+  fn csl_merge() {
+    let obj: fn(&CslVec) -> usize = CslVec::nnz;
+    let mut problem = dummy_mp_with_domain([0..=2, 0..=2, 0..=2], (ObjDirection::Min, obj), 1);
+    let (defs, source) = problem.parts_mut();
+    let csl = CslVec::new([3, 3], vec![0, 1, 2], vec![0, 1, 0], vec![0, 2, 2, 3]).unwrap();
+    let _ = source.constructor().or_os_iter([3].into_iter(), csl);
+    CslMerge::new(1, Pct::from_percent(100)).mutation(&mut OsRng, defs.domain(), source).unwrap();
+    let solution = (*source.get(0).unwrap().solution()).clone();
+    assert_eq!(solution.nnz(), 3);
+    assert_eq!(solution.indcs(), &[0, 1, 2]);
+    assert!(solution.offs() == [0, 3, 3, 3] || solution.offs() == [0, 0, 0, 3]);
+  }
+}
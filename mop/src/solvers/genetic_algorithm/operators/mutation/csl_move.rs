@@ -0,0 +1,120 @@
+use crate::{
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::{
+    mutation::{csl_lines, Mutation},
+    probability_schedule::ScheduledPct,
+  },
+  Pct, Progress,
+};
+use cl_aux::{Push, SingleTypeStorage};
+use ndstruct::csl::Csl;
+use rand::{seq::IteratorRandom, Rng};
+
+/// Moves `times` random values of [`Csl`] solutions from their innermost lines to random
+/// positions of other lines that have room, e.g., a customer from one route to another.
+///
+/// The indices of the modified lines are renumbered according to the new order of the values.
+#[derive(Clone, Debug)]
+pub struct CslMove {
+  probability: ScheduledPct,
+  times: usize,
+}
+
+impl CslMove {
+  #[inline]
+  pub fn new(times: usize, probability: Pct) -> Self {
+    CslMove { probability: ScheduledPct::new(probability), times }
+  }
+}
+
+impl_set_schedule!(CslMove);
+
+impl<DATA, DS, IS, M, OR, ORS, OS, SS, const D: usize> Mutation<M, MpOrs<ORS, SS>> for CslMove
+where
+  DATA: Clone,
+  DS: AsRef<[DATA]> + Default + Push<DATA> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]> + Default + Push<usize>,
+  ORS: AsMut<[OR]> + SingleTypeStorage<Item = OR>,
+  OS: AsRef<[usize]> + Default + Push<usize>,
+  SS: AsMut<[Csl<DS, IS, OS, D>]> + SingleTypeStorage<Item = Csl<DS, IS, OS, D>>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    _: &M,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    for mut result in source.iter_mut() {
+      if !self.probability.pct().is_in_rnd_pbty(rng) {
+        continue;
+      }
+      let solution = result.solution_mut();
+      let capacity = csl_lines::line_capacity(solution);
+      let mut lines = csl_lines::lines(solution);
+      let mut has_moved = false;
+      for _ in 0..self.times {
+        let Some(from) = (0..lines.len()).filter(|idx| !lines[*idx].is_empty()).choose(rng) else {
+          break;
+        };
+        let Some(to) =
+          (0..lines.len()).filter(|idx| *idx != from && lines[*idx].len() < capacity).choose(rng)
+        else {
+          break;
+        };
+        let from_idx = rng.gen_range(0..lines[from].len());
+        let to_idx = rng.gen_range(0..lines[to].len() + 1);
+        let value = lines[from].remove(from_idx);
+        lines[to].insert(to_idx, value);
+        csl_lines::renumber(&mut lines[from]);
+        csl_lines::renumber(&mut lines[to]);
+        has_moved = true;
+      }
+      if has_moved {
+        csl_lines::set_lines(solution, lines)?;
+      }
+    }
+    Ok(())
+  }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.probability.update(progress);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::mutation::{CslMove, Mutation},
+    utils::dummy_mp_with_domain,
+    ObjDirection, Pct,
+  };
+  use alloc::{vec, vec::Vec};
+  use ndstruct::csl::Csl;
+  use rand::rngs::OsRng;
+
+  type CslVec = Csl<Vec<usize>, Vec<usize>, Vec<usize>, 2>;
+
+  #[test]
+  fn csl_move() {
+    let obj: fn(&CslVec) -> usize = CslVec::nnz;
+    let mut problem =
+      dummy_mp_with_domain([0..=3, 0..=3, 0..=3, 0..=3], (ObjDirection::Min, obj), 1);
+    let (defs, source) = problem.parts_mut();
+    let csl = CslVec::new([2, 4], vec![0, 1, 2, 3], vec![0, 1, 2, 3], vec![0, 4, 4]).unwrap();
+    let _ = source.constructor().or_os_iter([4].into_iter(), csl);
+    CslMove::new(1, Pct::from_percent(100)).mutation(&mut OsRng, defs.domain(), source).unwrap();
+    let solution = (*source.get(0).unwrap().solution()).clone();
+    assert_eq!(solution.offs(), &[0, 3, 4]);
+    assert_eq!(solution.indcs(), &[0, 1, 2, 0]);
+    let mut data: Vec<usize> = solution.data().to_vec();
+    data.sort_unstable();
+    assert_eq!(data, [0, 1, 2, 3]);
+  }
+}
@@ -0,0 +1,109 @@
+use crate::{
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::{
+    mutation::{csl_lines, Mutation},
+    probability_schedule::ScheduledPct,
+  },
+  Pct, Progress,
+};
+use cl_aux::{Push, SingleTypeStorage};
+use ndstruct::csl::Csl;
+use rand::{seq::IteratorRandom, Rng};
+
+/// Splits `times` random innermost lines of [`Csl`] solutions at a random point and moves the
+/// tail to an empty line, e.g., a route into two routes.
+#[derive(Clone, Debug)]
+pub struct CslSplit {
+  probability: ScheduledPct,
+  times: usize,
+}
+
+impl CslSplit {
+  #[inline]
+  pub fn new(times: usize, probability: Pct) -> Self {
+    CslSplit { probability: ScheduledPct::new(probability), times }
+  }
+}
+
+impl_set_schedule!(CslSplit);
+
+impl<DATA, DS, IS, M, OR, ORS, OS, SS, const D: usize> Mutation<M, MpOrs<ORS, SS>> for CslSplit
+where
+  DATA: Clone,
+  DS: AsRef<[DATA]> + Default + Push<DATA> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]> + Default + Push<usize>,
+  ORS: AsMut<[OR]> + SingleTypeStorage<Item = OR>,
+  OS: AsRef<[usize]> + Default + Push<usize>,
+  SS: AsMut<[Csl<DS, IS, OS, D>]> + SingleTypeStorage<Item = Csl<DS, IS, OS, D>>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    _: &M,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    for mut result in source.iter_mut() {
+      if !self.probability.pct().is_in_rnd_pbty(rng) {
+        continue;
+      }
+      let solution = result.solution_mut();
+      let mut lines = csl_lines::lines(solution);
+      let mut has_split = false;
+      for _ in 0..self.times {
+        let Some(from) = (0..lines.len()).filter(|idx| lines[*idx].len() > 1).choose(rng) else {
+          break;
+        };
+        let Some(to) = (0..lines.len()).filter(|idx| lines[*idx].is_empty()).choose(rng) else {
+          break;
+        };
+        let at = rng.gen_range(1..lines[from].len());
+        lines[to] = lines[from].split_off(at);
+        csl_lines::renumber(&mut lines[to]);
+        has_split = true;
+      }
+      if has_split {
+        csl_lines::set_lines(solution, lines)?;
+      }
+    }
+    Ok(())
+  }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.probability.update(progress);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::mutation::{CslSplit, Mutation},
+    utils::dummy_mp_with_domain,
+    ObjDirection, Pct,
+  };
+  use alloc::{vec, vec::Vec};
+  use ndstruct::csl::Csl;
+  use rand::rngs::OsRng;
+
+  type CslVec = Csl<Vec<usize>, Vec<usize>, Vec<usize>, 2>;
+
+  #[test]
+  fn csl_split() {
+    let obj: fn(&CslVec) -> usize = CslVec::nnz;
+    let mut problem = dummy_mp_with_domain([0..=1, 0..=1], (ObjDirection::Min, obj), 1);
+    let (defs, source) = problem.parts_mut();
+    let csl = CslVec::new([2, 2], vec![0, 1], vec![0, 1], vec![0, 0, 2]).unwrap();
+    let _ = source.constructor().or_os_iter([2].into_iter(), csl);
+    CslSplit::new(2, Pct::from_percent(100)).mutation(&mut OsRng, defs.domain(), source).unwrap();
+    let solution = (*source.get(0).unwrap().solution()).clone();
+    assert_eq!(solution.data(), &[1, 0]);
+    assert_eq!(solution.indcs(), &[0, 0]);
+    assert_eq!(solution.offs(), &[0, 1, 2]);
+  }
+}
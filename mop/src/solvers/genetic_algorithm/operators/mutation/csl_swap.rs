@@ -0,0 +1,112 @@
+use crate::{
+  gp::MpOrs,
+  solvers::{
+    genetic_algorithm::operators::{mutation::Mutation, probability_schedule::ScheduledPct},
+    utils::two_dist_rnd_num,
+  },
+  Pct, Progress,
+};
+use alloc::vec::Vec;
+use cl_aux::SingleTypeStorage;
+use core::ops::Range;
+use ndstruct::csl::Csl;
+use rand::Rng;
+
+/// Swaps `times` random values of [`Csl`] solutions that belong to different innermost lines,
+/// e.g., two customers of different routes. The sparsity pattern is preserved.
+#[derive(Clone, Debug)]
+pub struct CslSwap {
+  probability: ScheduledPct,
+  times: usize,
+}
+
+impl CslSwap {
+  #[inline]
+  pub fn new(times: usize, probability: Pct) -> Self {
+    CslSwap { probability: ScheduledPct::new(probability), times }
+  }
+}
+
+impl_set_schedule!(CslSwap);
+
+impl<DATA, DS, IS, M, OR, ORS, OS, SS, const D: usize> Mutation<M, MpOrs<ORS, SS>> for CslSwap
+where
+  DS: AsMut<[DATA]> + AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  ORS: AsMut<[OR]> + SingleTypeStorage<Item = OR>,
+  OS: AsRef<[usize]>,
+  SS: AsMut<[Csl<DS, IS, OS, D>]> + SingleTypeStorage<Item = Csl<DS, IS, OS, D>>,
+{
+  type Error = core::convert::Infallible;
+
+  #[inline]
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    _: &M,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    for mut result in source.iter_mut() {
+      if !self.probability.pct().is_in_rnd_pbty(rng) {
+        continue;
+      }
+      let solution = result.solution_mut();
+      let first_off = solution.offs().first().copied().unwrap_or_default();
+      let ranges: Vec<Range<usize>> = solution
+        .offs()
+        .windows(2)
+        .map(|window| window[0] - first_off..window[1] - first_off)
+        .filter(|range| !range.is_empty())
+        .collect();
+      if ranges.len() < 2 {
+        continue;
+      }
+      for _ in 0..self.times {
+        let [a, b] = two_dist_rnd_num(rng, 0..ranges.len());
+        let first = rng.gen_range(ranges[a].clone());
+        let second = rng.gen_range(ranges[b].clone());
+        solution.data_mut().swap(first, second);
+      }
+    }
+    Ok(())
+  }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.probability.update(progress);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::mutation::{CslSwap, Mutation},
+    utils::dummy_mp_with_domain,
+    ObjDirection, Pct,
+  };
+  use alloc::{vec, vec::Vec};
+  use ndstruct::csl::Csl;
+  use rand::rngs::OsRng;
+
+  type CslVec = Csl<Vec<usize>, Vec<usize>, Vec<usize>, 2>;
+
+  #[test]
+  fn csl_swap() {
+    let obj: fn(&CslVec) -> usize = CslVec::nnz;
+    let mut problem = dummy_mp_with_domain([0..=2, 0..=2], (ObjDirection::Min, obj), 1);
+    let (defs, source) = problem.parts_mut();
+    let csl = CslVec::new([3, 2], vec![0, 1], vec![0, 1], vec![0, 0, 2, 2]).unwrap();
+    let _ = source.constructor().or_os_iter([2].into_iter(), csl.clone());
+    CslSwap::new(1, Pct::from_percent(100)).mutation(&mut OsRng, defs.domain(), source).unwrap();
+    assert_eq!(*source.get(0).unwrap().solution(), &csl);
+    let csl = CslVec::new([3, 2], vec![0, 1], vec![0, 1], vec![0, 1, 1, 2]).unwrap();
+    let _ = source.constructor().or_os_iter([2].into_iter(), csl);
+    CslSwap::new(1, Pct::from_percent(100)).mutation(&mut OsRng, defs.domain(), source).unwrap();
+    let solution = (*source.get(1).unwrap().solution()).clone();
+    assert_eq!(solution.data(), &[1, 0]);
+    assert_eq!(solution.offs(), &[0, 1, 1, 2]);
+  }
+}
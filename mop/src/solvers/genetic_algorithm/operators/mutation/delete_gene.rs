@@ -0,0 +1,117 @@
+use crate::{
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::{mutation::Mutation, probability_schedule::ScheduledPct},
+  Domain, Pct, Progress, Solution,
+};
+use cl_aux::SingleTypeStorage;
+use rand::Rng;
+
+/// Removes `times` random variables of variable-length solutions while their lengths are
+/// greater than the minimum length of the domain.
+#[derive(Clone, Debug)]
+pub struct DeleteGene {
+  probability: ScheduledPct,
+  times: usize,
+}
+
+impl DeleteGene {
+  #[inline]
+  pub fn new(times: usize, probability: Pct) -> Self {
+    DeleteGene { probability: ScheduledPct::new(probability), times }
+  }
+}
+
+impl_set_schedule!(DeleteGene);
+
+impl<D, OR, ORS, S, SS> Mutation<D, MpOrs<ORS, SS>> for DeleteGene
+where
+  D: Domain<S>,
+  ORS: AsMut<[OR]> + SingleTypeStorage<Item = OR>,
+  S: Solution,
+  SS: AsMut<[S]> + SingleTypeStorage<Item = S>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    domain: &D,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    let min_len = domain.min_len();
+    for mut result in source.iter_mut() {
+      if !self.probability.pct().is_in_rnd_pbty(rng) {
+        continue;
+      }
+      let solution = result.solution_mut();
+      for _ in 0..self.times {
+        if solution.len() <= min_len {
+          break;
+        }
+        let _ = solution.remove_var(rng.gen_range(0..solution.len()))?;
+      }
+    }
+    Ok(())
+  }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.probability.update(progress);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::mutation::{
+      DeleteGene, Mutation, RandomDomainAssignments, Swap,
+    },
+    utils::dummy_mp_with_domain,
+    ObjDirection, Pct, VariableLengthDomain,
+  };
+  use alloc::{vec, vec::Vec};
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn delete_gene() {
+    let obj: fn(&Vec<u8>) -> usize = Vec::len;
+    let mut problem = dummy_mp_with_domain(
+      VariableLengthDomain::new(2..=4, 0..=9).unwrap(),
+      (ObjDirection::Min, obj),
+      1,
+    );
+    let (defs, source) = problem.parts_mut();
+    let _ = source.constructor().or_os_iter([4].into_iter(), vec![1, 2, 3, 4]);
+    DeleteGene::new(5, Pct::from_percent(100)).mutation(&mut OsRng, defs.domain(), source).unwrap();
+    let solution = source.get(0).unwrap().solution().to_vec();
+    assert_eq!(solution.len(), 2);
+    assert!(solution.windows(2).all(|window| window[0] < window[1]));
+  }
+
+  #[test]
+  fn delete_gene_with_fixed_position_mutations() {
+    let obj: fn(&Vec<u8>) -> usize = Vec::len;
+    let mut problem = dummy_mp_with_domain(
+      VariableLengthDomain::new(0..=2, 0..=9).unwrap(),
+      (ObjDirection::Min, obj),
+      2,
+    );
+    let (defs, source) = problem.parts_mut();
+    let _ = source.constructor().or_os_iter([2].into_iter(), vec![1, 2]);
+    let _ = source.constructor().or_os_iter([2].into_iter(), vec![1, 2]);
+    let mut rda = RandomDomainAssignments::new(2, Pct::from_percent(100));
+    let mut swap = Swap::new(2, Pct::from_percent(100));
+    for len in [1, 0] {
+      DeleteGene::new(1, Pct::from_percent(100))
+        .mutation(&mut OsRng, defs.domain(), source)
+        .unwrap();
+      swap.mutation(&mut OsRng, defs.domain(), source).unwrap();
+      rda.mutation(&mut OsRng, defs.domain(), source).unwrap();
+      assert!(source.iter().all(|rslt| rslt.solution().len() == len));
+    }
+  }
+}
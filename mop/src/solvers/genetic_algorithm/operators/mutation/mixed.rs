@@ -0,0 +1,189 @@
+use crate::{
+  gp::MpOrs,
+  solvers::{genetic_algorithm::operators::mutation::Mutation, utils::standard_normal},
+  ConditionalDomain, MixedVar, MixedVarDomain, Pct,
+};
+use cl_aux::SingleTypeStorage;
+use num_traits::Float;
+use rand::Rng;
+
+/// Mutates `times` random variables of mixed solutions according to their kinds.
+///
+/// * Booleans are flipped.
+/// * Categorical variables receive another random label.
+/// * Continuous and integer variables receive a normally distributed perturbation whose
+///   standard deviation is `sigma` times the width of the domain. Integers move at least one
+///   unit and results are clamped to the domain.
+///
+/// Variables that don't match their domains are replaced by random variables of the domain.
+///
+/// With a [`ConditionalDomain`], only active variables are mutated and activations are updated
+/// after each mutated variable.
+#[derive(Clone, Debug)]
+pub struct Mixed {
+  probability: Pct,
+  sigma: f64,
+  times: usize,
+}
+
+impl Mixed {
+  #[inline]
+  pub fn new(sigma: f64, times: usize, probability: Pct) -> Self {
+    assert!(sigma >= 0.0);
+    Mixed { probability, sigma, times }
+  }
+
+  fn mutate_var<R>(
+    &self,
+    rng: &mut R,
+    domain: &MixedVarDomain,
+    var: &mut MixedVar,
+  ) -> crate::Result<()>
+  where
+    R: Rng,
+  {
+    match (domain, &mut *var) {
+      (MixedVarDomain::Bool, MixedVar::Bool(value)) => *value = !*value,
+      (MixedVarDomain::Categorical(labels), MixedVar::Categorical(value))
+        if labels.len() > 1 && *value < labels.len() =>
+      {
+        let other = rng.gen_range(0..labels.len().saturating_sub(1));
+        *value = if other >= *value { other.saturating_add(1) } else { other };
+      }
+      (MixedVarDomain::Continuous(range), MixedVar::Continuous(value)) if range.contains(value) => {
+        let delta = self.sigma * standard_normal(rng) * (range.end() - range.start());
+        *value = (*value + delta).clamp(*range.start(), *range.end());
+      }
+      (MixedVarDomain::Integer(range), MixedVar::Integer(value)) if range.contains(value) => {
+        let width: f64 = crate::Error::cast_rslt(range.end().saturating_sub(*range.start()))?;
+        let delta: i64 =
+          crate::Error::cast_rslt(Float::round(self.sigma * standard_normal(rng) * width))?;
+        let step = if delta != 0 {
+          delta
+        } else if rng.gen() {
+          1
+        } else {
+          -1
+        };
+        *value = value.saturating_add(step).clamp(*range.start(), *range.end());
+      }
+      _ => {
+        if let Ok(elem) = domain.new_random_var(rng) {
+          *var = elem;
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+impl<D, OR, ORS, S, SS> Mutation<D, MpOrs<ORS, SS>> for Mixed
+where
+  D: AsRef<[MixedVarDomain]>,
+  ORS: AsMut<[OR]> + SingleTypeStorage<Item = OR>,
+  S: AsMut<[MixedVar]>,
+  SS: AsMut<[S]> + SingleTypeStorage<Item = S>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    domain: &D,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    let domain = domain.as_ref();
+    for mut result in source.iter_mut() {
+      let vars = result.solution_mut().as_mut();
+      let len = vars.len().min(domain.len());
+      if len > 0 && self.probability.is_in_rnd_pbty(rng) {
+        for _ in 0..self.times {
+          let var_idx = rng.gen_range(0..len);
+          let var_domain = crate::Error::opt_rslt(domain.get(var_idx))?;
+          self.mutate_var(rng, var_domain, crate::Error::opt_rslt(vars.get_mut(var_idx))?)?;
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+impl<OR, ORS, S, SS> Mutation<ConditionalDomain, MpOrs<ORS, SS>> for Mixed
+where
+  ORS: AsMut<[OR]> + SingleTypeStorage<Item = OR>,
+  S: AsMut<[MixedVar]>,
+  SS: AsMut<[S]> + SingleTypeStorage<Item = S>,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    domain: &ConditionalDomain,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    for mut result in source.iter_mut() {
+      if !self.probability.is_in_rnd_pbty(rng) {
+        continue;
+      }
+      let vars = result.solution_mut().as_mut();
+      for _ in 0..self.times {
+        let active = |vars: &[MixedVar], idx: &usize| domain.is_var_active(vars, *idx);
+        let active_num = (0..vars.len()).filter(|idx| active(vars, idx)).count();
+        if active_num == 0 {
+          break;
+        }
+        let nth = rng.gen_range(0..active_num);
+        let var_idx =
+          crate::Error::opt_rslt((0..vars.len()).filter(|idx| active(vars, idx)).nth(nth))?;
+        let (var_domain, _) = crate::Error::opt_rslt(domain.vars().get(var_idx))?;
+        self.mutate_var(rng, var_domain, crate::Error::opt_rslt(vars.get_mut(var_idx))?)?;
+        domain.update_activations(vars, rng)?;
+      }
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::mutation::{Mixed, Mutation},
+    utils::dummy_mp_with_domain,
+    MixedVar, MixedVarDomain, ObjDirection, Pct,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn mixed() {
+    let domain = [
+      MixedVarDomain::Bool,
+      MixedVarDomain::Categorical(["a".into(), "b".into()].into()),
+      MixedVarDomain::Continuous(0.0..=1.0),
+      MixedVarDomain::Integer(0..=1),
+    ];
+    let obj: fn(&[MixedVar; 4]) -> f64 = |_| 0.0;
+    let mut problem = dummy_mp_with_domain(domain.clone(), (ObjDirection::Min, obj), 1);
+    let original = [
+      MixedVar::Bool(false),
+      MixedVar::Categorical(0),
+      MixedVar::Continuous(1.0),
+      MixedVar::Integer(0),
+    ];
+    let _ = problem.rslts_mut().constructor().or_os_iter([0.0].into_iter(), original);
+    let mut mixed = Mixed::new(0.0, 1, Pct::from_percent(100));
+    for _ in 0..10 {
+      mixed.mutation(&mut OsRng, &domain, problem.rslts_mut()).unwrap();
+    }
+    let solution = **problem.rslts().get(0).unwrap().solution();
+    assert!(domain.iter().zip(solution.iter()).all(|(d, v)| d.contains(v)));
+  }
+}
@@ -1,21 +1,25 @@
 use crate::{
-  gp::MpOrs, solvers::genetic_algorithm::operators::mutation::Mutation, Domain, Pct, Solution,
+  gp::MpOrs,
+  solvers::genetic_algorithm::operators::{mutation::Mutation, probability_schedule::ScheduledPct},
+  Domain, Pct, Progress, Solution,
 };
 use cl_aux::SingleTypeStorage;
-use rand::{rngs::OsRng, Rng};
+use rand::Rng;
 
 #[derive(Clone, Debug)]
 pub struct RandomDomainAssignments {
   times: usize,
-  probability: Pct,
+  probability: ScheduledPct,
 }
 
 impl RandomDomainAssignments {
   #[inline]
   pub fn new(times: usize, probability: Pct) -> Self {
-    RandomDomainAssignments { times, probability }
+    RandomDomainAssignments { times, probability: ScheduledPct::new(probability) }
   }
 }
+
+impl_set_schedule!(RandomDomainAssignments);
 impl<D, OR, ORS, S, SS> Mutation<D, MpOrs<ORS, SS>> for RandomDomainAssignments
 where
   D: Domain<S>,
@@ -27,18 +31,34 @@ where
   type Error = core::convert::Infallible;
 
   #[inline]
-  fn mutation(&self, sd: &D, source: &mut MpOrs<ORS, SS>) -> Result<(), Self::Error> {
-    let mut rng = OsRng;
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    sd: &D,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
     for mut result in source.iter_mut() {
-      if self.probability.is_in_rnd_pbty(&mut rng) {
+      let len = result.solution().len();
+      if len == 0 {
+        continue;
+      }
+      if self.probability.pct().is_in_rnd_pbty(rng) {
         for _ in 0..self.times {
-          let var_idx = rng.gen_range(0..result.solution().len());
-          sd.set_rnd_domain(result.solution_mut(), var_idx, &mut rng);
+          let var_idx = rng.gen_range(0..len);
+          sd.set_rnd_domain(result.solution_mut(), var_idx, rng);
         }
       }
     }
     Ok(())
   }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.probability.update(progress);
+  }
 }
 
 #[cfg(test)]
@@ -48,14 +68,15 @@ mod tests {
     utils::dummy_mp,
     Pct,
   };
+  use rand::rngs::OsRng;
 
   #[test]
   fn random_domain_assignment() {
     let mut problem = dummy_mp();
     let (defs, source) = problem.parts_mut();
     let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [1.0, 2.0]);
-    let rda = RandomDomainAssignments::new(2, Pct::from_percent(100));
-    rda.mutation(defs.domain(), source).unwrap();
+    let mut rda = RandomDomainAssignments::new(2, Pct::from_percent(100));
+    rda.mutation(&mut OsRng, defs.domain(), source).unwrap();
     let solution = *source.get(0).unwrap().solution();
     assert_ne!([*solution.get(0).unwrap() as i32, *solution.get(0).unwrap() as i32], [1, 2]);
   }
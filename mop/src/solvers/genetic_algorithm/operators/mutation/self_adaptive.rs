@@ -0,0 +1,191 @@
+use crate::{Domain, Solution};
+use alloc::vec::Vec;
+use cl_aux::SingleTypeStorage;
+use core::ops::{Deref, DerefMut};
+use rand::Rng;
+
+/// Value paired with a mutation step size, as in evolution strategies.
+///
+/// As a solution, `step_size` is the current step size of the individual. As a domain,
+/// `step_size` is the initial step size of every new random solution.
+///
+/// The step size is expressed as a fraction of the width of the domain of each variable. See
+/// [`SelfAdaptiveGaussian`](super::SelfAdaptiveGaussian).
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SelfAdaptive<T> {
+  step_size: f64,
+  value: T,
+}
+
+impl<T> SelfAdaptive<T> {
+  #[inline]
+  pub fn new(step_size: f64, value: T) -> Self {
+    Self { step_size, value }
+  }
+
+  #[inline]
+  pub fn into_value(self) -> T {
+    self.value
+  }
+
+  #[inline]
+  pub fn set_step_size(&mut self, step_size: f64) {
+    self.step_size = step_size;
+  }
+
+  #[inline]
+  pub fn step_size(&self) -> f64 {
+    self.step_size
+  }
+
+  #[inline]
+  pub fn value(&self) -> &T {
+    &self.value
+  }
+
+  #[inline]
+  pub fn value_mut(&mut self) -> &mut T {
+    &mut self.value
+  }
+}
+
+impl<E, T> AsMut<[E]> for SelfAdaptive<T>
+where
+  T: AsMut<[E]>,
+{
+  #[inline]
+  fn as_mut(&mut self) -> &mut [E] {
+    self.value.as_mut()
+  }
+}
+
+impl<E, T> AsRef<[E]> for SelfAdaptive<T>
+where
+  T: AsRef<[E]>,
+{
+  #[inline]
+  fn as_ref(&self) -> &[E] {
+    self.value.as_ref()
+  }
+}
+
+impl<T> Deref for SelfAdaptive<T> {
+  type Target = T;
+
+  #[inline]
+  fn deref(&self) -> &T {
+    &self.value
+  }
+}
+
+impl<T> DerefMut for SelfAdaptive<T> {
+  #[inline]
+  fn deref_mut(&mut self) -> &mut T {
+    &mut self.value
+  }
+}
+
+impl<D, S> Domain<SelfAdaptive<S>> for SelfAdaptive<D>
+where
+  D: Domain<S>,
+{
+  type Error = D::Error;
+
+  #[inline]
+  fn clamp(&self, s: &mut SelfAdaptive<S>) -> Option<()> {
+    self.value.clamp(&mut s.value)
+  }
+
+  #[inline]
+  fn invalid_var(&self) -> Option<usize> {
+    self.value.invalid_var()
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.value.len()
+  }
+
+  #[inline]
+  fn max_len(&self) -> usize {
+    self.value.max_len()
+  }
+
+  #[inline]
+  fn min_len(&self) -> usize {
+    self.value.min_len()
+  }
+
+  #[inline]
+  fn new_random_solution<R>(&self, rng: &mut R) -> Result<SelfAdaptive<S>, Self::Error>
+  where
+    R: Rng,
+  {
+    Ok(SelfAdaptive::new(self.step_size, self.value.new_random_solution(rng)?))
+  }
+
+  #[inline]
+  fn out_of_bounds_vars(&self, s: &SelfAdaptive<S>) -> Option<Vec<usize>> {
+    self.value.out_of_bounds_vars(&s.value)
+  }
+
+  #[inline]
+  fn reflect(&self, s: &mut SelfAdaptive<S>) -> Option<()> {
+    self.value.reflect(&mut s.value)
+  }
+
+  #[inline]
+  fn set_rnd_domain<R>(&self, s: &mut SelfAdaptive<S>, idx: usize, rng: &mut R)
+  where
+    R: Rng,
+  {
+    self.value.set_rnd_domain(&mut s.value, idx, rng);
+  }
+}
+
+impl<T> SingleTypeStorage for SelfAdaptive<T>
+where
+  T: SingleTypeStorage,
+{
+  type Item = T::Item;
+}
+
+impl<T> Solution for SelfAdaptive<T>
+where
+  T: Solution,
+{
+  const MAX_LEN: usize = T::MAX_LEN;
+
+  type Var = T::Var;
+
+  #[inline]
+  fn has_var(&self, idx: usize) -> bool {
+    self.value.has_var(idx)
+  }
+
+  #[inline]
+  fn insert_var(&mut self, idx: usize, var: Self::Var) -> crate::Result<()> {
+    self.value.insert_var(idx, var)
+  }
+
+  #[inline]
+  fn inter_swap(&mut self, other: &mut Self, idx: usize) {
+    self.value.inter_swap(&mut other.value, idx);
+  }
+
+  #[inline]
+  fn intra_swap(&mut self, a: usize, b: usize) {
+    self.value.intra_swap(a, b);
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.value.len()
+  }
+
+  #[inline]
+  fn remove_var(&mut self, idx: usize) -> crate::Result<Self::Var> {
+    self.value.remove_var(idx)
+  }
+}
@@ -0,0 +1,104 @@
+use crate::{
+  gp::MpOrs,
+  solvers::{
+    genetic_algorithm::operators::mutation::{Mutation, SelfAdaptive},
+    utils::{perturb, standard_normal},
+  },
+  NumVarDomains, Pct, Solution,
+};
+use cl_aux::SingleTypeStorage;
+use num_traits::Float;
+use rand::Rng;
+
+/// Schwefel; Evolution and Optimum Seeking
+///
+/// Gaussian mutation of all variables whose step size is carried by each solution through
+/// [`SelfAdaptive`]. The step size is updated before the variables with the log-normal rule
+/// `step_size * exp(tau * N(0, 1))`, where `tau` is `1 / sqrt(n)`, and never goes below
+/// `min_step_size`.
+#[derive(Clone, Debug)]
+pub struct SelfAdaptiveGaussian {
+  min_step_size: f64,
+  probability: Pct,
+}
+
+impl SelfAdaptiveGaussian {
+  #[inline]
+  pub fn new(min_step_size: f64, probability: Pct) -> Self {
+    assert!(min_step_size >= 0.0);
+    SelfAdaptiveGaussian { min_step_size, probability }
+  }
+}
+
+impl<D, OR, ORS, S, SS, T> Mutation<SelfAdaptive<D>, MpOrs<ORS, SS>> for SelfAdaptiveGaussian
+where
+  D: NumVarDomains<T>,
+  ORS: AsMut<[OR]> + SingleTypeStorage<Item = OR>,
+  S: AsMut<[T]> + Solution<Var = T>,
+  SS: AsMut<[SelfAdaptive<S>]> + SingleTypeStorage<Item = SelfAdaptive<S>>,
+  T: Copy,
+{
+  type Error = crate::Error;
+
+  #[inline]
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    domain: &SelfAdaptive<D>,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
+    let domain = domain.value();
+    for mut result in source.iter_mut() {
+      if !self.probability.is_in_rnd_pbty(rng) {
+        continue;
+      }
+      let solution = result.solution_mut();
+      let len = solution.value_mut().as_mut().len().min(domain.var_domains_num());
+      if len == 0 {
+        continue;
+      }
+      let tau = 1.0 / Float::sqrt(crate::Error::cast_rslt::<_, f64>(len)?);
+      let step_size = solution.step_size() * Float::exp(tau * standard_normal(rng));
+      let step_size = step_size.max(self.min_step_size);
+      solution.set_step_size(step_size);
+      for (var_idx, var) in solution.value_mut().as_mut().iter_mut().enumerate().take(len) {
+        let var_domain = crate::Error::opt_rslt(domain.var_domain(var_idx))?;
+        let delta = step_size * standard_normal(rng);
+        crate::Error::opt_rslt(perturb(var, var_domain, delta))?;
+      }
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::mutation::{
+      Mutation, SelfAdaptive, SelfAdaptiveGaussian,
+    },
+    utils::dummy_mp_with_domain,
+    ObjDirection, Pct,
+  };
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn self_adaptive_gaussian() {
+    let domain = SelfAdaptive::new(0.1, [0.0..=10.0, 4.0..=6.0]);
+    let obj: fn(&SelfAdaptive<[f64; 2]>) -> f64 = |s| s[0];
+    let mut problem = dummy_mp_with_domain(domain.clone(), (ObjDirection::Min, obj), 1);
+    let source = problem.rslts_mut();
+    let _ =
+      source.constructor().or_os_iter([2.0].iter().cloned(), SelfAdaptive::new(0.1, [5.0, 5.0]));
+    let mut sag = SelfAdaptiveGaussian::new(0.01, Pct::from_percent(100));
+    sag.mutation(&mut OsRng, &domain, source).unwrap();
+    let solution = (*source.get(0).unwrap().solution()).clone();
+    assert_ne!(solution.step_size(), 0.1);
+    assert!(solution.step_size() >= 0.01);
+    assert_ne!(*solution.value(), [5.0, 5.0]);
+    assert!((0.0..=10.0).contains(&solution[0]) && (4.0..=6.0).contains(&solution[1]));
+  }
+}
@@ -1,24 +1,29 @@
 use crate::{
   gp::MpOrs,
-  solvers::{genetic_algorithm::operators::mutation::Mutation, utils::two_dist_rnd_num},
-  Pct, Solution,
+  solvers::{
+    genetic_algorithm::operators::{mutation::Mutation, probability_schedule::ScheduledPct},
+    utils::two_dist_rnd_num,
+  },
+  Pct, Progress, Solution,
 };
 use cl_aux::SingleTypeStorage;
-use rand::rngs::OsRng;
+use rand::Rng;
 
 #[derive(Clone, Debug)]
 pub struct Swap {
   times: usize,
-  probability: Pct,
+  probability: ScheduledPct,
 }
 
 impl Swap {
   #[inline]
   pub fn new(times: usize, probability: Pct) -> Self {
-    Swap { times, probability }
+    Swap { times, probability: ScheduledPct::new(probability) }
   }
 }
 
+impl_set_schedule!(Swap);
+
 impl<M, OR, ORS, S, SS> Mutation<M, MpOrs<ORS, SS>> for Swap
 where
   ORS: AsMut<[OR]> + SingleTypeStorage<Item = OR>,
@@ -28,19 +33,34 @@ where
   type Error = core::convert::Infallible;
 
   #[inline]
-  fn mutation(&self, _: &M, source: &mut MpOrs<ORS, SS>) -> Result<(), Self::Error> {
-    let mut rng = OsRng;
+  fn mutation<R>(
+    &mut self,
+    rng: &mut R,
+    _: &M,
+    source: &mut MpOrs<ORS, SS>,
+  ) -> Result<(), Self::Error>
+  where
+    R: Rng,
+  {
     for mut individual in source.iter_mut() {
       let len = individual.solution_mut().len();
-      if self.probability.is_in_rnd_pbty(&mut rng) {
+      if len < 2 {
+        continue;
+      }
+      if self.probability.pct().is_in_rnd_pbty(rng) {
         for _ in 0..self.times {
-          let [a, b] = two_dist_rnd_num(&mut rng, 0..len);
+          let [a, b] = two_dist_rnd_num(rng, 0..len);
           individual.solution_mut().intra_swap(a, b);
         }
       }
     }
     Ok(())
   }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.probability.update(progress);
+  }
 }
 
 #[cfg(test)]
@@ -50,14 +70,15 @@ mod tests {
     utils::dummy_mp,
     Pct,
   };
+  use rand::rngs::OsRng;
 
   #[test]
   fn swap() {
     let mut problem = dummy_mp();
     let (defs, source) = problem.parts_mut();
     let _ = source.constructor().or_os_iter([2.0, 4.0].iter().cloned(), [10.0, 20.0]);
-    let rda = Swap::new(1, Pct::from_percent(100));
-    rda.mutation(defs.domain(), source).unwrap();
+    let mut rda = Swap::new(1, Pct::from_percent(100));
+    rda.mutation(&mut OsRng, defs.domain(), source).unwrap();
     assert_eq!(*source.get(0).unwrap().solution().get(0).unwrap() as i32, 20);
     assert_eq!(*source.get(0).unwrap().solution().get(1).unwrap() as i32, 10);
   }
@@ -0,0 +1,115 @@
+//! Time-varying probabilities of operators
+
+use crate::{Pct, Progress};
+
+/// Probability of an operator that is re-evaluated at each iteration.
+#[derive(Clone, Copy, Debug)]
+pub enum ProbabilitySchedule {
+  /// Moves from `start` to `end` following `end + (start - end) * exp(-rate * ratio)`, where
+  /// `ratio` is the fraction of processed iterations.
+  ExponentialDecay {
+    /// Probability of the last iteration
+    end: Pct,
+    /// Decay rate. Greater values converge faster to `end`.
+    rate: f32,
+    /// Probability of the first iteration
+    start: Pct,
+  },
+  /// Moves linearly from `start` to `end` according to the fraction of processed iterations.
+  LinearDecay {
+    /// Probability of the last iteration
+    end: Pct,
+    /// Probability of the first iteration
+    start: Pct,
+  },
+  /// Moves from `low`, when the population is fully diverse, to `high`, when all results are
+  /// equal. Stays at `low` if the solver doesn't inform the diversity.
+  PopulationDiversity {
+    /// Probability used when the diversity collapses
+    high: Pct,
+    /// Probability used when the diversity is maximum
+    low: Pct,
+  },
+}
+
+impl ProbabilitySchedule {
+  /// Probability for the given `progress`.
+  #[inline]
+  pub fn pct(&self, progress: &Progress) -> Pct {
+    let (from, to, weight) = match *self {
+      Self::ExponentialDecay { end, rate, start } => {
+        (start, end, 1.0 - num_traits::Float::exp(-f64::from(rate) * progress.ratio()))
+      }
+      Self::LinearDecay { end, start } => (start, end, progress.ratio()),
+      Self::PopulationDiversity { high, low } => {
+        (low, high, 1.0 - progress.diversity().unwrap_or(1.0).clamp(0.0, 1.0))
+      }
+    };
+    let (from, to) = (f64::from(*from), f64::from(*to));
+    let pct = num_traits::Float::round(from + (to - from) * weight);
+    Pct::from_percent(pct.clamp(0.0, 100.0) as u16)
+  }
+}
+
+/// Probability of an operator that follows an optional [`ProbabilitySchedule`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ScheduledPct {
+  pct: Pct,
+  schedule_opt: Option<ProbabilitySchedule>,
+}
+
+impl ScheduledPct {
+  #[inline]
+  pub(crate) fn new(pct: Pct) -> Self {
+    Self { pct, schedule_opt: None }
+  }
+
+  /// Current probability
+  #[inline]
+  pub(crate) fn pct(&self) -> Pct {
+    self.pct
+  }
+
+  #[inline]
+  pub(crate) fn set_schedule(&mut self, schedule: ProbabilitySchedule) {
+    self.schedule_opt = Some(schedule);
+  }
+
+  /// Re-evaluates the current probability if a schedule was set.
+  #[inline]
+  pub(crate) fn update(&mut self, progress: &Progress) {
+    if let Some(schedule) = self.schedule_opt.as_ref() {
+      self.pct = schedule.pct(progress);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    solvers::genetic_algorithm::operators::probability_schedule::ProbabilitySchedule, Pct, Progress,
+  };
+
+  #[test]
+  fn probability_schedule() {
+    let linear =
+      ProbabilitySchedule::LinearDecay { end: Pct::from_percent(10), start: Pct::from_percent(90) };
+    assert_eq!(*linear.pct(&Progress::new(0, 10)), 90);
+    assert_eq!(*linear.pct(&Progress::new(5, 10)), 50);
+    assert_eq!(*linear.pct(&Progress::new(10, 10)), 10);
+
+    let exponential = ProbabilitySchedule::ExponentialDecay {
+      end: Pct::from_percent(0),
+      rate: 1.0,
+      start: Pct::from_percent(100),
+    };
+    assert_eq!(*exponential.pct(&Progress::new(10, 10)), 37);
+
+    let diversity = ProbabilitySchedule::PopulationDiversity {
+      high: Pct::from_percent(80),
+      low: Pct::from_percent(20),
+    };
+    assert_eq!(*diversity.pct(&Progress::new(0, 10)), 20);
+    assert_eq!(*diversity.pct(&Progress::new(0, 10).set_diversity(Some(0.0))), 80);
+  }
+}
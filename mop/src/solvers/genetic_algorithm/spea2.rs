@@ -2,6 +2,7 @@
 
 mod arch_union_popul;
 mod environment_selection_truncation_result;
+mod spea2_checkpoint;
 
 use crate::{
   gp::{GpOrsEvaluators, Mp, MpOrs},
@@ -11,9 +12,10 @@ use crate::{
       GeneticAlgorithmParams,
     },
     utils::euclidean_distance,
+    SolverRng,
   },
-  utils::verify_pareto_dominance,
-  Obj, ParBounds, Pct, Solution, Solver,
+  utils::{objs_diversity, verify_pareto_dominance},
+  Obj, ParBounds, Pct, Progress, Solution, Solver, SolverCheckpoint,
 };
 use alloc::vec::Vec;
 use arch_union_popul::{ArchUnionPopul, Properties};
@@ -27,7 +29,9 @@ use core::{
 };
 use environment_selection_truncation_result::EnvironmentSelectionTruncationResult;
 use num_traits::{NumCast, One, Pow, Zero};
-use rand::distributions::uniform::SampleUniform;
+use rand::{distributions::uniform::SampleUniform, SeedableRng};
+pub use spea2_checkpoint::Spea2Checkpoint;
+use spea2_checkpoint::SPEA2_CHECKPOINT_VERSION;
 
 /// # Types
 ///
@@ -43,6 +47,7 @@ pub struct Spea2<CO, D, M, MS, OR, ORS, OS, SS> {
   arch_rslts: MpOrs<ORS, SS>,
   arch_u_popul: ArchUnionPopul<OR, ORS, SS>,
   archive_size: usize,
+  diversity_buffer: Vec<[f64; 4]>,
   estr: Vec<EnvironmentSelectionTruncationResult<OR>>,
   gap: GeneticAlgorithmParams<CO, M, MS>,
   intermediary_arch_rslts: MpOrs<ORS, SS>,
@@ -51,6 +56,8 @@ pub struct Spea2<CO, D, M, MS, OR, ORS, OS, SS> {
   mating_pool: MpOrs<ORS, SS>,
   phantom: PhantomData<(D, OS)>,
   population_size: usize,
+  progress: Progress,
+  rng: SolverRng,
   two: OR,
 }
 
@@ -94,6 +101,7 @@ where
         props: Vec::with_capacity(arch_u_popul_len),
       },
       archive_size,
+      diversity_buffer: Vec::new(),
       phantom: PhantomData,
       estr: Vec::with_capacity(archive_size),
       intermediary_arch_rslts: MpOrs::with_capacity(defs, archive_size),
@@ -102,10 +110,20 @@ where
       k: num_integer::sqrt(arch_u_popul_len),
       mating_pool: MpOrs::with_capacity(defs, population_size),
       population_size,
+      progress: Progress::default(),
+      rng: SolverRng::from_os_rng()?,
       two: crate::Error::cast_rslt(2)?,
     })
   }
 
+  /// Seeds the random number generator used by all operators, making runs reproducible.
+  #[inline]
+  #[must_use]
+  pub fn set_seed(mut self, seed: u64) -> Self {
+    self.rng = SolverRng::seed_from_u64(seed);
+    self
+  }
+
   fn copy_less_than_one_to_archive(&mut self) {
     self.arch_rslts.clear();
     let (aup, ar) = (&self.arch_u_popul, &mut self.arch_rslts);
@@ -280,16 +298,31 @@ where
   #[inline]
   fn after_iter<'a>(&'a mut self, p: &'a mut Mp<D, ORS, OS, SS>) -> Result<(), Self::Error> {
     let filling_num = self.population_size;
+    let rslts = p.rslts().iter();
+    let diversity = objs_diversity(rslts.map(|r| r.obj_rslts), &mut self.diversity_buffer);
+    let progress = self.progress.set_diversity(diversity);
+    self.gap.crossover.update_progress(&progress);
+    self.gap.mutation.update_progress(&progress);
     self.gap.mating_selection.mating_selection(
+      &mut self.rng,
       p.defs().objs(),
       &mut self.arch_rslts,
       &mut self.mating_pool,
       filling_num,
     )?;
-    self.gap.crossover.crossover(&mut self.mating_pool, p.rslts_mut(), filling_num)?;
+    self.gap.crossover.crossover(
+      &mut self.rng,
+      &mut self.mating_pool,
+      p.rslts_mut(),
+      filling_num,
+    )?;
 
     let (defs, rslts) = p.parts_mut();
-    self.gap.mutation.mutation(defs.domain(), rslts)?;
+    if self.gap.mutation.needs_evaluated_source() {
+      GpOrsEvaluators::eval_objs(defs, rslts);
+      self.gap.crossover.offspring_evaluated(rslts);
+    }
+    self.gap.mutation.mutation(&mut self.rng, defs.domain(), rslts)?;
 
     Ok(())
   }
@@ -300,6 +333,8 @@ where
     let ar = &mut self.arch_rslts;
     GpOrsEvaluators::eval_objs(defs, ar);
     GpOrsEvaluators::eval_objs(defs, rslts);
+    self.gap.crossover.offspring_evaluated(rslts);
+    self.gap.mutation.offspring_evaluated(rslts);
 
     self.fill_arch_u_popul(p);
     self.set_strength(p);
@@ -310,6 +345,69 @@ where
     crate::Error::opt_rslt(self.manage_environment_selection_diff())?;
     Ok(())
   }
+
+  #[inline]
+  fn restarted(&mut self, p: &mut Mp<D, ORS, OS, SS>) {
+    self.gap.crossover.restarted();
+    self.gap.mutation.restarted();
+    self.population_size = p.rslts().rslts_num();
+    self.k = num_integer::sqrt(self.archive_size.saturating_add(self.population_size));
+  }
+
+  #[inline]
+  fn update_progress(&mut self, progress: &Progress) {
+    self.progress = *progress;
+  }
+}
+
+impl<CO, D, M, MS, OR, ORS, OS, SS> SolverCheckpoint<Mp<D, ORS, OS, SS>>
+  for Spea2<CO, D, M, MS, OR, ORS, OS, SS>
+where
+  CO: Crossover<MpOrs<ORS, SS>>,
+  M: Mutation<D, MpOrs<ORS, SS>>,
+  ORS: Clone,
+  SS: Clone,
+  Self: Solver<Mp<D, ORS, OS, SS>, Error = crate::Error>,
+{
+  type Checkpoint = Spea2Checkpoint<ORS, SS>;
+
+  #[inline]
+  fn checkpoint(&self, p: &Mp<D, ORS, OS, SS>) -> Self::Checkpoint {
+    let mut adaptive_states = Vec::new();
+    self.gap.crossover.adaptive_states(&mut adaptive_states);
+    self.gap.mutation.adaptive_states(&mut adaptive_states);
+    Spea2Checkpoint {
+      adaptive_states,
+      arch_rslts: self.arch_rslts.clone(),
+      k: self.k,
+      popul_rslts: p.rslts().clone(),
+      population_size: self.population_size,
+      progress: self.progress,
+      rng: self.rng.clone(),
+      version: SPEA2_CHECKPOINT_VERSION,
+    }
+  }
+
+  #[inline]
+  fn restore(
+    &mut self,
+    p: &mut Mp<D, ORS, OS, SS>,
+    checkpoint: Self::Checkpoint,
+  ) -> Result<(), Self::Error> {
+    if checkpoint.version != SPEA2_CHECKPOINT_VERSION {
+      return Err(crate::Error::UnsupportedCheckpointVersion(checkpoint.version));
+    }
+    let mut adaptive_states = checkpoint.adaptive_states.into_iter();
+    self.gap.crossover.set_adaptive_states(&mut adaptive_states);
+    self.gap.mutation.set_adaptive_states(&mut adaptive_states);
+    self.arch_rslts = checkpoint.arch_rslts;
+    self.k = checkpoint.k;
+    *p.rslts_mut() = checkpoint.popul_rslts;
+    self.population_size = checkpoint.population_size;
+    self.progress = checkpoint.progress;
+    self.rng = checkpoint.rng;
+    Ok(())
+  }
 }
 
 #[allow(
@@ -326,16 +424,25 @@ where
 #[cfg(test)]
 mod tests {
   use crate::{
+    gp::{MpDefinitionsBuilder, MpVec},
+    opt::OptFacade,
     quality_comparator::ObjsAvg,
     solvers::genetic_algorithm::{
       operators::{
-        crossover::MultiPoint, mating_selection::Tournament, mutation::RandomDomainAssignments,
+        crossover::{MultiPoint, WholeArithmetic},
+        mating_selection::Tournament,
+        mutation::{Gaussian, RandomDomainAssignments},
       },
       GeneticAlgorithmParamsBuilder, Spea2,
     },
     utils::dummy_mp_with_solutions,
-    Pct,
+    ObjDirection, Pct, SolverCheckpoint,
   };
+  use alloc::{boxed::Box, vec, vec::Vec};
+  use core::ops::RangeInclusive;
+
+  type Problem =
+    MpVec<[RangeInclusive<f64>; 2], (ObjDirection, fn(&[f64; 2]) -> f64), f64, [f64; 2]>;
 
   #[test]
   fn spea2() {
@@ -388,4 +495,272 @@ mod tests {
     assert_eq!(spea2.arch_rslts.get(0), problem.rslts().get(0));
     assert_eq!(spea2.arch_rslts.get(1), problem.rslts().get(1));
   }
+
+  #[test]
+  fn spea2_checkpoint() {
+    let new_problem = || -> Problem {
+      let defs = MpDefinitionsBuilder::default()
+        .domain([-4.0..=4.0, -4.0..=4.0])
+        .objs(vec![
+          (ObjDirection::Min, (|s: &[f64; 2]| s[0] * s[0] + s[1] * s[1]) as fn(&[f64; 2]) -> f64),
+          (ObjDirection::Min, |s: &[f64; 2]| (s[0] - 2.0) * (s[0] - 2.0) + s[1] * s[1]),
+        ])
+        .build::<[f64; 2]>()
+        .unwrap();
+      MpVec::with_user_solutions(defs, 8, |idx| [idx as f64 - 4.0, 4.0 - idx as f64])
+    };
+    let new_spea2 = |problem: &Problem, seed| {
+      Spea2::new(
+        Pct::from_percent(50),
+        GeneticAlgorithmParamsBuilder::default()
+          .crossover(MultiPoint::new(1, Pct::from_percent(70)))
+          .mating_selection(Tournament::new(2, ObjsAvg))
+          .mutation(RandomDomainAssignments::new(1, Pct::from_percent(30)))
+          .build()
+          .unwrap(),
+        problem,
+        8,
+      )
+      .unwrap()
+      .set_seed(seed)
+    };
+    let new_facade = |max_iterations| {
+      OptFacade::new(max_iterations).set_opt_hooks(()).set_quality_comparator(ObjsAvg)
+    };
+
+    let mut uninterrupted_problem = new_problem();
+    let uninterrupted_spea2 = new_spea2(&uninterrupted_problem, 1);
+    let uninterrupted =
+      new_facade(10).solve_problem_with(&mut uninterrupted_problem, uninterrupted_spea2).unwrap();
+
+    let mut interrupted_problem = new_problem();
+    let mut interrupted_spea2 = new_spea2(&interrupted_problem, 1);
+    let interrupted =
+      new_facade(4).solve_problem_with(&mut interrupted_problem, &mut interrupted_spea2).unwrap();
+    let facade_checkpoint = interrupted.checkpoint();
+    let solver_checkpoint = interrupted_spea2.checkpoint(&interrupted_problem);
+    assert_eq!(facade_checkpoint.current_iteration(), 4);
+
+    let mut resumed_problem = new_problem();
+    let mut resumed_spea2 = new_spea2(&resumed_problem, 2);
+    resumed_spea2.restore(&mut resumed_problem, solver_checkpoint).unwrap();
+    let resumed = new_facade(10)
+      .resume_problem_with(facade_checkpoint, &mut resumed_problem, &mut resumed_spea2)
+      .unwrap();
+
+    assert_eq!(resumed_problem.rslts(), uninterrupted_problem.rslts());
+    assert_eq!(resumed.best_objs_history(), uninterrupted.best_objs_history());
+    assert_eq!(resumed.evaluations(), uninterrupted.evaluations());
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn spea2_checkpoint_serde() {
+    use crate::{
+      gp::{MpOrRef, NoCstrRslts},
+      opt::{Any, ObjsStagnationWindow, OptFacadeCheckpoint},
+      solvers::genetic_algorithm::{
+        operators::{
+          adaptive_selection::AdaptiveStrategy,
+          crossover::{AdaptiveCrossover, Uniform},
+          mutation::AdaptiveMutation,
+        },
+        Spea2Checkpoint,
+      },
+    };
+    use alloc::collections::VecDeque;
+
+    type FacadeCheckpoint = OptFacadeCheckpoint<
+      NoCstrRslts,
+      f64,
+      Vec<f64>,
+      NoCstrRslts,
+      Vec<[f64; 2]>,
+      (),
+      (VecDeque<Vec<f64>>,),
+    >;
+    type SolverCheckpoint = Spea2Checkpoint<Vec<f64>, Vec<[f64; 2]>>;
+
+    let new_problem = || -> Problem {
+      let defs = MpDefinitionsBuilder::default()
+        .domain([-4.0..=4.0, -4.0..=4.0])
+        .objs(vec![
+          (ObjDirection::Min, (|s: &[f64; 2]| s[0] * s[0] + s[1] * s[1]) as fn(&[f64; 2]) -> f64),
+          (ObjDirection::Min, |s: &[f64; 2]| (s[0] - 2.0) * (s[0] - 2.0) + s[1] * s[1]),
+        ])
+        .build::<[f64; 2]>()
+        .unwrap();
+      MpVec::with_user_solutions(defs, 8, |idx| [idx as f64 - 4.0, 4.0 - idx as f64])
+    };
+    let fitness: fn(&MpOrRef<'_, f64, [f64; 2]>) -> f64 =
+      |rslt| -rslt.obj_rslts().iter().sum::<f64>();
+    let new_spea2 = |problem: &Problem, seed| {
+      Spea2::new(
+        Pct::from_percent(50),
+        GeneticAlgorithmParamsBuilder::default()
+          .crossover(AdaptiveCrossover::new(
+            fitness,
+            (
+              MultiPoint::new(1, Pct::from_percent(70)),
+              Uniform::new(Pct::from_percent(50), Pct::from_percent(70)),
+            ),
+            AdaptiveStrategy::Ucb { alpha: 0.5, exploration: 1.0 },
+          ))
+          .mating_selection(Tournament::new(2, ObjsAvg))
+          .mutation(AdaptiveMutation::new(
+            fitness,
+            (
+              Gaussian::new(0.5, 1, Pct::from_percent(30)),
+              RandomDomainAssignments::new(1, Pct::from_percent(30)),
+            ),
+            AdaptiveStrategy::Ucb { alpha: 0.5, exploration: 1.0 },
+          ))
+          .build()
+          .unwrap(),
+        problem,
+        8,
+      )
+      .unwrap()
+      .set_seed(seed)
+    };
+    let new_facade = |max_iterations| {
+      OptFacade::new(max_iterations)
+        .set_opt_hooks(())
+        .set_quality_comparator(ObjsAvg)
+        .set_termination(Any((ObjsStagnationWindow::new(0.0, 20),)))
+    };
+
+    let mut uninterrupted_problem = new_problem();
+    let uninterrupted_spea2 = new_spea2(&uninterrupted_problem, 1);
+    let uninterrupted =
+      new_facade(10).solve_problem_with(&mut uninterrupted_problem, uninterrupted_spea2).unwrap();
+
+    let mut interrupted_problem = new_problem();
+    let mut interrupted_spea2 = new_spea2(&interrupted_problem, 1);
+    let interrupted =
+      new_facade(4).solve_problem_with(&mut interrupted_problem, &mut interrupted_spea2).unwrap();
+    let facade_checkpoint = interrupted.checkpoint();
+    let solver_checkpoint = interrupted_spea2.checkpoint(&interrupted_problem);
+    assert_eq!(facade_checkpoint.termination().0.len(), 4);
+    assert_eq!(solver_checkpoint.population_size(), 8);
+
+    let facade_json = serde_json::to_string(&facade_checkpoint).unwrap();
+    let solver_json = serde_json::to_string(&solver_checkpoint).unwrap();
+    let deserialized_facade: FacadeCheckpoint = serde_json::from_str(&facade_json).unwrap();
+    let deserialized_solver: SolverCheckpoint = serde_json::from_str(&solver_json).unwrap();
+    assert_eq!(deserialized_facade, facade_checkpoint);
+    assert_eq!(deserialized_solver, solver_checkpoint);
+
+    let mut resumed_problem = new_problem();
+    let mut resumed_spea2 = new_spea2(&resumed_problem, 2);
+    resumed_spea2.restore(&mut resumed_problem, deserialized_solver).unwrap();
+    let resumed = new_facade(10)
+      .resume_problem_with(deserialized_facade, &mut resumed_problem, &mut resumed_spea2)
+      .unwrap();
+
+    assert_eq!(resumed_problem.rslts(), uninterrupted_problem.rslts());
+    assert_eq!(resumed.best_objs_history(), uninterrupted.best_objs_history());
+    assert_eq!(resumed.evaluations(), uninterrupted.evaluations());
+  }
+
+  #[test]
+  fn spea2_with_adaptive_mutation_evaluates_offspring_twice() {
+    use crate::{
+      gp::MpOrRef,
+      solvers::genetic_algorithm::operators::{
+        adaptive_selection::AdaptiveStrategy, mutation::AdaptiveMutation,
+      },
+    };
+
+    let new_problem = || -> Problem {
+      let defs = MpDefinitionsBuilder::default()
+        .domain([-4.0..=4.0, -4.0..=4.0])
+        .objs(vec![(ObjDirection::Min, (|s: &[f64; 2]| s[0] * s[0]) as fn(&[f64; 2]) -> f64)])
+        .build::<[f64; 2]>()
+        .unwrap();
+      MpVec::with_user_solutions(defs, 8, |idx| [idx as f64 - 4.0, 4.0 - idx as f64])
+    };
+    let new_facade = || OptFacade::new(5).set_opt_hooks(()).set_quality_comparator(ObjsAvg);
+    let fitness: fn(&MpOrRef<'_, f64, [f64; 2]>) -> f64 = |rslt| -rslt.obj_rslts()[0];
+
+    let mut problem = new_problem();
+    let spea2 = Spea2::new(
+      Pct::from_percent(50),
+      GeneticAlgorithmParamsBuilder::default()
+        .crossover(MultiPoint::new(1, Pct::from_percent(70)))
+        .mating_selection(Tournament::new(2, ObjsAvg))
+        .mutation(RandomDomainAssignments::new(1, Pct::from_percent(30)))
+        .build()
+        .unwrap(),
+      &problem,
+      8,
+    )
+    .unwrap();
+    let plain = new_facade().solve_problem_with(&mut problem, spea2).unwrap();
+
+    let mut problem = new_problem();
+    let spea2 = Spea2::new(
+      Pct::from_percent(50),
+      GeneticAlgorithmParamsBuilder::default()
+        .crossover(MultiPoint::new(1, Pct::from_percent(70)))
+        .mating_selection(Tournament::new(2, ObjsAvg))
+        .mutation(AdaptiveMutation::new(
+          fitness,
+          (
+            RandomDomainAssignments::new(1, Pct::from_percent(30)),
+            Gaussian::new(0.5, 1, Pct::from_percent(30)),
+          ),
+          AdaptiveStrategy::default(),
+        ))
+        .build()
+        .unwrap(),
+      &problem,
+      8,
+    )
+    .unwrap();
+    let adaptive = new_facade().solve_problem_with(&mut problem, spea2).unwrap();
+
+    // Each of the 4 mutations between the 5 iterations also evaluates the 8 offspring
+    assert_eq!(plain.evaluations().objs(), &[56]);
+    assert_eq!(adaptive.evaluations().objs(), &[56 + 4 * 8]);
+  }
+
+  #[test]
+  fn spea2_with_dynamic_solutions() {
+    macro_rules! solve {
+      ($domain:expr, $solution:ty) => {{
+        let obj: fn(&$solution) -> f64 = |s| s.iter().map(|var| var * var).sum();
+        let defs = MpDefinitionsBuilder::default()
+          .domain($domain)
+          .objs(vec![(ObjDirection::Min, obj)])
+          .build()
+          .unwrap();
+        let mut problem = MpVec::with_random_solutions(defs, 8).unwrap();
+        let spea2 = Spea2::new(
+          Pct::from_percent(50),
+          GeneticAlgorithmParamsBuilder::default()
+            .crossover(WholeArithmetic::new(Pct::from_percent(70)))
+            .mating_selection(Tournament::new(2, ObjsAvg))
+            .mutation(Gaussian::new(0.1, 1, Pct::from_percent(30)))
+            .build()
+            .unwrap(),
+          &problem,
+          8,
+        )
+        .unwrap();
+        let _ = OptFacade::new(5)
+          .set_opt_hooks(())
+          .set_quality_comparator(ObjsAvg)
+          .solve_problem_with(&mut problem, spea2)
+          .unwrap();
+        assert!(problem.rslts().iter().all(|rslt| {
+          rslt.solution().len() == 3 && rslt.solution().iter().all(|var| (-1.0..=1.0).contains(var))
+        }));
+      }};
+    }
+
+    let len = 3;
+    solve!(vec![-1.0..=1.0; len], Vec<f64>);
+    solve!(vec![-1.0..=1.0; len].into_boxed_slice(), Box<[f64]>);
+  }
 }
@@ -0,0 +1,58 @@
+use crate::{
+  gp::MpOrs,
+  solvers::{genetic_algorithm::operators::adaptive_selection::AdaptiveState, SolverRng},
+  Progress,
+};
+use alloc::vec::Vec;
+
+pub(crate) const SPEA2_CHECKPOINT_VERSION: u32 = 2;
+
+/// State of [`Spea2`](super::Spea2) between two iterations. See
+/// [`SolverCheckpoint`](crate::SolverCheckpoint).
+///
+/// The statistics of adaptive operators are stored, other operators are expected to be
+/// stateless.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spea2Checkpoint<ORS, SS> {
+  pub(crate) adaptive_states: Vec<AdaptiveState>,
+  pub(crate) arch_rslts: MpOrs<ORS, SS>,
+  pub(crate) k: usize,
+  pub(crate) popul_rslts: MpOrs<ORS, SS>,
+  pub(crate) population_size: usize,
+  pub(crate) progress: Progress,
+  pub(crate) rng: SolverRng,
+  pub(crate) version: u32,
+}
+
+impl<ORS, SS> Spea2Checkpoint<ORS, SS> {
+  /// Results of the archive
+  #[inline]
+  pub fn arch_rslts(&self) -> &MpOrs<ORS, SS> {
+    &self.arch_rslts
+  }
+
+  /// Results of the population
+  #[inline]
+  pub fn popul_rslts(&self) -> &MpOrs<ORS, SS> {
+    &self.popul_rslts
+  }
+
+  /// Population size, which can differ from the initial size after restarts
+  #[inline]
+  pub fn population_size(&self) -> usize {
+    self.population_size
+  }
+
+  /// Progress informed by the last iteration
+  #[inline]
+  pub fn progress(&self) -> &Progress {
+    &self.progress
+  }
+
+  /// Format version
+  #[inline]
+  pub fn version(&self) -> u32 {
+    self.version
+  }
+}
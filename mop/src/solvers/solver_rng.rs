@@ -0,0 +1,109 @@
+use rand::{rngs::OsRng, Error, RngCore, SeedableRng};
+
+/// Blackman and Vigna; xoshiro256++
+///
+/// Seedable pseudorandom number generator used by solvers to drive all their random
+/// operators. Unlike `OsRng`, its state can be stored and restored, which makes runs
+/// reproducible and resumable.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolverRng {
+  state: [u64; 4],
+}
+
+impl SolverRng {
+  /// Instance seeded by the operating system
+  #[inline]
+  pub fn from_os_rng() -> crate::Result<Self> {
+    Self::from_rng(OsRng).map_err(|_err| crate::Error::Other("Unavailable system randomness"))
+  }
+}
+
+impl RngCore for SolverRng {
+  #[inline]
+  fn next_u32(&mut self) -> u32 {
+    (self.next_u64() >> 32) as u32
+  }
+
+  #[inline]
+  fn next_u64(&mut self) -> u64 {
+    let [a, b, c, d] = &mut self.state;
+    let rslt = a.wrapping_add(*d).rotate_left(23).wrapping_add(*a);
+    let t = *b << 17;
+    *c ^= *a;
+    *d ^= *b;
+    *b ^= *c;
+    *a ^= *d;
+    *c ^= t;
+    *d = d.rotate_left(45);
+    rslt
+  }
+
+  #[inline]
+  fn fill_bytes(&mut self, dest: &mut [u8]) {
+    for chunk in dest.chunks_mut(8) {
+      let bytes = self.next_u64().to_le_bytes();
+      chunk.copy_from_slice(&bytes[..chunk.len()]);
+    }
+  }
+
+  #[inline]
+  fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+    self.fill_bytes(dest);
+    Ok(())
+  }
+}
+
+impl SeedableRng for SolverRng {
+  type Seed = [u8; 32];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    let mut state = [0; 4];
+    for (elem, chunk) in state.iter_mut().zip(seed.chunks_exact(8)) {
+      let mut bytes = [0; 8];
+      bytes.copy_from_slice(chunk);
+      *elem = u64::from_le_bytes(bytes);
+    }
+    // The all-zero state is a fixed point
+    if state == [0; 4] {
+      return Self::seed_from_u64(0);
+    }
+    Self { state }
+  }
+
+  /// Expands `seed` with SplitMix64
+  #[inline]
+  fn seed_from_u64(mut seed: u64) -> Self {
+    let mut state = [0; 4];
+    for elem in &mut state {
+      seed = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+      let mut z = seed;
+      z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+      z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+      *elem = z ^ (z >> 31);
+    }
+    Self { state }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::solvers::SolverRng;
+  use rand::{Rng, SeedableRng};
+
+  #[test]
+  fn solver_rng() {
+    let mut first = SolverRng::seed_from_u64(7);
+    let mut second = SolverRng::seed_from_u64(7);
+    let _ = first.gen::<u64>();
+    let _ = second.gen::<u64>();
+    let mut resumed = second.clone();
+    for _ in 0..8 {
+      let value = first.gen::<u64>();
+      assert_eq!(value, second.gen::<u64>());
+      assert_eq!(value, resumed.gen::<u64>());
+    }
+    assert_ne!(SolverRng::from_seed([0; 32]), SolverRng::from_seed([1; 32]));
+  }
+}
@@ -1,5 +1,11 @@
-use core::ops::{Mul, Range, Sub};
-use num_traits::{NumCast, Pow, Zero};
+use crate::NumVarDomain;
+use alloc::vec::Vec;
+use core::{
+  cmp::Ordering,
+  f64::consts::PI,
+  ops::{Mul, Range, Sub},
+};
+use num_traits::{Float, NumCast, Pow, Zero};
 use rand::{distributions::uniform::SampleUniform, Rng};
 
 pub(crate) fn euclidean_distance<T>(first: &[T], second: &[T]) -> Option<T>
@@ -41,3 +47,93 @@ where
   }
   [first, second]
 }
+
+/// Indices of `values` sorted from the lowest to the greatest value.
+pub(crate) fn ascending_idxs(values: &[f64]) -> Vec<usize> {
+  let mut idxs: Vec<usize> = (0..values.len()).collect();
+  idxs.sort_unstable_by(|&a, &b| values[a].partial_cmp(&values[b]).unwrap_or(Ordering::Equal));
+  idxs
+}
+
+/// Index of the slot pointed by `point` in a wheel whose slot sizes are given by `weights`.
+pub(crate) fn roulette_wheel_idx(weights: &[f64], point: f64) -> usize {
+  let mut cumulative = 0.0;
+  for (idx, weight) in weights.iter().enumerate() {
+    cumulative += weight;
+    if point < cumulative {
+      return idx;
+    }
+  }
+  weights.len().saturating_sub(1)
+}
+
+/// Spins a roulette wheel whose slot sizes are given by `weights`. Falls back to an uniform
+/// choice if the wheel has no area.
+pub(crate) fn spin_roulette_wheel<R>(rng: &mut R, weights: &[f64], total: f64) -> usize
+where
+  R: Rng,
+{
+  if total > 0.0 && total.is_finite() {
+    roulette_wheel_idx(weights, rng.gen::<f64>() * total)
+  } else {
+    rng.gen_range(0..weights.len().max(1))
+  }
+}
+
+/// Shifts `weights` so that the worst value becomes zero, returning the sum of all weights.
+pub(crate) fn windowed_weights(weights: &mut [f64]) -> f64 {
+  let min = weights.iter().copied().fold(f64::INFINITY, f64::min);
+  let mut total = 0.0;
+  for weight in weights.iter_mut() {
+    *weight -= min;
+    total += *weight;
+  }
+  total
+}
+
+/// `weight * a + (1 - weight) * b` computed with `f64` precision in the encoded space of
+/// `domain`, if any. Returns `None` if `T` can't be represented as `f64` or vice-versa.
+pub(crate) fn blend<T, V>(a: T, b: T, weight: f64, domain: Option<&V>) -> Option<T>
+where
+  T: NumCast,
+  V: NumVarDomain<T>,
+{
+  if let Some(elem) = domain {
+    let (a, b) = (elem.encode(a)?, elem.encode(b)?);
+    return elem.decode(weight * a + (1.0 - weight) * b);
+  }
+  let (a, b) = (a.to_f64()?, b.to_f64()?);
+  NumCast::from(weight * a + (1.0 - weight) * b)
+}
+
+/// Adds `delta` times the width of the encoded space of `domain` to the encoded `value`,
+/// decoding the result back into `domain`. Returns `None` if `value` can't be encoded or
+/// decoded.
+pub(crate) fn perturb<T, V>(value: &mut T, domain: &V, delta: f64) -> Option<()>
+where
+  T: Copy,
+  V: NumVarDomain<T>,
+{
+  let [start, end] = domain.encoded_bounds()?;
+  let perturbed = domain.encode(*value)? + delta * (end - start);
+  *value = domain.decode(perturbed)?;
+  Some(())
+}
+
+/// Sample of the standard Cauchy distribution
+pub(crate) fn standard_cauchy<R>(rng: &mut R) -> f64
+where
+  R: Rng,
+{
+  Float::tan(PI * (rng.gen::<f64>() - 0.5))
+}
+
+/// Sample of the standard normal distribution through the Box-Muller transform
+pub(crate) fn standard_normal<R>(rng: &mut R) -> f64
+where
+  R: Rng,
+{
+  let first = 1.0 - rng.gen::<f64>();
+  let second = rng.gen::<f64>();
+  Float::sqrt(-2.0 * Float::ln(first)) * Float::cos(2.0 * PI * second)
+}
@@ -2,7 +2,9 @@ use crate::{
   gp::{MpDefinitions, MpVec, MphDefinitions, MphVec},
   Obj, ObjDirection,
 };
+use alloc::vec::Vec;
 use core::{cmp::Ordering, ops::RangeInclusive};
+use num_traits::NumCast;
 
 type DummyMpTy =
   MpVec<[RangeInclusive<f64>; 2], (ObjDirection, fn(&[f64; 2]) -> f64), f64, [f64; 2]>;
@@ -47,11 +49,101 @@ where
   }
 }
 
+/// Hypervolume dominated by `points` and bounded by `reference`, assuming that all objectives
+/// are minimized.
+///
+/// Points that don't strictly dominate `reference` are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use mop::utils::hypervolume;
+/// let points: [&[f64]; 2] = [&[1.0, 2.0], &[2.0, 1.0]];
+/// assert_eq!(hypervolume(points, &[3.0, 3.0]), 3.0);
+/// ```
+#[inline]
+pub fn hypervolume<'any, I>(points: I, reference: &[f64]) -> f64
+where
+  I: IntoIterator<Item = &'any [f64]>,
+{
+  let mut filtered: Vec<&[f64]> = points
+    .into_iter()
+    .filter(|point| {
+      point.len() >= reference.len() && point.iter().zip(reference).all(|(value, r)| value < r)
+    })
+    .collect();
+  hypervolume_slices(&mut filtered, reference.len(), reference)
+}
+
+/// Mean, over all objectives, of the standard deviation of the results normalized by their
+/// range, from 0 (all results are equal) to 1 (results are split between the extremes).
+pub(crate) fn objs_diversity<'any, I, OR>(obj_rslts: I, buffer: &mut Vec<[f64; 4]>) -> Option<f64>
+where
+  I: Iterator<Item = &'any [OR]>,
+  OR: Copy + NumCast + 'any,
+{
+  buffer.clear();
+  let mut len = 0.0;
+  for rslt in obj_rslts {
+    if buffer.is_empty() {
+      buffer.extend(rslt.iter().map(|_| [f64::INFINITY, f64::NEG_INFINITY, 0.0, 0.0]));
+    }
+    for ([min, max, sum, sum_sq], obj_rslt) in buffer.iter_mut().zip(rslt) {
+      let value = obj_rslt.to_f64()?;
+      *min = min.min(value);
+      *max = max.max(value);
+      *sum += value;
+      *sum_sq += value * value;
+    }
+    len += 1.0;
+  }
+  if buffer.is_empty() {
+    return None;
+  }
+  let mut diversity = 0.0;
+  for [min, max, sum, sum_sq] in buffer.iter().copied() {
+    let range = max - min;
+    if range > 0.0 {
+      let mean = sum / len;
+      let std = num_traits::Float::sqrt((sum_sq / len - mean * mean).max(0.0));
+      diversity += (2.0 * std / range).min(1.0);
+    }
+  }
+  Some(diversity / crate::Error::cast_rslt::<_, f64>(buffer.len()).ok()?)
+}
+
+// Hypervolume by slicing objectives: the volume between two consecutive values of the last
+// objective is the hypervolume of the preceding points in the remaining objectives.
+fn hypervolume_slices(points: &mut [&[f64]], dims: usize, reference: &[f64]) -> f64 {
+  let last = if let Some(elem) = dims.checked_sub(1) { elem } else { return 0.0 };
+  if points.is_empty() {
+    return 0.0;
+  }
+  if last == 0 {
+    let min = points.iter().map(|point| point[0]).fold(f64::INFINITY, f64::min);
+    return reference[0] - min;
+  }
+  points.sort_unstable_by(|a, b| a[last].partial_cmp(&b[last]).unwrap_or(Ordering::Equal));
+  let mut volume = 0.0;
+  let mut buffer = Vec::with_capacity(points.len());
+  for idx in 0..points.len() {
+    let upper = points.get(idx + 1).map_or(reference[last], |point| point[last]);
+    let height = upper - points[idx][last];
+    if height > 0.0 {
+      buffer.clear();
+      buffer.extend(points[..=idx].iter().copied());
+      volume += height * hypervolume_slices(&mut buffer, last, reference);
+    }
+  }
+  volume
+}
+
 #[inline]
 pub fn dummy_mp() -> DummyMpTy {
   MpVec::with_capacity(
     MpDefinitions {
       domain: [0.0..=0.0, 0.0..=0.0],
+      evaluations: <_>::default(),
       hard_cstrs: <_>::default(),
       name: <_>::default(),
       objs: alloc::vec![(ObjDirection::Min, obj), (ObjDirection::Min, obj),],
@@ -61,6 +153,26 @@ pub fn dummy_mp() -> DummyMpTy {
   )
 }
 
+/// Problem of `domain` with the single objective `obj` and room for `rslts_num` results
+#[inline]
+pub fn dummy_mp_with_domain<D, OR, S>(
+  domain: D,
+  obj: (ObjDirection, fn(&S) -> OR),
+  rslts_num: usize,
+) -> MpVec<D, (ObjDirection, fn(&S) -> OR), OR, S> {
+  MpVec::with_capacity(
+    MpDefinitions {
+      domain,
+      evaluations: <_>::default(),
+      hard_cstrs: <_>::default(),
+      name: <_>::default(),
+      objs: alloc::vec![obj],
+      soft_cstrs: <_>::default(),
+    },
+    rslts_num,
+  )
+}
+
 #[inline]
 pub fn dummy_mp_with_solutions() -> DummyMpTy {
   let mut problem = dummy_mp();
@@ -79,6 +191,7 @@ pub fn dummy_mph() -> DummyMphTy {
   MphVec::with_capacity(
     MphDefinitions {
       domain: [0.0..=0.0, 0.0..=0.0],
+      evaluations: <_>::default(),
       hard_cstrs: alloc::vec![hc, hc],
       name: <_>::default(),
       objs: alloc::vec![(ObjDirection::Min, obj), (ObjDirection::Min, obj)],
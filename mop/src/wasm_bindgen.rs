@@ -144,6 +144,13 @@ impl OptFacade {
     OptFacade(opt::OptFacade::new(max_iterations))
   }
 
+  /// Maximum duration in milliseconds, measured with `Date.now()`. Negative, non-finite or too
+  /// large values are rejected.
+  pub fn set_max_duration(self, milliseconds: f64) -> Result<OptFacade, JsValue> {
+    let max_duration = js_err(core::time::Duration::try_from_secs_f64(milliseconds / 1000.0))?;
+    Ok(OptFacade(self.0.set_max_duration(max_duration)))
+  }
+
   pub fn set_max_iterations(self, max_iterations: usize) -> Self {
     OptFacade(self.0.set_max_iterations(max_iterations))
   }
@@ -183,6 +190,11 @@ impl OptFacade {
     } else {
       facade
     };
+    let facade = if let Some(max_duration) = self.0.max_duration() {
+      facade.set_max_duration(max_duration)
+    } else {
+      facade
+    };
     let _this = js_err(facade.solve_problem_with(&mut mp_ref, spea2))?;
     js_err(MphMpMph::transfer(&mph_defs, &mut mph_rslts, &mp_ref))?;
 
@@ -318,10 +330,16 @@ impl Solution {
 impl crate::Solution for Solution {
   const MAX_LEN: usize = 16;
 
+  type Var = f64;
+
   fn has_var(&self, idx: usize) -> bool {
     self.0.has_var(idx)
   }
 
+  fn insert_var(&mut self, idx: usize, var: Self::Var) -> crate::Result<()> {
+    self.0.insert_var(idx, var)
+  }
+
   fn inter_swap(&mut self, other: &mut Self, idx: usize) {
     self.0.inter_swap(&mut other.0, idx);
   }
@@ -333,6 +351,10 @@ impl crate::Solution for Solution {
   fn len(&self) -> usize {
     self.0.len()
   }
+
+  fn remove_var(&mut self, idx: usize) -> crate::Result<Self::Var> {
+    self.0.remove_var(idx)
+  }
 }
 
 // Domain
@@ -372,6 +394,14 @@ impl Domain {
 impl crate::Domain<Solution> for Domain {
   type Error = crate::Error;
 
+  fn clamp(&self, s: &mut Solution) -> Option<()> {
+    self.0.clamp(&mut s.0)
+  }
+
+  fn invalid_var(&self) -> Option<usize> {
+    self.0.invalid_var()
+  }
+
   fn len(&self) -> usize {
     self.0.len()
   }
@@ -383,6 +413,14 @@ impl crate::Domain<Solution> for Domain {
     Ok(Solution(self.0.new_random_solution(rng)?))
   }
 
+  fn out_of_bounds_vars(&self, s: &Solution) -> Option<Vec<usize>> {
+    self.0.out_of_bounds_vars(&s.0)
+  }
+
+  fn reflect(&self, s: &mut Solution) -> Option<()> {
+    self.0.reflect(&mut s.0)
+  }
+
   fn set_rnd_domain<R>(&self, s: &mut Solution, idx: usize, rng: &mut R)
   where
     R: Rng,
@@ -404,6 +442,14 @@ mod tests {
   use alloc::vec;
   use wasm_bindgen_test::*;
 
+  #[wasm_bindgen_test]
+  fn test_max_duration() {
+    assert!(OptFacade::new(1).set_max_duration(10.0).is_ok());
+    assert!(OptFacade::new(1).set_max_duration(-1.0).is_err());
+    assert!(OptFacade::new(1).set_max_duration(f64::INFINITY).is_err());
+    assert!(OptFacade::new(1).set_max_duration(f64::NAN).is_err());
+  }
+
   #[wasm_bindgen_test]
   fn test_problem() {
     let opdb = OptProblemDefinitionsBuilder::default()